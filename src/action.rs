@@ -6,6 +6,15 @@ use serde::{
 use strum::Display;
 
 use crate::game::celestial_bodies::solar_system::SolarSystem;
+use crate::game::diplomacy::diplomatic_option::DiplomaticOption;
+use crate::game::diplomacy::treaty::Treaty;
+use crate::game::empires::policy::Policy;
+use crate::game::event_log::EventCategory;
+use crate::game::events::random_event::EventPrompt;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::research::research_graph::ResearchGraph;
+use crate::game::sensors::knowledge::Contact;
+use crate::mode::Mode;
 use crate::tabs::Tabs;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Display, Deserialize)]
@@ -35,26 +44,60 @@ pub enum Action {
   LoadDependencyInfo(Vec<Vec<(String, bool)>>),
   LoadResearchProgressText(String),
   LoadResearchProgress(u32),
+  LoadResearchGraph(ResearchGraph),
+  LoadResearchLog(Vec<(String, Color)>),
   LoadSystemView(SolarSystem),
+  LoadSensorContacts(Vec<Contact>),
   LoadColonies(Vec<String>),
   LoadColonyInfo(Vec<(String, Color)>),
   LoadColonyBuildings(Vec<(String, u32, Color)>),
   LoadConstructionInfo(Vec<(String, u32)>),
   LoadShipModuleTypes(Vec<(String, Color)>),
   LoadShipModulesForType(Vec<(String, Color)>),
+  LoadFleets(Vec<(u32, String, Color)>),
+  LoadFleetInfo(Vec<Vec<String>>),
+  LoadCombatReports(Vec<Vec<String>>),
+  LoadEmpires(Vec<(String, String, Color)>),
+  LoadEmpireInfo(Vec<Vec<String>>),
+  LoadDiplomaticOptions(Vec<DiplomaticOption>),
+  LoadPolicies(Vec<(Policy, bool)>),
+  LoadStabilityReport(Vec<Vec<String>>),
+  LoadOfficers(Vec<(u32, String, Color)>),
+  LoadOfficerInfo(Vec<Vec<String>>),
+  LoadAssignmentOptions(Vec<(Option<Assignment>, String)>),
+  LoadEventPrompt(Option<EventPrompt>),
+  LoadEventLog(Vec<(String, Color)>),
+  LoadNotifications(Vec<(String, Color)>),
+  LoadMods(Vec<(String /* Mod id */, bool /* Is enabled */, Vec<String> /* Details */)>),
+  LoadGameMods(Vec<String>),
+  LoadCommands(Option<Vec<(String /* Command */, Action)>>),
 
   // Scheduling
   ScheduleLoadSystemView,
   ScheduleLoadResearchesForField(String),
   ScheduleLoadResearchInfo(String),
+  ScheduleLoadResearchGraph,
+  ScheduleLoadResearchLog,
   ScheduleLoadColonyInfo(String),
   ScheduleLoadConstructionInfo(String),
   ScheduleLoadShipModuleTypes,
   ScheduleLoadShipModulesForType(String),
+  ScheduleLoadFleets,
+  ScheduleLoadFleetInfo(u32),
+  ScheduleLoadEmpires,
+  ScheduleLoadEmpireInfo(String),
+  ScheduleLoadPolicies,
+  ScheduleLoadOfficers,
+  ScheduleLoadOfficerInfo(u32),
+  ScheduleLoadEventLog(Vec<EventCategory> /* Shown categories */),
+  ScheduleLoadMods,
 
   // Navigation
   NavigateNextTab,
   NavigatePrevTab,
+  NavigateToTab(usize),
+  FocusBody(String /* Body name */),
+  SetMode(Mode),
 
   // Form actions
   StartSelecting,
@@ -75,5 +118,104 @@ pub enum Action {
   EnterSystemMapNavigation,
   StartResearch(String),
   StartSelectingBuilding,
-  StartConstruction((String /* Colony name */, String /* Building type name */))
+  StartConstruction((String /* Colony name */, String /* Building type name */)),
+  DeclareWar(String /* Empire id */),
+  ProposeTreaty((String /* Empire id */, Treaty)),
+  AcceptTreaty((String /* Empire id */, Treaty)),
+  CancelTreaty((String /* Empire id */, Treaty)),
+  SetPolicy((Policy, bool /* Is enacted */)),
+  AssignOfficer((u32 /* Officer id */, Option<Assignment>)),
+  SetModEnabled((String /* Mod id */, bool /* Is enabled */)),
+  ToggleCommandPalette,
+  RunCommand,
+  AnswerEvent,
+  ChooseEventOption(usize),
+  TogglePause,
+  SetPaused(bool),
+  SetGameSpeed(u32 /* Ticks per in-game day */),
+  ToggleLogFilter,
+  ToggleAutoPause,
+  SetAutoPause((EventCategory, bool /* Pauses the game */)),
+  LoadKeyHints(Vec<(String /* Keys */, String /* Description */)>),
+  LoadHelp(Option<(Mode, Vec<(String /* Keys */, String /* Description */)>)>),
+}
+
+impl Action {
+  /// Returns `true` if the action is an order changing the game rather than the interface, the
+  /// orders are what a replay is made of
+  pub fn is_game_order(&self) -> bool {
+    matches!(
+      self,
+      Action::StartResearch(_)
+        | Action::StartConstruction(_)
+        | Action::DeclareWar(_)
+        | Action::ProposeTreaty(_)
+        | Action::AcceptTreaty(_)
+        | Action::CancelTreaty(_)
+        | Action::SetPolicy(_)
+        | Action::AssignOfficer(_)
+        | Action::ChooseEventOption(_)
+    )
+  }
+
+  /// Describes what an action bound to a key does, for the help window and the key hints
+  pub fn get_description(&self) -> String {
+    match self {
+      Action::Quit => String::from("Quit"),
+      Action::Suspend => String::from("Suspend"),
+      Action::Help => String::from("Help"),
+      Action::NavigateNextTab => String::from("Next tab"),
+      Action::NavigatePrevTab => String::from("Previous tab"),
+      Action::StartSelecting => String::from("Select"),
+      Action::ContinueSelecting => String::from("Open"),
+      Action::SelectNext => String::from("Next"),
+      Action::SelectPrevious => String::from("Previous"),
+      Action::Select => String::from("Confirm"),
+      Action::Up => String::from("Move up"),
+      Action::Down => String::from("Move down"),
+      Action::Left => String::from("Move left"),
+      Action::Right => String::from("Move right"),
+      Action::ZoomIn => String::from("Zoom in"),
+      Action::ZoomOut => String::from("Zoom out"),
+      Action::MainAction => String::from("Main action"),
+      Action::SecondaryAction => String::from("Secondary action"),
+      Action::EnterSystemMapNavigation => String::from("Navigate the map"),
+      Action::StartSelectingBuilding => String::from("Build"),
+      Action::AnswerEvent => String::from("Answer"),
+      Action::TogglePause => String::from("Pause/resume"),
+      Action::ToggleLogFilter => String::from("Show/hide"),
+      Action::ToggleAutoPause => String::from("Pause on events"),
+      Action::ToggleCommandPalette => String::from("Command palette"),
+      Action::RunCommand => String::from("Run"),
+      action => action.to_string(),
+    }
+  }
+
+  /// Returns how important the action is to the footer, which only has room for a few of them,
+  /// the lowest first. The actions without a priority are only listed by the help window
+  pub fn get_hint_priority(&self) -> Option<u32> {
+    match self {
+      Action::Help => Some(0),
+      Action::Select
+      | Action::ContinueSelecting
+      | Action::StartSelecting
+      | Action::AnswerEvent
+      | Action::RunCommand => Some(1),
+      Action::TogglePause | Action::MainAction | Action::StartSelectingBuilding => Some(2),
+      Action::SelectNext
+      | Action::SelectPrevious
+      | Action::Up
+      | Action::Down
+      | Action::Left
+      | Action::Right => Some(3),
+      Action::NavigateNextTab
+      | Action::NavigatePrevTab
+      | Action::ZoomIn
+      | Action::ZoomOut
+      | Action::EnterSystemMapNavigation
+      | Action::ToggleCommandPalette => Some(4),
+      Action::SecondaryAction | Action::ToggleLogFilter | Action::ToggleAutoPause => Some(5),
+      _ => None,
+    }
+  }
 }
@@ -1,9 +1,12 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
 use std::thread;
 use std::time::Duration;
 
 use color_eyre::eyre::Result;
 use crossterm::event::KeyEvent;
 use log::info;
+use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::Rect;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
@@ -17,18 +20,54 @@ use crate::{
   tui,
 };
 use crate::components::colonies_menu::ColoniesMenu;
+use crate::components::diplomacy_menu::DiplomacyMenu;
+use crate::components::policies_menu::PoliciesMenu;
+use crate::components::personnel_menu::PersonnelMenu;
+use crate::components::event_popup::EventPopup;
+use crate::components::event_log_menu::EventLogMenu;
+use crate::components::notifications::Notifications;
+use crate::components::help_popup::HelpPopup;
+use crate::components::key_hints::KeyHints;
+use crate::components::mods_menu::ModsMenu;
+use crate::components::command_palette::CommandPalette;
+use crate::components::fleets_menu::FleetsMenu;
 use crate::components::research_menu::ResearchMenu;
+use crate::components::research_tree::ResearchTree;
 use crate::components::ship_module_designer::ShipModuleDesigner;
 use crate::components::system_menu::SystemMenu;
 use crate::components::top_menu::TopMenu;
-use crate::game::celestial_bodies::Displayable;
+use crate::control::{server, ControlAddress};
+use crate::control::rpc::{ControlReply, GameStatus};
+use crate::replay::{RecordedOrder, Replay, ReplayRecorder};
+use crate::saves::{
+  get_saves_dir, record_current_order, set_current_day, set_current_game, write_autosave, Save,
+};
+use crate::game::celestial_bodies::{Displayable, Orbitable};
 use crate::game::colony::building::BuildingType;
 use crate::game::colony::colony::Colony;
+use crate::game::empires::strategy::Order;
+use crate::game::event_log::EventCategory;
 use crate::game::game_state::GameState;
+use crate::game::mods::game_mod::{
+  get_available_mods, get_enabled_mods, get_mods_dir, load_enabled_mods, save_enabled_mods,
+};
+use crate::game::scripting::script_manager::ScriptManager;
 use crate::game::shipbuilding::ship_module::ShipModule;
 use crate::mode::Mode::{SelectingBodyInSystemTree, SelectingResearchField};
 use crate::tabs::Tabs;
 
+/// Amount of the latest events shown in the log tab
+const EVENT_LOG_LENGTH: usize = 200;
+
+/// Splits the screen into the area of the tabs and the footer line below it
+fn split_footer(area: Rect) -> (Rect, Rect) {
+  let chunks = Layout::new(
+    Direction::Vertical,
+    [Constraint::Min(0), Constraint::Length(1)],
+  ).split(area);
+  (chunks[0], chunks[1])
+}
+
 pub struct App {
   pub config: Config,
   pub tick_rate: f64,
@@ -37,25 +76,78 @@ pub struct App {
   pub should_quit: bool,
   pub should_suspend: bool,
   pub mode: Mode,
+  /// Mode to return to once the help window is closed
+  mode_before_help: Mode,
+  /// Mode the key hints were last loaded for
+  hinted_mode: Option<Mode>,
   pub last_tick_key_events: Vec<KeyEvent>,
   pub state: GameState,
+  /// Scripts of the player, run after every in-game day
+  scripts: ScriptManager,
   tabs: Vec<Tabs>,
   cur_tab: usize,
   game_unpaused: bool,
+  pause_on: HashSet<EventCategory>,
   game_tickrate_ratio: u32,
   game_tick_counter: u32,
+  /// Where local tools control the game, the game is not controlled if it is `None`
+  control: Option<ControlAddress>,
+  /// Records the orders of the player, the game is saved as the orders given so far along with
+  /// its progress
+  recorder: ReplayRecorder,
 }
 
 impl App {
-  pub fn new(tick_rate: f64, frame_rate: f64) -> Result<Self> {
+  pub fn new(
+    tick_rate: f64,
+    frame_rate: f64,
+    control: Option<ControlAddress>,
+    record: Option<PathBuf>,
+    save: Option<Save>,
+  ) -> Result<Self> {
     let fps = FpsCounter::default();
     let system_tree = SystemMenu::default();
     let research_menu = ResearchMenu::default();
+    let research_tree = ResearchTree::default();
     let top_menu = TopMenu::default();
     let colonies_menu = ColoniesMenu::default();
     let ship_modules = ShipModuleDesigner::default();
+    let fleets_menu = FleetsMenu::default();
+    let diplomacy_menu = DiplomacyMenu::default();
+    let policies_menu = PoliciesMenu::default();
+    let personnel_menu = PersonnelMenu::default();
+    let event_log_menu = EventLogMenu::default();
+    let mods_menu = ModsMenu::default();
+    let event_popup = EventPopup::default();
+    let notifications = Notifications::default();
+    let help_popup = HelpPopup::default();
+    let command_palette = CommandPalette::default();
+    let key_hints = KeyHints::default();
 
-    let config = Config::new()?;
+    let mut config = Config::new()?;
+    let (state, scripts, game) = match save {
+      Some(save) => {
+        config.game = save.replay.options.clone();
+        let (state, scripts) = save.restore()?;
+        (state, scripts, save.replay)
+      }
+      None => {
+        config.game.mods = get_enabled_mods(&config.game)?;
+        let state = GameState::new(config.game.clone())?;
+        let scripts = ScriptManager::for_options(&config.game, state.get_assets())?;
+        let game = Replay::new(&state);
+        (state, scripts, game)
+      }
+    };
+    // The game did not end, the emergency save is not checked against a result and its
+    // progress is the replayed one
+    set_current_game(Save {
+      replay: Replay { result: None, ..game.clone() },
+      mods: state.get_mods(),
+      research: BTreeMap::new(),
+    });
+    let recorder = ReplayRecorder::new(record, game)?;
+    let pause_on = config.game.pause_on.iter().copied().collect();
     let mode = Mode::Main;
     Ok(Self {
       tick_rate,
@@ -64,40 +156,119 @@ impl App {
         Box::new(top_menu),
         Box::new(system_tree),
         Box::new(research_menu),
+        Box::new(research_tree),
         Box::new(colonies_menu),
         Box::new(ship_modules),
+        Box::new(fleets_menu),
+        Box::new(diplomacy_menu),
+        Box::new(policies_menu),
+        Box::new(personnel_menu),
+        Box::new(event_log_menu),
+        Box::new(mods_menu),
+        Box::new(notifications),
+        Box::new(event_popup),
+        Box::new(help_popup),
+        Box::new(command_palette),
+        Box::new(key_hints),
         Box::new(fps),
       ],
       should_quit: false,
       should_suspend: false,
       config,
       mode,
+      mode_before_help: mode,
+      hinted_mode: None,
       last_tick_key_events: Vec::new(),
-      state: GameState::new(),
+      state,
+      scripts,
       tabs: vec![
         Tabs::SystemView,
         Tabs::Research,
+        Tabs::ResearchTree,
         Tabs::Colonies,
         Tabs::ShipModules,
+        Tabs::Fleets,
+        Tabs::Diplomacy,
+        Tabs::Policies,
+        Tabs::Personnel,
+        Tabs::Log,
+        Tabs::Mods,
       ],
       cur_tab: 0,
       game_unpaused: true,
+      pause_on,
       game_tickrate_ratio: 10,
       game_tick_counter: 0,
+      control,
+      recorder,
     })
   }
 
+  /// Saves the game to the next autosave slot, a failed save does not stop the game
+  fn autosave(&self) {
+    let game = Save::new(self.recorder.get_replay(&self.state), &self.state);
+    match write_autosave(&get_saves_dir(), self.config.autosave.slots, &game) {
+      Ok(path) => info!("Game saved to {}", path.display()),
+      Err(e) => log::error!("Unable to save the game: {e}"),
+    }
+  }
+
+  /// Lists every command of the command palette, with the action it dispatches
+  fn get_commands(&self) -> Vec<(String, Action)> {
+    let mut commands: Vec<(String, Action)> = self.tabs.iter().enumerate()
+      .map(|(i, tab)| (format!("Open {}", String::from(tab.clone())), Action::NavigateToTab(i)))
+      .collect();
+    commands.extend(
+      self.state.get_player().get_research_manager().get_available_research_names().into_iter()
+        .map(|(id, name)| (format!("Research {name}"), Action::StartResearch(id)))
+    );
+    for colony in self.state.get_colonies() {
+      commands.extend(BuildingType::get_variants().into_iter().map(|(building, _)| {
+        let building: String = building.into();
+        (
+          format!("Build {building} on {}", colony.get_name()),
+          Action::StartConstruction((colony.get_name(), building)),
+        )
+      }));
+    }
+    let system = self.state.get_known_system();
+    commands.extend(
+      std::iter::once(system.get_star().get_name())
+        .chain(system.get_satellites().iter().map(|p| p.get_name()))
+        .map(|name| (format!("Go to {name}"), Action::FocusBody(name)))
+    );
+    commands.extend([
+      (String::from("Pause or resume the game"), Action::TogglePause),
+      (String::from("Show the keys"), Action::Help),
+      (String::from("Quit"), Action::Quit),
+    ]);
+    commands
+  }
+
   pub async fn run(&mut self) -> Result<()> {
     let (action_tx, mut action_rx) = mpsc::unbounded_channel();
-
+    let mut control_rx = match self.control.as_ref() {
+      Some(address) => Some(server::start(address).await?.0),
+      None => None,
+    };
 
     // Preload tasks
-    action_tx.send(Action::LoadSystemView(self.state.get_starting_system()))?;
+    action_tx.send(Action::LoadSystemView(self.state.get_known_system()))?;
+    action_tx.send(Action::LoadSensorContacts(self.state.get_sensor_contacts()))?;
     action_tx.send(Action::LoadTabs(self.tabs.clone()))?;
+    // A loaded game may have been saved while an event was waiting for an answer
+    let prompt = self.state.get_event_manager().get_prompt(self.state.get_player());
+    if let Some(prompt) = prompt {
+      self.game_unpaused = false;
+      self.mode = Mode::AnsweringEvent;
+      action_tx.send(Action::LoadEventPrompt(Some(prompt)))?;
+    }
 
 
-    let mut tui = tui::Tui::new()?.tick_rate(self.tick_rate).frame_rate(self.frame_rate);
-    // tui.mouse(true);
+    let mut tui = tui::Tui::new()?
+      .tick_rate(self.tick_rate)
+      .frame_rate(self.frame_rate)
+      .mouse(true);
     tui.enter()?;
 
     for component in self.components.iter_mut() {
@@ -140,17 +311,46 @@ impl App {
           },
           _ => {},
         }
-        for component in self.components.iter_mut() {
+        // Only the visible components react to the mouse, and none of them while a window
+        // covers the tab
+        let is_covered = matches!(
+          self.mode,
+          Mode::AnsweringEvent | Mode::ShowingHelp | Mode::UsingCommandPalette,
+        );
+        for component in self.components.iter_mut()
+            .filter(|c| c.is_drawn_in_tab(&self.tabs[self.cur_tab])) {
+          if is_covered && matches!(e, tui::Event::Mouse(_)) {
+            continue
+          }
           if let Some(action) = component.handle_events(Some(e.clone()))? {
             action_tx.send(action)?;
           }
         }
       }
 
+      while let Some(request) = control_rx.as_mut().and_then(|rx| rx.try_recv().ok()) {
+        let status = GameStatus::new(&self.state, !self.game_unpaused, self.game_tickrate_ratio);
+        let result = match request.call.handle(&self.state, status) {
+          Ok(ControlReply::Answer(answer)) => Ok(answer),
+          Ok(ControlReply::Dispatch(action)) => {
+            action_tx.send(action)?;
+            Ok(serde_json::Value::Bool(true))
+          }
+          Err(e) => Err(e),
+        };
+        // The tool may have disconnected in the meantime
+        let _ = request.reply.send(result);
+      }
+
       while let Ok(action) = action_rx.try_recv() {
         if action != Action::Tick && action != Action::Render {
           log::debug!("{action:?}");
         }
+        if action.is_game_order() {
+          self.recorder.record(&self.state, &action)?;
+          let day = self.state.get_date();
+          record_current_order(RecordedOrder { day, action: action.clone() });
+        }
         match action {
           Action::Tick => {
             self.last_tick_key_events.drain(..);
@@ -164,7 +364,96 @@ impl App {
             }
           },
           Action::IngameTick => {
-            self.state.tick();
+            let mut entries = self.state.tick();
+            let script_entries = self.scripts.run(&mut self.state, &entries);
+            entries.extend(script_entries);
+            set_current_day(self.state.get_date());
+            let interval = self.config.autosave.interval;
+            if interval > 0 && self.state.get_date().is_multiple_of(interval) {
+              self.autosave();
+            }
+            if !entries.is_empty() {
+              let mut notifications: Vec<(String, Color)> = entries.iter()
+                .map(|e| (e.get_name(), e.get_menu_color()))
+                .collect();
+              if entries.iter().any(|e| self.pause_on.contains(&e.get_category())) {
+                self.game_unpaused = false;
+                notifications.push(
+                  (String::from("Game paused, press <Space> to resume"), Color::White)
+                );
+              }
+              action_tx.send(Action::LoadNotifications(notifications))?;
+            }
+            let is_covered = matches!(
+              self.mode,
+              Mode::AnsweringEvent | Mode::ShowingHelp | Mode::UsingCommandPalette,
+            );
+            if !is_covered {
+              let prompt = self.state.get_event_manager().get_prompt(self.state.get_player());
+              if let Some(prompt) = prompt {
+                self.game_unpaused = false;
+                self.mode = Mode::AnsweringEvent;
+                action_tx.send(Action::LoadEventPrompt(Some(prompt)))?;
+              }
+            }
+          }
+          Action::ChooseEventOption(choice) => {
+            self.state.give_order(Order::AnswerEvent { choice });
+            let prompt = self.state.get_event_manager().get_prompt(self.state.get_player());
+            if prompt.is_none() {
+              self.game_unpaused = true;
+              self.mode = Mode::Main;
+            }
+            action_tx.send(Action::LoadEventPrompt(prompt))?;
+          }
+          Action::TogglePause if self.mode != Mode::AnsweringEvent => {
+            self.game_unpaused = !self.game_unpaused;
+            action_tx.send(Action::LoadNotifications(vec![(
+              String::from(if self.game_unpaused { "Game resumed" } else { "Game paused" }),
+              Color::White,
+            )]))?;
+          }
+          Action::SetPaused(is_paused) if self.mode != Mode::AnsweringEvent => {
+            self.game_unpaused = !is_paused;
+          }
+          Action::SetGameSpeed(ticks_per_day) => {
+            self.game_tickrate_ratio = ticks_per_day;
+            self.game_tick_counter = 0;
+          }
+          Action::SetAutoPause((category, is_pausing)) => {
+            if is_pausing {
+              self.pause_on.insert(category);
+            } else {
+              self.pause_on.remove(&category);
+            }
+          }
+          Action::Help => {
+            if self.mode == Mode::ShowingHelp {
+              self.mode = self.mode_before_help;
+              action_tx.send(Action::LoadHelp(None))?;
+            } else {
+              self.mode_before_help = self.mode;
+              self.mode = Mode::ShowingHelp;
+              action_tx.send(Action::LoadHelp(Some((
+                self.mode_before_help,
+                self.config.keybindings.get_hints(&self.mode_before_help),
+              ))))?;
+            }
+          }
+          Action::ToggleCommandPalette => {
+            if self.mode == Mode::UsingCommandPalette {
+              self.mode = Mode::Main;
+              action_tx.send(Action::LoadCommands(None))?;
+            } else {
+              self.mode = Mode::UsingCommandPalette;
+              action_tx.send(Action::LoadCommands(Some(self.get_commands())))?;
+            }
+          }
+          Action::FocusBody(_) => {
+            if let Some(tab) = self.tabs.iter().position(|t| *t == Tabs::SystemView) {
+              self.cur_tab = tab;
+              action_tx.send(Action::NavigateToTab(tab))?;
+            }
           }
           Action::Quit => self.should_quit = true,
           Action::Suspend => self.should_suspend = true,
@@ -180,12 +469,20 @@ impl App {
               self.cur_tab = self.tabs.len() - 1;
             }
           }
+          Action::NavigateToTab(tab) if tab < self.tabs.len() => {
+            self.cur_tab = tab;
+          }
+          Action::SetMode(mode) => {
+            self.mode = mode;
+          }
           Action::Resize(w, h) => {
             tui.resize(Rect::new(0, 0, w, h))?;
             tui.draw(|f| {
               for component in self.components.iter_mut() {
                 if component.is_drawn_in_tab(&self.tabs[self.cur_tab]) {
-                  let r = component.draw(f, f.size());
+                  let (screen, footer) = split_footer(f.size());
+                  let area = if component.is_footer() { footer } else { screen };
+                  let r = component.draw(f, area);
                   if let Err(e) = r {
                     action_tx.send(Action::Error(format!("Failed to draw: {:?}", e))).unwrap();
                   }
@@ -197,7 +494,9 @@ impl App {
             tui.draw(|f| {
               for component in self.components.iter_mut() {
                 if component.is_drawn_in_tab(&self.tabs[self.cur_tab]) {
-                  let r = component.draw(f, f.size());
+                  let (screen, footer) = split_footer(f.size());
+                  let area = if component.is_footer() { footer } else { screen };
+                  let r = component.draw(f, area);
                   if let Err(e) = r {
                     action_tx.send(Action::Error(format!("Failed to draw: {:?}", e))).unwrap();
                   }
@@ -209,14 +508,23 @@ impl App {
             self.mode = match self.tabs[self.cur_tab] {
               Tabs::SystemView => { SelectingBodyInSystemTree }
               Tabs::Research => { SelectingResearchField }
+              Tabs::ResearchTree => { Mode::ResearchTreeNavigation }
               Tabs::Colonies => { Mode::SelectingColony }
               Tabs::ShipModules => { Mode::SelectingShipModuleType }
+              Tabs::Fleets => { Mode::SelectingFleet }
+              Tabs::Diplomacy => { Mode::SelectingEmpire }
+              Tabs::Policies => { Mode::SelectingPolicy }
+              Tabs::Personnel => { Mode::SelectingOfficer }
+              Tabs::Log => { Mode::SelectingLogCategory }
+              Tabs::Mods => { Mode::SelectingMod }
             }
           }
           Action::ContinueSelecting => {
             self.mode = match self.mode {
               SelectingResearchField => { Mode::SelectingResearch }
               Mode::SelectingShipModuleType => { Mode::SelectingShipModule }
+              Mode::SelectingEmpire => { Mode::SelectingDiplomaticOption }
+              Mode::SelectingOfficer => { Mode::SelectingAssignment }
               _ => { Mode::Main }
             }
           }
@@ -258,6 +566,12 @@ impl App {
 
             action_tx.send(Action::LoadResearchFields(self.state.get_research_fields()))?;
           }
+          Action::ScheduleLoadResearchLog => {
+            action_tx.send(Action::LoadResearchLog(self.state.get_latest_events(20)))?;
+          }
+          Action::ScheduleLoadResearchGraph => {
+            action_tx.send(Action::LoadResearchGraph(self.state.get_research_graph()))?;
+          }
           Action::ScheduleLoadResearchesForField(ref field) => {
             action_tx.send(
               Action::LoadResearchesForField(
@@ -266,7 +580,8 @@ impl App {
             ).expect("Can send events");
           }
           Action::ScheduleLoadSystemView => {
-            action_tx.send(Action::LoadSystemView(self.state.get_starting_system()))?;
+            action_tx.send(Action::LoadSystemView(self.state.get_known_system()))?;
+            action_tx.send(Action::LoadSensorContacts(self.state.get_sensor_contacts()))?;
           }
           Action::StartResearch(ref r) => {
             self.state.start_research(r.clone());
@@ -326,6 +641,110 @@ impl App {
               )
             )?;
           },
+          Action::ScheduleLoadFleets => {
+            action_tx.send(Action::LoadFleets(self.state.get_fleets()))?;
+            action_tx.send(Action::LoadCombatReports(self.state.get_combat_reports(10)))?;
+          },
+          Action::ScheduleLoadFleetInfo(id) => {
+            action_tx.send(Action::LoadFleetInfo(self.state.get_fleet_info(id)))?;
+            action_tx.send(Action::LoadFleets(self.state.get_fleets()))?;
+            action_tx.send(Action::LoadCombatReports(self.state.get_combat_reports(10)))?;
+          },
+          Action::ScheduleLoadEmpires => {
+            let diplomacy = self.state.get_diplomacy();
+            action_tx.send(
+              Action::LoadEmpires(diplomacy.get_known_empires(self.state.get_empires()))
+            )?;
+          },
+          Action::ScheduleLoadEmpireInfo(ref id) => {
+            let diplomacy = self.state.get_diplomacy();
+            let empires = self.state.get_empires();
+            action_tx.send(Action::LoadEmpireInfo(diplomacy.get_empire_info(id, empires)))?;
+            action_tx.send(Action::LoadDiplomaticOptions(diplomacy.get_diplomatic_options(id)))?;
+            action_tx.send(Action::LoadEmpires(diplomacy.get_known_empires(empires)))?;
+          },
+          Action::DeclareWar(ref id) => {
+            self.state.give_order(Order::DeclareWar(id.clone()));
+          },
+          Action::ProposeTreaty((ref id, treaty)) => {
+            self.state.give_order(Order::ProposeTreaty { empire: id.clone(), treaty });
+          },
+          Action::AcceptTreaty((ref id, treaty)) => {
+            self.state.give_order(Order::AcceptTreaty { empire: id.clone(), treaty });
+          },
+          Action::CancelTreaty((ref id, treaty)) => {
+            self.state.give_order(Order::CancelTreaty { empire: id.clone(), treaty });
+          },
+          Action::ScheduleLoadPolicies => {
+            let player = self.state.get_player();
+            action_tx.send(Action::LoadPolicies(player.get_policy_states()))?;
+            action_tx.send(Action::LoadStabilityReport(player.get_stability_report()))?;
+          },
+          Action::SetPolicy((policy, is_enacted)) => {
+            self.state.give_order(Order::SetPolicy { policy, is_enacted });
+            action_tx.send(Action::ScheduleLoadPolicies)?;
+          },
+          Action::ScheduleLoadEventLog(ref categories) => {
+            action_tx.send(
+              Action::LoadEventLog(self.state.get_filtered_events(EVENT_LOG_LENGTH, categories))
+            )?;
+          },
+          Action::ScheduleLoadOfficers => {
+            action_tx.send(Action::LoadOfficers(self.state.get_player().get_officers()))?;
+          },
+          Action::ScheduleLoadOfficerInfo(id) => {
+            let player = self.state.get_player();
+            let fleets = self.state.get_all_fleets();
+            action_tx.send(Action::LoadOfficerInfo(player.get_officer_info(id, fleets)))?;
+            action_tx.send(
+              Action::LoadAssignmentOptions(player.get_assignment_options(id, fleets))
+            )?;
+            action_tx.send(Action::LoadOfficers(player.get_officers()))?;
+          },
+          Action::AssignOfficer((officer, ref assignment)) => {
+            self.state.give_order(Order::AssignOfficer { officer, assignment: assignment.clone() });
+            action_tx.send(Action::ScheduleLoadOfficerInfo(officer))?;
+          },
+          Action::ScheduleLoadMods => {
+            let dir = get_mods_dir();
+            let enabled = load_enabled_mods(&dir)?;
+            action_tx.send(Action::LoadMods(
+              get_available_mods(&dir)?.iter().map(|m| {
+                let details = vec![
+                  m.get_name(),
+                  format!("Version: {}", m.get_version()),
+                  String::new(),
+                  m.get_description(),
+                ];
+                (m.get_id(), enabled.contains(&m.get_id()), details)
+              }).collect()
+            ))?;
+            action_tx.send(Action::LoadGameMods(self.state.get_mods()))?;
+          },
+          Action::SetModEnabled((ref id, is_enabled)) => {
+            let dir = get_mods_dir();
+            let mut enabled = load_enabled_mods(&dir)?;
+            enabled.retain(|m| m != id);
+            if is_enabled {
+              enabled.push(id.clone());
+            }
+            save_enabled_mods(&dir, &enabled)?;
+            action_tx.send(Action::LoadNotifications(vec![(
+              format!(
+                "Mod {id} is {} from the next game",
+                if is_enabled { "enabled" } else { "disabled" },
+              ),
+              Color::White,
+            )]))?;
+            action_tx.send(Action::ScheduleLoadMods)?;
+          },
+          Action::ScheduleLoadShipModulesForType(ref module_type) => {
+            action_tx.send(
+              Action::LoadShipModulesForType(
+                self.state.get_ship_modules_by_type(module_type.clone())
+              )
+            )?;
+          },
           _ => {},
         }
         for component in self.components.iter_mut()
@@ -334,12 +753,18 @@ impl App {
             action_tx.send(action)?
           };
         }
+        if self.hinted_mode != Some(self.mode) {
+          self.hinted_mode = Some(self.mode);
+          action_tx.send(Action::LoadKeyHints(self.config.keybindings.get_key_hints(&self.mode)))?;
+        }
       }
       if self.should_suspend {
         tui.suspend()?;
         action_tx.send(Action::Resume)?;
-        tui = tui::Tui::new()?.tick_rate(self.tick_rate).frame_rate(self.frame_rate);
-        // tui.mouse(true);
+        tui = tui::Tui::new()?
+          .tick_rate(self.tick_rate)
+          .frame_rate(self.frame_rate)
+          .mouse(true);
         tui.enter()?;
       } else if self.should_quit {
         tui.stop()?;
@@ -347,6 +772,10 @@ impl App {
       }
     }
     tui.exit()?;
+    self.recorder.finish(&self.state)?;
+    if self.config.autosave.interval > 0 {
+      self.autosave();
+    }
     Ok(())
   }
 }
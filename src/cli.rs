@@ -1,4 +1,8 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::control::ControlAddress;
 
 #[derive(Parser, Debug)]
 #[command(author, version = env!("CARGO_PKG_VERSION"), about)]
@@ -15,4 +19,87 @@ pub struct Cli {
     default_value_t = 10.0
   )]
   pub frame_rate: f64,
+
+  #[arg(
+    long,
+    value_name = "ADDRESS",
+    help = "Let local tools control the game over JSON-RPC at localhost:PORT or unix:PATH"
+  )]
+  pub control: Option<ControlAddress>,
+
+  #[arg(long, value_name = "FILE", help = "Record the orders of the player into a replay file")]
+  pub record: Option<PathBuf>,
+
+  #[arg(long = "continue", help = "Continue the last saved game", conflicts_with = "load")]
+  pub continue_game: bool,
+
+  #[arg(long, value_name = "FILE", help = "Continue a saved game")]
+  pub load: Option<PathBuf>,
+
+  #[command(subcommand)]
+  pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+  /// Check the game assets for errors and exit without starting the game
+  ValidateAssets,
+  /// Play a game without the interface following a plan, and write the metrics of the player's
+  /// empire
+  Simulate(SimulateArgs),
+  /// Play a recorded game again without the interface, and write the metrics of the player's
+  /// empire
+  Replay(ReplayArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct SimulateArgs {
+  #[arg(long, value_name = "INT", help = "Seed of the game, 0 if neither it nor the plan sets one")]
+  pub seed: Option<u64>,
+
+  #[arg(long, value_name = "INT", help = "Number of in-game days to play", default_value_t = 365)]
+  pub ticks: u64,
+
+  #[arg(long, value_name = "FILE", help = "JSON file with the game options and the orders to give")]
+  pub plan: Option<PathBuf>,
+
+  #[arg(
+    long,
+    value_name = "INT",
+    help = "Number of days between two rows of metrics",
+    default_value_t = 1
+  )]
+  pub interval: u64,
+
+  #[arg(long, value_enum, help = "Format of the metrics", default_value_t = MetricsFormat::Csv)]
+  pub format: MetricsFormat,
+
+  #[arg(short, long, value_name = "FILE", help = "File to write the metrics to, stdout if not set")]
+  pub output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReplayArgs {
+  #[arg(value_name = "FILE", help = "Replay file written with --record")]
+  pub replay: PathBuf,
+
+  #[arg(
+    long,
+    value_name = "INT",
+    help = "Number of days between two rows of metrics",
+    default_value_t = 1
+  )]
+  pub interval: u64,
+
+  #[arg(long, value_enum, help = "Format of the metrics", default_value_t = MetricsFormat::Csv)]
+  pub format: MetricsFormat,
+
+  #[arg(short, long, value_name = "FILE", help = "File to write the metrics to, stdout if not set")]
+  pub output: Option<PathBuf>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MetricsFormat {
+  Csv,
+  Json,
 }
@@ -14,9 +14,21 @@ pub mod fps;
 pub mod system_menu;
 pub mod top_menu;
 pub mod research_menu;
+pub mod research_tree;
 mod utils;
 pub mod colonies_menu;
 pub mod ship_module_designer;
+pub mod fleets_menu;
+pub mod diplomacy_menu;
+pub mod policies_menu;
+pub mod personnel_menu;
+pub mod event_popup;
+pub mod event_log_menu;
+pub mod notifications;
+pub mod help_popup;
+pub mod key_hints;
+pub mod mods_menu;
+pub mod command_palette;
 
 /// `Component` is a trait that represents a visual and interactive element of the user interface.
 /// Implementors of this trait can be registered with the main application loop and will be able to receive events,
@@ -129,4 +141,13 @@ pub trait Component {
   fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> Result<()>;
 
   fn is_drawn_in_tab(&self, tab: &Tabs) -> bool;
+
+  /// Tells whether the component is drawn in the footer line instead of the rest of the screen
+  ///
+  /// # Returns
+  ///
+  /// * `bool` - `true` for the footer, `false` otherwise.
+  fn is_footer(&self) -> bool {
+    false
+  }
 }
@@ -1,12 +1,15 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets;
 use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph};
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::Component;
 use crate::components::utils::widget_utils;
+use crate::mode::Mode;
 use crate::tabs::Tabs;
 use crate::tui::Frame;
 
@@ -37,6 +40,10 @@ use crate::tui::Frame;
 /// construction projects:
 ///     - `String` - name of the building
 ///     - `u32` - progress in %
+/// - action_tx (`Option<UnboundedSender<Action>>`) - is used to send the actions of the mouse
+///   clicks
+/// - colonies_area (`Rect`) - holds the area the colonies list was last drawn in
+/// - buildings_area (`Rect`) - holds the area the buildings list was last drawn in
 pub struct ColoniesMenu {
     is_initialised: bool,
     colonies: Vec<String>,
@@ -47,7 +54,10 @@ pub struct ColoniesMenu {
     buildings_list_state: ListState,
     buildings_list: Vec<(String, u32, Color)>,
     info: Vec<(String, Color)>,
-    construction_info: Vec<(String, u32)>
+    construction_info: Vec<(String, u32)>,
+    action_tx: Option<UnboundedSender<Action>>,
+    colonies_area: Rect,
+    buildings_area: Rect,
 }
 
 impl Default for ColoniesMenu {
@@ -64,12 +74,60 @@ impl Default for ColoniesMenu {
             buildings_list_state: ListState::default(),
             buildings_list: vec![(String::from("Select a colony"), 0, Color::Red)],
             info: vec![(String::from("Select a colony"), Color::Red)],
-            construction_info: vec![(String::from("Select a colony"), 0)]
+            construction_info: vec![(String::from("Select a colony"), 0)],
+            action_tx: None,
+            colonies_area: Rect::default(),
+            buildings_area: Rect::default(),
         }
     }
 }
 
 impl Component for ColoniesMenu {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> color_eyre::Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    /// A click on a colony selects it. A click on a building starts its construction, but only
+    /// while a building is being chosen, like with the keyboard
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> color_eyre::Result<Option<Action>> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Ok(None)
+        }
+
+        let colony = widget_utils::get_list_entry_at(
+            self.colonies_area,
+            self.list_state.offset(),
+            self.colonies.iter().map(|c| c.lines().count().max(1)),
+            mouse.column,
+            mouse.row,
+        );
+        if let Some(colony) = colony {
+            self.list_state.select(Some(colony));
+            self.is_focused = true;
+            self.is_building_focused = false;
+            if let Some(tx) = self.action_tx.as_ref() {
+                tx.send(Action::SetMode(Mode::SelectingColony))?;
+            }
+            return Ok(Some(Action::Select))
+        }
+
+        if self.is_building_focused {
+            let building = widget_utils::get_list_entry_at(
+                self.buildings_area,
+                self.buildings_list_state.offset(),
+                self.buildings_list.iter().map(|_| 1),
+                mouse.column,
+                mouse.row,
+            );
+            if let Some(building) = building {
+                self.buildings_list_state.select(Some(building));
+                return Ok(Some(Action::Select))
+            }
+        }
+        Ok(None)
+    }
+
     fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
         if !self.is_initialised {
             self.is_initialised = true;
@@ -278,6 +336,7 @@ impl Component for ColoniesMenu {
             .direction(ListDirection::TopToBottom);
 
 
+        self.buildings_area = b_chunks[0];
         f.render_stateful_widget(buildings_list, b_chunks[0], &mut self.buildings_list_state);
 
 
@@ -330,6 +389,7 @@ impl Component for ColoniesMenu {
 
         f.render_widget(construction_list, b_chunks[1]);
 
+        self.colonies_area = h_chunks[0];
         f.render_stateful_widget(colonies_list, h_chunks[0], &mut self.list_state);
         f.render_widget(colony_info, p_chunks[0]);
         f.render_widget(help, v_chunks[2]);
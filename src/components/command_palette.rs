@@ -0,0 +1,183 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, ListDirection, ListState, Paragraph};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::{text_utils, widget_utils};
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `CommandPalette` is a window shown over every tab, which finds a command by a part of its name
+/// and runs it
+///
+/// **Fields**
+/// - commands (`Option<Vec<(String, Action)>>`) - holds the name and the action of every command
+///   the player can give, the palette is hidden if it is `None`
+/// - query (`String`) - what the player typed
+/// - matches (`Vec<usize>`) - indices of the commands matching the query, the best match first
+/// - match_list_state (`ListState`) - holds the current state of the matches list widget
+/// - action_tx (`Option<UnboundedSender<Action>>`) - sends the action closing the palette
+#[derive(Default)]
+pub struct CommandPalette {
+    commands: Option<Vec<(String, Action)>>,
+    query: String,
+    matches: Vec<usize>,
+    match_list_state: ListState,
+    action_tx: Option<UnboundedSender<Action>>,
+}
+
+impl CommandPalette {
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(usize, i64)> = self.commands.iter().flatten().enumerate()
+            .filter_map(|(i, (name, _))| {
+                text_utils::get_fuzzy_score(&self.query, name).map(|score| (i, score))
+            })
+            .collect();
+        scored.sort_by_key(|(_, score)| -score);
+        self.matches = scored.into_iter().map(|(i, _)| i).collect();
+        self.match_list_state.select(Some(0));
+    }
+
+    fn get_highlighted(&self) -> Option<&(String, Action)> {
+        let index = self.matches.get(self.match_list_state.selected().unwrap_or(0))?;
+        self.commands.as_ref()?.get(*index)
+    }
+}
+
+impl Component for CommandPalette {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> color_eyre::Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    /// Typing while the palette is shown edits the query, the other keys are bound in the
+    /// configuration file
+    fn handle_key_events(&mut self, key: KeyEvent) -> color_eyre::Result<Option<Action>> {
+        let is_shortcut = key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        if self.commands.is_none() || is_shortcut {
+            return Ok(None)
+        }
+        match key.code {
+            KeyCode::Char(c) => self.query.push(c),
+            KeyCode::Backspace => {
+                self.query.pop();
+            }
+            _ => return Ok(None),
+        }
+        self.update_matches();
+        Ok(None)
+    }
+
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        match action {
+            Action::LoadCommands(commands) => {
+                self.commands = commands;
+                self.query.clear();
+                self.update_matches();
+            }
+            Action::SelectPrevious if self.commands.is_some() => {
+                self.match_list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.match_list_state.selected().unwrap_or(0),
+                        self.matches.len(),
+                    )
+                ))
+            }
+            Action::SelectNext if self.commands.is_some() => {
+                self.match_list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.match_list_state.selected().unwrap_or(0),
+                        self.matches.len(),
+                    )
+                ))
+            }
+            Action::RunCommand => {
+                // The palette is closed first, so the command runs in the mode it was opened from
+                if let Some((_, command)) = self.get_highlighted().cloned() {
+                    if let Some(tx) = self.action_tx.as_ref() {
+                        tx.send(Action::ToggleCommandPalette)?;
+                    }
+                    return Ok(Some(command))
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let commands = match self.commands.as_ref() {
+            Some(commands) => commands,
+            None => return Ok(()),
+        };
+
+        let popup_area = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ],
+        ).split(
+            Layout::new(
+                Direction::Vertical,
+                vec![
+                    Constraint::Percentage(15),
+                    Constraint::Percentage(60),
+                    Constraint::Percentage(25),
+                ],
+            ).split(area)[1]
+        )[1];
+
+        let chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ],
+        ).split(popup_area);
+
+        let query = Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::LightYellow)),
+            Span::raw(self.query.clone()),
+            Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK)),
+        ]))
+            .block(
+                Block::default()
+                    .title("Command")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .border_style(Style::default().fg(Color::LightYellow))
+            );
+
+        let match_list = widgets::List::new(
+            self.matches.iter().map(|i| Line::from(commands[*i].0.clone()))
+        )
+            .block(
+                Block::default()
+                    .title(format!("{} of {} commands", self.matches.len(), commands.len()))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .border_style(Style::default().fg(Color::LightYellow))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(query, chunks[0]);
+        f.render_stateful_widget(match_list, chunks[1], &mut self.match_list_state);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, _tab: &Tabs) -> bool {
+        true
+    }
+}
\ No newline at end of file
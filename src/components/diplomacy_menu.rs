@@ -0,0 +1,259 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::game::celestial_bodies::Displayable;
+use crate::game::diplomacy::diplomatic_option::DiplomaticOption;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `DiplomacyMenu` is a tab, that shows every known empire, its relations with the player and
+/// lets the player declare wars and propose, accept or cancel treaties
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - empires (`Vec<(String, String, Color)>`) - holds the id, name and color of every empire
+/// - empire_list_state (`ListState`) - holds the current state of the empires list widget
+/// - selected_empire (`Option<String>`) - holds the id of the selected empire or `None`
+/// - options (`Vec<DiplomaticOption>`) - holds what the player can do with the selected empire
+/// - option_list_state (`ListState`) - holds the current state of the options list widget
+/// - empire_list_focused (`bool`) - `true` if the empires list is active, `false` otherwise
+/// - option_list_focused (`bool`) - `true` if the options list is active, `false` otherwise
+/// - info (`Vec<Vec<String>>`) - holds the properties of the selected empire
+pub struct DiplomacyMenu {
+    is_initialised: bool,
+    empires: Vec<(String, String, Color)>,
+    empire_list_state: ListState,
+    selected_empire: Option<String>,
+    options: Vec<DiplomaticOption>,
+    option_list_state: ListState,
+    empire_list_focused: bool,
+    option_list_focused: bool,
+    info: Vec<Vec<String>>,
+}
+
+impl Default for DiplomacyMenu {
+    fn default() -> Self {
+        let mut empire_list_state = ListState::default();
+        empire_list_state.select(Some(0));
+        let mut option_list_state = ListState::default();
+        option_list_state.select(Some(0));
+        Self {
+            is_initialised: false,
+            empires: Vec::new(),
+            empire_list_state,
+            selected_empire: None,
+            options: Vec::new(),
+            option_list_state,
+            empire_list_focused: false,
+            option_list_focused: false,
+            info: vec![vec![String::from("Select an empire")]],
+        }
+    }
+}
+
+impl DiplomacyMenu {
+    /// Turns the selected option into the action carrying it out
+    fn get_option_action(&self) -> Option<Action> {
+        let empire = self.selected_empire.clone()?;
+        let option = self.option_list_state.selected().and_then(|i| self.options.get(i))?;
+        Some(match *option {
+            DiplomaticOption::DeclareWar => Action::DeclareWar(empire),
+            DiplomaticOption::Propose(treaty) => Action::ProposeTreaty((empire, treaty)),
+            DiplomaticOption::Cancel(treaty) => Action::CancelTreaty((empire, treaty)),
+            DiplomaticOption::Accept(treaty) => Action::AcceptTreaty((empire, treaty)),
+        })
+    }
+}
+
+impl Component for DiplomacyMenu {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadEmpires))
+        }
+
+        match action {
+            Action::LoadEmpires(empires) => {
+                self.empires = empires;
+                if self.empire_list_state.selected().unwrap_or(0) >= self.empires.len() {
+                    self.empire_list_state.select(Some(0));
+                }
+            }
+            Action::LoadEmpireInfo(info) => {
+                self.info = info;
+            }
+            Action::LoadDiplomaticOptions(options) => {
+                self.options = options;
+                if self.option_list_state.selected().unwrap_or(0) >= self.options.len() {
+                    self.option_list_state.select(Some(0));
+                }
+            }
+            Action::StartSelecting => {
+                self.empire_list_focused = true;
+                self.option_list_focused = false;
+            }
+            Action::SelectPrevious => {
+                if self.empire_list_focused {
+                    self.empire_list_state.select(Some(
+                        widget_utils::select_prev_in_list(
+                            self.empire_list_state.selected().unwrap_or(0),
+                            self.empires.len(),
+                        )
+                    ))
+                } else if self.option_list_focused {
+                    self.option_list_state.select(Some(
+                        widget_utils::select_prev_in_list(
+                            self.option_list_state.selected().unwrap_or(0),
+                            self.options.len(),
+                        )
+                    ))
+                }
+            }
+            Action::SelectNext => {
+                if self.empire_list_focused {
+                    self.empire_list_state.select(Some(
+                        widget_utils::select_next_in_list(
+                            self.empire_list_state.selected().unwrap_or(0),
+                            self.empires.len(),
+                        )
+                    ))
+                } else if self.option_list_focused {
+                    self.option_list_state.select(Some(
+                        widget_utils::select_next_in_list(
+                            self.option_list_state.selected().unwrap_or(0),
+                            self.options.len(),
+                        )
+                    ))
+                }
+            }
+            Action::ContinueSelecting if self.empire_list_focused => {
+                self.empire_list_focused = false;
+                if let Some((id, _, _)) = self.empire_list_state.selected()
+                    .and_then(|i| self.empires.get(i)) {
+                    self.option_list_focused = true;
+                    self.option_list_state.select(Some(0));
+                    self.selected_empire = Some(id.clone());
+                    return Ok(Some(Action::ScheduleLoadEmpireInfo(id.clone())))
+                }
+            }
+            Action::Select if self.option_list_focused => {
+                self.option_list_focused = false;
+                return Ok(self.get_option_action())
+            }
+            Action::IngameTick => {
+                return Ok(Some(match self.selected_empire.clone() {
+                    Some(id) => Action::ScheduleLoadEmpireInfo(id),
+                    None => Action::ScheduleLoadEmpires,
+                }))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(20),
+                Constraint::Percentage(50),
+                Constraint::Percentage(30),
+            ],
+        ).split(v_chunks[1]);
+
+        let focused_style = |is_focused: bool| {
+            if is_focused {
+                Style::default().fg(Color::LightBlue)
+            } else {
+                Style::default()
+            }
+        };
+
+        let empire_list = widgets::List::new(
+            self.empires.iter().map(|(_, name, color)| {
+                Line::styled(name.clone(), Style::default().fg(*color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Empires")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(focused_style(self.empire_list_focused))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let info = Paragraph::new(
+            self.info.iter().flatten().map(|l| Line::from(l.clone())).collect::<Vec<Line>>()
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Relations")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let option_list = widgets::List::new(
+            self.options.iter().map(|o| {
+                Line::styled(o.get_name(), Style::default().fg(o.get_menu_color()))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Diplomatic options")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(focused_style(self.option_list_focused))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let help = Paragraph::new(
+            if self.empire_list_focused {
+                "Use arrows to highlight an empire, then press <Enter> to select it"
+            } else if self.option_list_focused {
+                "Use arrows to highlight an option, then press <Enter> to carry it out"
+            } else {
+                "Press <Alt+S> to select an empire"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(empire_list, h_chunks[0], &mut self.empire_list_state);
+        f.render_widget(info, h_chunks[1]);
+        f.render_stateful_widget(option_list, h_chunks[2], &mut self.option_list_state);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Diplomacy
+    }
+}
\ No newline at end of file
@@ -0,0 +1,211 @@
+use std::collections::BTreeSet;
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::config::Config;
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::EventCategory;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `EventLogMenu` is a tab, that shows the event log filtered by the categories of the events,
+/// and lets the player choose the categories which pause the game
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - shown (`BTreeSet<EventCategory>`) - holds the categories of the shown events
+/// - pausing (`BTreeSet<EventCategory>`) - holds the categories which pause the game
+/// - category_list_state (`ListState`) - holds the current state of the categories list widget
+/// - category_list_focused (`bool`) - `true` if the categories list is active, `false` otherwise
+/// - entries (`Vec<(String, Color)>`) - holds the shown log entries, newest first
+pub struct EventLogMenu {
+    is_initialised: bool,
+    shown: BTreeSet<EventCategory>,
+    pausing: BTreeSet<EventCategory>,
+    category_list_state: ListState,
+    category_list_focused: bool,
+    entries: Vec<(String, Color)>,
+}
+
+impl Default for EventLogMenu {
+    fn default() -> Self {
+        let mut category_list_state = ListState::default();
+        category_list_state.select(Some(0));
+        Self {
+            is_initialised: false,
+            shown: EventCategory::get_variants().into_iter().collect(),
+            pausing: BTreeSet::new(),
+            category_list_state,
+            category_list_focused: false,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl EventLogMenu {
+    fn get_highlighted(&self) -> Option<EventCategory> {
+        self.category_list_state.selected()
+            .and_then(|i| EventCategory::get_variants().get(i).copied())
+    }
+
+    fn schedule_load_log(&self) -> Action {
+        Action::ScheduleLoadEventLog(self.shown.iter().copied().collect())
+    }
+}
+
+impl Component for EventLogMenu {
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::Result<()> {
+        self.pausing = config.game.pause_on.into_iter().collect();
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(self.schedule_load_log()))
+        }
+
+        let categories = EventCategory::get_variants().len();
+        match action {
+            Action::LoadEventLog(entries) => {
+                self.entries = entries;
+            }
+            Action::StartSelecting => {
+                self.category_list_focused = true;
+            }
+            Action::SelectPrevious if self.category_list_focused => {
+                self.category_list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.category_list_state.selected().unwrap_or(0),
+                        categories,
+                    )
+                ))
+            }
+            Action::SelectNext if self.category_list_focused => {
+                self.category_list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.category_list_state.selected().unwrap_or(0),
+                        categories,
+                    )
+                ))
+            }
+            Action::ToggleLogFilter => {
+                if let Some(category) = self.get_highlighted() {
+                    if !self.shown.remove(&category) {
+                        self.shown.insert(category);
+                    }
+                    return Ok(Some(self.schedule_load_log()))
+                }
+            }
+            Action::ToggleAutoPause => {
+                if let Some(category) = self.get_highlighted() {
+                    let is_pausing = !self.pausing.remove(&category);
+                    if is_pausing {
+                        self.pausing.insert(category);
+                    }
+                    return Ok(Some(Action::SetAutoPause((category, is_pausing))))
+                }
+            }
+            Action::Select => {
+                self.category_list_focused = false;
+            }
+            Action::IngameTick => {
+                return Ok(Some(self.schedule_load_log()))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Length(26),
+                Constraint::Min(0),
+            ],
+        ).split(v_chunks[1]);
+
+        let category_list = widgets::List::new(
+            EventCategory::get_variants().into_iter().map(|category| {
+                Line::styled(
+                    format!(
+                        "[{}] {}{}",
+                        if self.shown.contains(&category) { "x" } else { " " },
+                        category.get_name(),
+                        if self.pausing.contains(&category) { " (pause)" } else { "" },
+                    ),
+                    Style::default().fg(category.get_menu_color()),
+                )
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Categories")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(if self.category_list_focused {
+                        Style::default().fg(Color::LightBlue)
+                    } else {
+                        Style::default()
+                    })
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let log = widgets::List::new(
+            self.entries.iter().map(|(text, color)| {
+                Line::styled(text.clone(), Style::default().fg(*color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Event log")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let help = Paragraph::new(
+            if self.category_list_focused {
+                "Use arrows to highlight a category, press <Enter> to show or hide its events, \
+                <p> to pause the game when they happen, <Esc> to finish"
+            } else {
+                "Press <Alt+S> to filter the log, press <Space> to pause or resume the game"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(category_list, h_chunks[0], &mut self.category_list_state);
+        f.render_widget(log, h_chunks[1]);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Log
+    }
+}
\ No newline at end of file
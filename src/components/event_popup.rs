@@ -0,0 +1,142 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{
+    Block, Borders, BorderType, Clear, ListDirection, ListState, Paragraph, Wrap,
+};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::game::events::random_event::EventPrompt;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `EventPopup` is a modal window shown over every tab while an event waits for the player to
+/// choose an option
+///
+/// **Fields**
+/// - prompt (`Option<EventPrompt>`) - holds the event waiting for an answer, the popup is hidden
+///   if it is `None`
+/// - option_list_state (`ListState`) - holds the current state of the options list widget
+#[derive(Default)]
+pub struct EventPopup {
+    prompt: Option<EventPrompt>,
+    option_list_state: ListState,
+}
+
+impl Component for EventPopup {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        let options = self.prompt.as_ref().map_or(0, |p| p.options.len());
+        match action {
+            Action::LoadEventPrompt(prompt) => {
+                self.prompt = prompt;
+                self.option_list_state.select(Some(0));
+            }
+            Action::SelectPrevious if self.prompt.is_some() => {
+                self.option_list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.option_list_state.selected().unwrap_or(0),
+                        options,
+                    )
+                ))
+            }
+            Action::SelectNext if self.prompt.is_some() => {
+                self.option_list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.option_list_state.selected().unwrap_or(0),
+                        options,
+                    )
+                ))
+            }
+            Action::AnswerEvent => {
+                let choice = self.option_list_state.selected().unwrap_or(0);
+                let is_affordable = self.prompt.as_ref()
+                    .and_then(|p| p.options.get(choice))
+                    .is_some_and(|(_, is_affordable)| *is_affordable);
+                if is_affordable {
+                    return Ok(Some(Action::ChooseEventOption(choice)))
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let prompt = match self.prompt.as_ref() {
+            Some(prompt) => prompt,
+            None => return Ok(()),
+        };
+
+        let popup_area = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20),
+            ],
+        ).split(
+            Layout::new(
+                Direction::Vertical,
+                vec![
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(50),
+                    Constraint::Percentage(25),
+                ],
+            ).split(area)[1]
+        )[1];
+
+        let block = Block::default()
+            .title(prompt.title.clone())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(Style::default().fg(Color::LightMagenta));
+        let inner = block.inner(popup_area);
+
+        let chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Min(0),
+                Constraint::Length(prompt.options.len() as u16 + 2),
+                Constraint::Length(1),
+            ],
+        ).split(inner);
+
+        let text = Paragraph::new(prompt.text.clone()).wrap(Wrap { trim: false });
+
+        let option_list = widgets::List::new(
+            prompt.options.iter().map(|(description, is_affordable)| {
+                let color = if *is_affordable { Color::White } else { Color::DarkGray };
+                Line::styled(description.clone(), Style::default().fg(color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Options")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let help = Paragraph::new(
+            "Use arrows to highlight an option, then press <Enter> to choose it"
+        ).style(Style::default().fg(Color::Gray));
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(block, popup_area);
+        f.render_widget(text, chunks[0]);
+        f.render_stateful_widget(option_list, chunks[1], &mut self.option_list_state);
+        f.render_widget(help, chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, _tab: &Tabs) -> bool {
+        true
+    }
+}
\ No newline at end of file
@@ -0,0 +1,206 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `FleetsMenu` is a tab, that shows every known fleet and the reports of the latest battles
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - fleets (`Vec<(u32, String, Color)>`) - holds the id, name and color of every fleet
+/// - list_state (`ListState`) - holds the current state of the fleets list widget
+/// - selected_fleet (`Option<u32>`) - holds the id of the selected fleet or `None`
+/// - is_focused (`bool`) - `true` if the fleets list is active, `false` otherwise
+/// - info (`Vec<Vec<String>>`) - holds the properties of the selected fleet
+/// - reports (`Vec<Vec<String>>`) - holds the latest combat reports, newest first
+pub struct FleetsMenu {
+    is_initialised: bool,
+    fleets: Vec<(u32, String, Color)>,
+    list_state: ListState,
+    selected_fleet: Option<u32>,
+    is_focused: bool,
+    info: Vec<Vec<String>>,
+    reports: Vec<Vec<String>>,
+}
+
+impl Default for FleetsMenu {
+    fn default() -> Self {
+        let mut state = ListState::default();
+        state.select(Some(0));
+        Self {
+            is_initialised: false,
+            fleets: Vec::new(),
+            list_state: state,
+            selected_fleet: None,
+            is_focused: false,
+            info: vec![vec![String::from("Select a fleet")]],
+            reports: Vec::new(),
+        }
+    }
+}
+
+impl Component for FleetsMenu {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadFleets))
+        }
+
+        match action {
+            Action::LoadFleets(fleets) => {
+                self.fleets = fleets;
+                if self.list_state.selected().unwrap_or(0) >= self.fleets.len() {
+                    self.list_state.select(Some(0));
+                }
+            }
+            Action::LoadFleetInfo(info) => {
+                self.info = info;
+            }
+            Action::LoadCombatReports(reports) => {
+                self.reports = reports;
+            }
+            Action::StartSelecting => {
+                self.is_focused = true;
+            }
+            Action::SelectPrevious if self.is_focused => {
+                self.list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.list_state.selected().unwrap_or(0),
+                        self.fleets.len(),
+                    )
+                ))
+            }
+            Action::SelectNext if self.is_focused => {
+                self.list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.list_state.selected().unwrap_or(0),
+                        self.fleets.len(),
+                    )
+                ))
+            }
+            Action::Select if self.is_focused => {
+                self.is_focused = false;
+                if let Some((id, _, _)) = self.list_state.selected()
+                    .and_then(|i| self.fleets.get(i)) {
+                    self.selected_fleet = Some(*id);
+                    return Ok(Some(Action::ScheduleLoadFleetInfo(*id)))
+                }
+            }
+            Action::IngameTick => {
+                return Ok(Some(match self.selected_fleet {
+                    Some(id) => Action::ScheduleLoadFleetInfo(id),
+                    None => Action::ScheduleLoadFleets,
+                }))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(20),
+                Constraint::Percentage(40),
+                Constraint::Percentage(40),
+            ],
+        ).split(v_chunks[1]);
+
+        let fleets_list = widgets::List::new(
+            self.fleets.iter().map(|(_, name, color)| {
+                Line::styled(name.clone(), Style::default().fg(*color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Fleets")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(
+                        if self.is_focused {
+                            Style::default().fg(Color::LightBlue)
+                        } else {
+                            Style::default()
+                        }
+                    )
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let info = Paragraph::new(
+            self.info.iter().flatten().map(|l| Line::from(l.clone())).collect::<Vec<Line>>()
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Fleet")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let mut report_lines = Vec::<Line>::new();
+        self.reports.iter().for_each(|report| {
+            report.iter().enumerate().for_each(|(i, line)| {
+                report_lines.push(if i == 0 {
+                    Line::styled(line.clone(), Style::default().fg(Color::LightRed))
+                } else {
+                    Line::from(line.clone())
+                })
+            });
+            report_lines.push(Line::from(""));
+        });
+
+        let reports = Paragraph::new(report_lines)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Combat reports")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let help = Paragraph::new(
+            if self.is_focused {
+                "Use arrows to highlight a fleet, then press <Enter> to select it"
+            } else {
+                "Press <Alt+S> to select a fleet"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(fleets_list, h_chunks[0], &mut self.list_state);
+        f.render_widget(info, h_chunks[1]);
+        f.render_widget(reports, h_chunks[2]);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Fleets
+    }
+}
@@ -0,0 +1,90 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::mode::Mode;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `HelpPopup` is a window shown over every tab, which lists every key bound in the mode the
+/// player was in when they opened it
+///
+/// **Fields**
+/// - help (`Option<(Mode, Vec<(String, String)>)>`) - holds the mode and the keys of every
+///   action bound in it, the popup is hidden if it is `None`
+#[derive(Default)]
+pub struct HelpPopup {
+    help: Option<(Mode, Vec<(String, String)>)>,
+}
+
+impl Component for HelpPopup {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if let Action::LoadHelp(help) = action {
+            self.help = help;
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let (mode, hints) = match self.help.as_ref() {
+            Some(help) => help,
+            None => return Ok(()),
+        };
+
+        let popup_area = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ],
+        ).split(
+            Layout::new(
+                Direction::Vertical,
+                vec![
+                    Constraint::Percentage(20),
+                    Constraint::Percentage(60),
+                    Constraint::Percentage(20),
+                ],
+            ).split(area)[1]
+        )[1];
+
+        let key_width = hints.iter().map(|(keys, _)| keys.len()).max().unwrap_or(0);
+        let mut lines: Vec<Line> = hints.iter().map(|(keys, description)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{keys:<key_width$}  "),
+                    Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(description.clone()),
+            ])
+        }).collect();
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            "The keys can be changed in the keybindings section of the configuration file",
+            Style::default().fg(Color::Gray),
+        ));
+
+        let help = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title(format!("Keys: {mode:?}"))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Double)
+                    .border_style(Style::default().fg(Color::LightYellow))
+            );
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(help, popup_area);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, _tab: &Tabs) -> bool {
+        true
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use ratatui::layout::Rect;
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `KeyHints` is the footer line below every tab, which shows the keys of the most important
+/// actions bound in the current mode, i.e. the keys of the focused widget. The hints which don't
+/// fit the line are left out, the help window lists all of them
+///
+/// **Fields**
+/// - hints (`Vec<(String, String)>`) - holds the keys of the most important actions bound in the
+///   current mode and the description of the action, the most important first
+#[derive(Default)]
+pub struct KeyHints {
+    hints: Vec<(String, String)>,
+}
+
+impl Component for KeyHints {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if let Action::LoadKeyHints(hints) = action {
+            self.hints = hints;
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let mut spans = Vec::<Span>::new();
+        let mut width = 0;
+        for (keys, description) in self.hints.iter() {
+            let hint = [
+                Span::styled(
+                    keys.clone(),
+                    Style::default().fg(Color::LightYellow).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(" {description}  ")),
+            ];
+            width += hint.iter().map(|s| s.width()).sum::<usize>();
+            if width > area.width as usize {
+                break;
+            }
+            spans.extend(hint);
+        }
+        let hints = Paragraph::new(Line::from(spans));
+
+        f.render_widget(hints, area);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, _tab: &Tabs) -> bool {
+        true
+    }
+
+    fn is_footer(&self) -> bool {
+        true
+    }
+}
\ No newline at end of file
@@ -0,0 +1,196 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `ModsMenu` is a tab, that lets the player enable and disable the installed mods and shows the
+/// mods the current game was started with
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - mods (`Vec<(String, bool, Vec<String>)>`) - holds the id of every installed mod, whether it
+///   is enabled and its details
+/// - game_mods (`Vec<String>`) - holds the id and the version of the mods of the current game
+/// - mod_list_state (`ListState`) - holds the current state of the mods list widget
+/// - mod_list_focused (`bool`) - `true` if the mods list is active, `false` otherwise
+pub struct ModsMenu {
+    is_initialised: bool,
+    mods: Vec<(String, bool, Vec<String>)>,
+    game_mods: Vec<String>,
+    mod_list_state: ListState,
+    mod_list_focused: bool,
+}
+
+impl Default for ModsMenu {
+    fn default() -> Self {
+        let mut mod_list_state = ListState::default();
+        mod_list_state.select(Some(0));
+        Self {
+            is_initialised: false,
+            mods: Vec::new(),
+            game_mods: Vec::new(),
+            mod_list_state,
+            mod_list_focused: false,
+        }
+    }
+}
+
+impl ModsMenu {
+    fn get_highlighted(&self) -> Option<&(String, bool, Vec<String>)> {
+        self.mod_list_state.selected().and_then(|i| self.mods.get(i))
+    }
+}
+
+impl Component for ModsMenu {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadMods))
+        }
+
+        match action {
+            Action::LoadMods(mods) => {
+                self.mods = mods;
+                if self.mod_list_state.selected().unwrap_or(0) >= self.mods.len() {
+                    self.mod_list_state.select(Some(0));
+                }
+            }
+            Action::LoadGameMods(game_mods) => {
+                self.game_mods = game_mods;
+            }
+            Action::StartSelecting => {
+                self.mod_list_focused = true;
+            }
+            Action::SelectPrevious if self.mod_list_focused => {
+                self.mod_list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.mod_list_state.selected().unwrap_or(0),
+                        self.mods.len(),
+                    )
+                ))
+            }
+            Action::SelectNext if self.mod_list_focused => {
+                self.mod_list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.mod_list_state.selected().unwrap_or(0),
+                        self.mods.len(),
+                    )
+                ))
+            }
+            Action::Select if self.mod_list_focused => {
+                self.mod_list_focused = false;
+                if let Some((id, is_enabled, _)) = self.get_highlighted() {
+                    return Ok(Some(Action::SetModEnabled((id.clone(), !is_enabled))))
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+            ],
+        ).split(v_chunks[1]);
+
+        let mod_list = widgets::List::new(
+            self.mods.iter().map(|(id, is_enabled, _)| {
+                Line::styled(
+                    format!("[{}] {id}", if *is_enabled { "x" } else { " " }),
+                    Style::default().fg(if *is_enabled { Color::LightGreen } else { Color::Gray }),
+                )
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Installed mods")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(if self.mod_list_focused {
+                        Style::default().fg(Color::LightBlue)
+                    } else {
+                        Style::default()
+                    })
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let details = Paragraph::new(
+            match self.get_highlighted() {
+                Some((_, _, details)) => details.iter().map(|l| Line::from(l.clone())).collect(),
+                None => vec![Line::from("No mods are installed")],
+            }
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Details")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let game_mods = Paragraph::new(
+            if self.game_mods.is_empty() {
+                vec![Line::from("Base game only")]
+            } else {
+                self.game_mods.iter().map(|m| Line::from(m.clone())).collect()
+            }
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Mods of this game")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let help = Paragraph::new(
+            if self.mod_list_focused {
+                "Use arrows to highlight a mod, then press <Enter> to enable or disable it for the \
+                next game"
+            } else {
+                "Press <Alt+S> to select a mod"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(mod_list, h_chunks[0], &mut self.mod_list_state);
+        f.render_widget(details, h_chunks[1]);
+        f.render_widget(game_mods, h_chunks[2]);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Mods
+    }
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::collections::VecDeque;
+
+use ratatui::layout::Rect;
+use ratatui::prelude::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, BorderType, Clear, Paragraph};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// Amount of notifications shown at once, the oldest ones are dropped first
+const MAX_NOTIFICATIONS: usize = 5;
+/// Amount of interface ticks a notification stays on the screen for
+const NOTIFICATION_TICKS: u32 = 60;
+/// Width of the notifications window, in cells
+const WIDTH: u16 = 60;
+
+/// `Notifications` shows the latest events of the game as toasts in the top right corner of
+/// every tab. The toasts fade away after a while, even when the game is paused
+///
+/// **Fields**
+/// - toasts (`VecDeque<(String, Color, u32)>`) - holds the text and color of every shown
+///   notification along with the amount of ticks it stays for
+#[derive(Default)]
+pub struct Notifications {
+    toasts: VecDeque<(String, Color, u32)>,
+}
+
+impl Component for Notifications {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        match action {
+            Action::LoadNotifications(notifications) => {
+                self.toasts.extend(
+                    notifications.into_iter().map(|(text, color)| (text, color, NOTIFICATION_TICKS))
+                );
+                while self.toasts.len() > MAX_NOTIFICATIONS {
+                    self.toasts.pop_front();
+                }
+            }
+            Action::Tick => {
+                self.toasts.iter_mut().for_each(|(_, _, ticks)| *ticks -= 1);
+                self.toasts.retain(|(_, _, ticks)| *ticks > 0);
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        if self.toasts.is_empty() {
+            return Ok(())
+        }
+
+        // Below the tabs, so the tab names stay visible
+        let width = WIDTH.min(area.width);
+        let height = (self.toasts.len() as u16 + 2).min(area.height.saturating_sub(3));
+        let toast_area = Rect::new(area.x + area.width - width, area.y + 3, width, height);
+
+        let toasts = Paragraph::new(
+            self.toasts.iter().map(|(text, color, _)| {
+                Line::styled(text.clone(), Style::default().fg(*color))
+            }).collect::<Vec<Line>>()
+        )
+            .block(
+                Block::default()
+                    .title("Notifications")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        f.render_widget(Clear, toast_area);
+        f.render_widget(toasts, toast_area);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, _tab: &Tabs) -> bool {
+        true
+    }
+}
\ No newline at end of file
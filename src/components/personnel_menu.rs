@@ -0,0 +1,257 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::game::personnel::assignment::Assignment;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `PersonnelMenu` is a tab, that shows the officers of the player and lets the player send them
+/// to the colonies, researches and fleets
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - officers (`Vec<(u32, String, Color)>`) - holds the id, name and color of every officer
+/// - officer_list_state (`ListState`) - holds the current state of the officers list widget
+/// - selected_officer (`Option<u32>`) - holds the id of the selected officer or `None`
+/// - assignments (`Vec<(Option<Assignment>, String)>`) - holds the posts the selected officer
+///   can be sent to and their descriptions
+/// - assignment_list_state (`ListState`) - holds the current state of the posts list widget
+/// - officer_list_focused (`bool`) - `true` if the officers list is active, `false` otherwise
+/// - assignment_list_focused (`bool`) - `true` if the posts list is active, `false` otherwise
+/// - info (`Vec<Vec<String>>`) - holds the properties of the selected officer
+pub struct PersonnelMenu {
+    is_initialised: bool,
+    officers: Vec<(u32, String, Color)>,
+    officer_list_state: ListState,
+    selected_officer: Option<u32>,
+    assignments: Vec<(Option<Assignment>, String)>,
+    assignment_list_state: ListState,
+    officer_list_focused: bool,
+    assignment_list_focused: bool,
+    info: Vec<Vec<String>>,
+}
+
+impl Default for PersonnelMenu {
+    fn default() -> Self {
+        let mut officer_list_state = ListState::default();
+        officer_list_state.select(Some(0));
+        let mut assignment_list_state = ListState::default();
+        assignment_list_state.select(Some(0));
+        Self {
+            is_initialised: false,
+            officers: Vec::new(),
+            officer_list_state,
+            selected_officer: None,
+            assignments: Vec::new(),
+            assignment_list_state,
+            officer_list_focused: false,
+            assignment_list_focused: false,
+            info: vec![vec![String::from("Select an officer")]],
+        }
+    }
+}
+
+impl Component for PersonnelMenu {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadOfficers))
+        }
+
+        match action {
+            Action::LoadOfficers(officers) => {
+                self.officers = officers;
+                if self.officer_list_state.selected().unwrap_or(0) >= self.officers.len() {
+                    self.officer_list_state.select(Some(0));
+                }
+            }
+            Action::LoadOfficerInfo(info) => {
+                self.info = info;
+            }
+            Action::LoadAssignmentOptions(assignments) => {
+                self.assignments = assignments;
+                if self.assignment_list_state.selected().unwrap_or(0) >= self.assignments.len() {
+                    self.assignment_list_state.select(Some(0));
+                }
+            }
+            Action::StartSelecting => {
+                self.officer_list_focused = true;
+                self.assignment_list_focused = false;
+            }
+            Action::SelectPrevious => {
+                if self.officer_list_focused {
+                    self.officer_list_state.select(Some(
+                        widget_utils::select_prev_in_list(
+                            self.officer_list_state.selected().unwrap_or(0),
+                            self.officers.len(),
+                        )
+                    ))
+                } else if self.assignment_list_focused {
+                    self.assignment_list_state.select(Some(
+                        widget_utils::select_prev_in_list(
+                            self.assignment_list_state.selected().unwrap_or(0),
+                            self.assignments.len(),
+                        )
+                    ))
+                }
+            }
+            Action::SelectNext => {
+                if self.officer_list_focused {
+                    self.officer_list_state.select(Some(
+                        widget_utils::select_next_in_list(
+                            self.officer_list_state.selected().unwrap_or(0),
+                            self.officers.len(),
+                        )
+                    ))
+                } else if self.assignment_list_focused {
+                    self.assignment_list_state.select(Some(
+                        widget_utils::select_next_in_list(
+                            self.assignment_list_state.selected().unwrap_or(0),
+                            self.assignments.len(),
+                        )
+                    ))
+                }
+            }
+            Action::ContinueSelecting if self.officer_list_focused => {
+                self.officer_list_focused = false;
+                if let Some((id, _, _)) = self.officer_list_state.selected()
+                    .and_then(|i| self.officers.get(i)) {
+                    self.assignment_list_focused = true;
+                    self.assignment_list_state.select(Some(0));
+                    self.selected_officer = Some(*id);
+                    return Ok(Some(Action::ScheduleLoadOfficerInfo(*id)))
+                }
+            }
+            Action::Select if self.assignment_list_focused => {
+                self.assignment_list_focused = false;
+                let assignment = self.assignment_list_state.selected()
+                    .and_then(|i| self.assignments.get(i));
+                if let (Some(id), Some((assignment, _))) = (self.selected_officer, assignment) {
+                    return Ok(Some(Action::AssignOfficer((id, assignment.clone()))))
+                }
+            }
+            Action::IngameTick => {
+                return Ok(Some(match self.selected_officer {
+                    Some(id) => Action::ScheduleLoadOfficerInfo(id),
+                    None => Action::ScheduleLoadOfficers,
+                }))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+            ],
+        ).split(v_chunks[1]);
+
+        let focused_style = |is_focused: bool| {
+            if is_focused {
+                Style::default().fg(Color::LightBlue)
+            } else {
+                Style::default()
+            }
+        };
+
+        let officer_list = widgets::List::new(
+            self.officers.iter().map(|(_, name, color)| {
+                Line::styled(name.clone(), Style::default().fg(*color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Officers")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(focused_style(self.officer_list_focused))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let info = Paragraph::new(
+            self.info.iter()
+                .flat_map(|b| b.iter().map(|l| Line::from(l.clone())).chain([Line::from("")]))
+                .collect::<Vec<Line>>()
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Service record")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let assignment_list = widgets::List::new(
+            self.assignments.iter().map(|(assignment, description)| {
+                Line::styled(
+                    description.clone(),
+                    Style::default().fg(match assignment {
+                        Some(_) => Color::White,
+                        None => Color::LightYellow,
+                    }),
+                )
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Assignments")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(focused_style(self.assignment_list_focused))
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let help = Paragraph::new(
+            if self.officer_list_focused {
+                "Use arrows to highlight an officer, then press <Enter> to select them"
+            } else if self.assignment_list_focused {
+                "Use arrows to highlight a post, then press <Enter> to send the officer there"
+            } else {
+                "Press <Alt+S> to select an officer"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(officer_list, h_chunks[0], &mut self.officer_list_state);
+        f.render_widget(info, h_chunks[1]);
+        f.render_stateful_widget(assignment_list, h_chunks[2], &mut self.assignment_list_state);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Personnel
+    }
+}
\ No newline at end of file
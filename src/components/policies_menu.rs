@@ -0,0 +1,197 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets;
+use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph, Wrap};
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::components::utils::widget_utils;
+use crate::game::celestial_bodies::Displayable;
+use crate::game::empires::policy::Policy;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `PoliciesMenu` is a tab, that lets the player enact and repeal the policies of the empire and
+/// shows how stable the colonies are
+///
+/// **Fields**
+/// - is_initialised (`bool`) - is used for initial data loading, `false` if data was never
+///   loaded, `true` otherwise
+/// - policies (`Vec<(Policy, bool)>`) - holds every policy and whether it is enacted
+/// - policy_list_state (`ListState`) - holds the current state of the policies list widget
+/// - policy_list_focused (`bool`) - `true` if the policies list is active, `false` otherwise
+/// - stability (`Vec<Vec<String>>`) - holds the stability of every colony
+pub struct PoliciesMenu {
+    is_initialised: bool,
+    policies: Vec<(Policy, bool)>,
+    policy_list_state: ListState,
+    policy_list_focused: bool,
+    stability: Vec<Vec<String>>,
+}
+
+impl Default for PoliciesMenu {
+    fn default() -> Self {
+        let mut policy_list_state = ListState::default();
+        policy_list_state.select(Some(0));
+        Self {
+            is_initialised: false,
+            policies: Vec::new(),
+            policy_list_state,
+            policy_list_focused: false,
+            stability: Vec::new(),
+        }
+    }
+}
+
+impl PoliciesMenu {
+    fn get_highlighted(&self) -> Option<&(Policy, bool)> {
+        self.policy_list_state.selected().and_then(|i| self.policies.get(i))
+    }
+}
+
+impl Component for PoliciesMenu {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadPolicies))
+        }
+
+        match action {
+            Action::LoadPolicies(policies) => {
+                self.policies = policies;
+                if self.policy_list_state.selected().unwrap_or(0) >= self.policies.len() {
+                    self.policy_list_state.select(Some(0));
+                }
+            }
+            Action::LoadStabilityReport(stability) => {
+                self.stability = stability;
+            }
+            Action::StartSelecting => {
+                self.policy_list_focused = true;
+            }
+            Action::SelectPrevious if self.policy_list_focused => {
+                self.policy_list_state.select(Some(
+                    widget_utils::select_prev_in_list(
+                        self.policy_list_state.selected().unwrap_or(0),
+                        self.policies.len(),
+                    )
+                ))
+            }
+            Action::SelectNext if self.policy_list_focused => {
+                self.policy_list_state.select(Some(
+                    widget_utils::select_next_in_list(
+                        self.policy_list_state.selected().unwrap_or(0),
+                        self.policies.len(),
+                    )
+                ))
+            }
+            Action::Select if self.policy_list_focused => {
+                self.policy_list_focused = false;
+                if let Some((policy, is_enacted)) = self.get_highlighted() {
+                    return Ok(Some(Action::SetPolicy((*policy, !is_enacted))))
+                }
+            }
+            Action::IngameTick => {
+                return Ok(Some(Action::ScheduleLoadPolicies))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3)
+            ],
+        ).split(area);
+
+        let h_chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+                Constraint::Percentage(40),
+            ],
+        ).split(v_chunks[1]);
+
+        let policy_list = widgets::List::new(
+            self.policies.iter().map(|(policy, is_enacted)| {
+                Line::styled(
+                    format!("[{}] {}", if *is_enacted { "x" } else { " " }, policy.get_name()),
+                    Style::default().fg(policy.get_menu_color()),
+                )
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Policies")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(if self.policy_list_focused {
+                        Style::default().fg(Color::LightBlue)
+                    } else {
+                        Style::default()
+                    })
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol(">> ")
+            .repeat_highlight_symbol(false)
+            .direction(ListDirection::TopToBottom);
+
+        let effects = Paragraph::new(
+            self.get_highlighted()
+                .map(|(policy, _)| policy.get_description())
+                .unwrap_or_default()
+                .into_iter()
+                .map(Line::from)
+                .collect::<Vec<Line>>()
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Effects")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let stability = Paragraph::new(
+            self.stability.iter().flatten().map(|l| Line::from(l.clone())).collect::<Vec<Line>>()
+        )
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Stability")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let help = Paragraph::new(
+            if self.policy_list_focused {
+                "Use arrows to highlight a policy, then press <Enter> to enact or repeal it"
+            } else {
+                "Press <Alt+S> to select a policy"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_stateful_widget(policy_list, h_chunks[0], &mut self.policy_list_state);
+        f.render_widget(effects, h_chunks[1]);
+        f.render_widget(stability, h_chunks[2]);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::Policies
+    }
+}
\ No newline at end of file
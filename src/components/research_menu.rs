@@ -1,13 +1,16 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::layout::Constraint::{Fill, Length};
 use ratatui::prelude::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets;
 use ratatui::widgets::{Block, Borders, BorderType, ListDirection, ListState, Paragraph};
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::Component;
 use crate::components::utils::widget_utils;
+use crate::mode::Mode;
 use crate::tabs::Tabs;
 use crate::tui::Frame;
 
@@ -24,6 +27,10 @@ pub struct ResearchMenu {
     dependency_info: Option<Vec<Vec<(String, bool)>>>,
     research_progress: u32,
     gauge_text: String,
+    log: Vec<(String, Color)>,
+    action_tx: Option<UnboundedSender<Action>>,
+    field_list_area: Rect,
+    research_list_area: Rect,
 }
 
 impl Default for ResearchMenu {
@@ -45,12 +52,61 @@ impl Default for ResearchMenu {
             info: Vec::new(),
             dependency_info: None,
             research_progress: 0,
-            gauge_text: String::from("")
+            gauge_text: String::from(""),
+            log: Vec::new(),
+            action_tx: None,
+            field_list_area: Rect::default(),
+            research_list_area: Rect::default(),
         }
     }
 }
 
 impl Component for ResearchMenu {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> color_eyre::Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    /// A click on a field or a research highlights it and selects it, as if it was selected
+    /// with the keyboard
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> color_eyre::Result<Option<Action>> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Ok(None)
+        }
+
+        let field = widget_utils::get_list_entry_at(
+            self.field_list_area,
+            self.field_list_state.offset(),
+            self.field_list.iter().map(|_| 1),
+            mouse.column,
+            mouse.row,
+        );
+        let research = widget_utils::get_list_entry_at(
+            self.research_list_area,
+            self.research_list_state.offset(),
+            self.research_list.iter().map(|_| 1),
+            mouse.column,
+            mouse.row,
+        );
+
+        let (mode, action) = if let Some(field) = field {
+            self.field_list_state.select(Some(field));
+            (Mode::SelectingResearchField, Action::ContinueSelecting)
+        } else if let Some(research) = research {
+            self.research_list_state.select(Some(research));
+            self.field_list_focused = false;
+            self.research_list_focused = true;
+            (Mode::SelectingResearch, Action::Select)
+        } else {
+            return Ok(None)
+        };
+
+        if let Some(tx) = self.action_tx.as_ref() {
+            tx.send(Action::SetMode(mode))?;
+        }
+        Ok(Some(action))
+    }
+
     fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
         if !self.is_initialised {
             self.is_initialised = true;
@@ -68,6 +124,14 @@ impl Component for ResearchMenu {
                 }
             },
 
+            Action::IngameTick => {
+                return Ok(Some(Action::ScheduleLoadResearchLog))
+            }
+
+            Action::LoadResearchLog(log) => {
+                self.log = log;
+            }
+
             Action::LoadResearchFields(fields) => {
                 self.field_list = fields;
             }
@@ -247,6 +311,8 @@ impl Component for ResearchMenu {
             .repeat_highlight_symbol(false)
             .direction(ListDirection::TopToBottom);
 
+        self.field_list_area = chunks[0];
+        self.research_list_area = chunks[1];
         f.render_stateful_widget(fields_list, chunks[0], &mut self.field_list_state);
         f.render_stateful_widget(research_list, chunks[1], &mut self.research_list_state);
 
@@ -256,6 +322,7 @@ impl Component for ResearchMenu {
             Direction::Vertical,
             vec![
                 Fill(1),
+                Length(8),
                 Length(5),
                 Length(3),
             ],
@@ -351,8 +418,21 @@ impl Component for ResearchMenu {
             .label(self.gauge_text.clone());
 
 
+        let log = widgets::List::new(
+            self.log.iter().map(|(text, color)| {
+                Line::styled(text, Style::default().fg(*color))
+            })
+        )
+            .block(
+                Block::default()
+                    .title("Research log")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
         f.render_widget(info, info_chunks[0]);
-        f.render_widget(research_progress, info_chunks[1]);
+        f.render_widget(log, info_chunks[1]);
+        f.render_widget(research_progress, info_chunks[2]);
 
         let help_key_style = Style::default().fg(Color::LightBlue).add_modifier(Modifier::BOLD);
 
@@ -373,7 +453,7 @@ impl Component for ResearchMenu {
                 .border_type(BorderType::Rounded)
         );
 
-        f.render_widget(help, info_chunks[2]);
+        f.render_widget(help, info_chunks[3]);
 
         Ok(())
     }
@@ -0,0 +1,223 @@
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, BorderType, Paragraph, Wrap};
+use ratatui::widgets::canvas::Canvas;
+
+use crate::action::Action;
+use crate::components::Component;
+use crate::game::research::research_graph::ResearchGraph;
+use crate::tabs::Tabs;
+use crate::tui::Frame;
+
+/// `ResearchTree` is a tab that draws the whole technology tree as a layered graph and lets the
+/// player pick a goal technology to see what has to be researched to unlock it
+///
+/// **Fields**
+/// - graph (`ResearchGraph`) - holds the laid out technology tree
+/// - cursor (`Option<String>`) - holds the id of the highlighted technology
+/// - goal (`Option<String>`) - holds the id of the goal technology or `None` if no goal is set
+/// - goal_path (`Vec<String>`) - holds the ids of the technologies leading to the goal
+/// - is_focused (`bool`) - `true` if the player is navigating the graph, `false` otherwise
+#[derive(Default)]
+pub struct ResearchTree {
+    is_initialised: bool,
+    graph: ResearchGraph,
+    cursor: Option<String>,
+    goal: Option<String>,
+    goal_path: Vec<String>,
+    is_focused: bool,
+}
+
+impl ResearchTree {
+    fn set_graph(&mut self, graph: ResearchGraph) {
+        self.graph = graph;
+        if self.cursor.is_none() {
+            self.cursor = self.graph.get_node_at(0, 0).map(|n| n.id.clone());
+        }
+        self.goal_path = match self.goal.clone() {
+            Some(goal) => self.graph.get_path_to(&goal),
+            None => Vec::new(),
+        };
+    }
+
+    /// Moves the cursor by the given amount of layers and rows, keeping it inside the graph
+    fn move_cursor(&mut self, d_layer: isize, d_row: isize) {
+        let (layer, row) = match self.cursor.as_ref().and_then(|id| self.graph.get_node(id)) {
+            Some(node) => (node.layer, node.row),
+            None => return,
+        };
+
+        let layer_count = self.graph.get_layer_count() as isize;
+        let new_layer = (layer as isize + d_layer).clamp(0, layer_count - 1) as usize;
+        let layer_size = self.graph.get_layer_size(new_layer) as isize;
+        let new_row = (row as isize + d_row).clamp(0, layer_size - 1) as usize;
+
+        if let Some(node) = self.graph.get_node_at(new_layer, new_row) {
+            self.cursor = Some(node.id.clone());
+        }
+    }
+}
+
+impl Component for ResearchTree {
+    fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
+        if !self.is_initialised {
+            self.is_initialised = true;
+            return Ok(Some(Action::ScheduleLoadResearchGraph));
+        }
+
+        match action {
+            Action::IngameTick => {
+                return Ok(Some(Action::ScheduleLoadResearchGraph));
+            }
+            Action::LoadResearchGraph(graph) => {
+                self.set_graph(graph);
+            }
+            Action::StartSelecting => {
+                self.is_focused = true;
+            }
+            Action::Up => { self.move_cursor(0, -1) }
+            Action::Down => { self.move_cursor(0, 1) }
+            Action::Left => { self.move_cursor(-1, 0) }
+            Action::Right => { self.move_cursor(1, 0) }
+            Action::Select if self.is_focused => {
+                self.is_focused = false;
+                self.goal = self.cursor.clone();
+                self.goal_path = match self.goal.clone() {
+                    Some(goal) => self.graph.get_path_to(&goal),
+                    None => Vec::new(),
+                };
+            }
+            Action::MainAction => {
+                if let Some(next) = self.goal_path.first() {
+                    return Ok(Some(Action::StartResearch(next.clone())));
+                }
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn draw(&mut self, f: &mut Frame<'_>, area: Rect) -> color_eyre::Result<()> {
+        let v_chunks = Layout::new(
+            Direction::Vertical,
+            vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ],
+        ).split(area);
+
+        let chunks = Layout::new(
+            Direction::Horizontal,
+            vec![
+                Constraint::Fill(1),
+                Constraint::Percentage(25),
+            ],
+        ).split(v_chunks[1]);
+
+        // One canvas unit is one terminal cell, centered on the highlighted technology
+        let (center_x, center_y) = self.cursor.as_ref()
+            .and_then(|id| self.graph.get_node(id))
+            .map(ResearchGraph::get_node_position)
+            .unwrap_or((0.0, 0.0));
+        let half_width = chunks[0].width.saturating_sub(2) as f64 / 2.0;
+        let half_height = chunks[0].height.saturating_sub(2) as f64 / 2.0;
+
+        let tree = Canvas::default()
+            .block(
+                Block::default()
+                    .title("Technology tree")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(
+                        if self.is_focused {
+                            Style::default().fg(Color::LightBlue)
+                        } else {
+                            Style::default()
+                        }
+                    )
+            )
+            .x_bounds([center_x - half_width / 2.0, center_x + half_width * 1.5])
+            .y_bounds([center_y - half_height, center_y + half_height])
+            .paint(|ctx| {
+                self.graph.draw_image(ctx, self.cursor.as_ref(), &self.goal_path);
+            });
+
+        let mut info_text = Vec::<Line>::new();
+        if let Some(node) = self.cursor.as_ref().and_then(|id| self.graph.get_node(id)) {
+            info_text.push(Line::styled(
+                node.name.clone(),
+                Style::default().fg(node.color).add_modifier(Modifier::BOLD),
+            ));
+            info_text.push(Line::from(format!("Field: {}", node.field)));
+            info_text.push(Line::from(format!("Cost: {} points", node.cost)));
+        }
+
+        info_text.push(Line::from(""));
+        match self.goal.as_ref().and_then(|id| self.graph.get_node(id)) {
+            Some(goal) => {
+                info_text.push(Line::from(format!("Goal: {}", goal.name)));
+                if self.goal_path.is_empty() {
+                    info_text.push(Line::styled(
+                        "Already researched",
+                        Style::default().fg(Color::LightCyan),
+                    ));
+                } else {
+                    info_text.push(Line::from(format!(
+                        "Total cost: {} points ({} remaining)",
+                        self.graph.get_total_cost(&self.goal_path),
+                        self.graph.get_remaining_cost(&self.goal_path),
+                    )));
+                    info_text.push(Line::from(""));
+                    self.goal_path.iter()
+                        .filter_map(|id| self.graph.get_node(id))
+                        .enumerate()
+                        .for_each(|(i, n)| {
+                            info_text.push(Line::from(Span::styled(
+                                format!("{:>3}. {} ({})", i + 1, n.name, n.cost),
+                                Style::default().fg(n.color),
+                            )));
+                        });
+                }
+            }
+            None => {
+                info_text.push(Line::styled("No goal selected", Style::default().fg(Color::Red)));
+            }
+        }
+
+        let info = Paragraph::new(info_text)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Goal")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            );
+
+        let help = Paragraph::new(
+            if self.is_focused {
+                "Use arrows to move between technologies, then press <Enter> to set the goal"
+            } else {
+                "Press <Alt+S> to navigate the tree, <Alt+R> to start researching the next \
+                technology towards the goal"
+            }
+        ).block(
+            Block::default()
+                .title("Controls help")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+        );
+
+        f.render_widget(tree, chunks[0]);
+        f.render_widget(info, chunks[1]);
+        f.render_widget(help, v_chunks[2]);
+
+        Ok(())
+    }
+
+    fn is_drawn_in_tab(&self, tab: &Tabs) -> bool {
+        *tab == Tabs::ResearchTree
+    }
+}
@@ -1,12 +1,17 @@
 use color_eyre::owo_colors::OwoColorize;
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, BorderType, List, ListState};
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::Component;
-use crate::components::utils::widget_utils::{select_next_in_list, select_prev_in_list};
+use crate::components::utils::widget_utils::{
+    get_list_entry_at, select_next_in_list, select_prev_in_list,
+};
+use crate::mode::Mode;
 use crate::tabs::Tabs;
 use crate::tui::Frame;
 
@@ -31,9 +36,54 @@ pub struct ShipModuleDesigner {
     types_list_state: ListState,
     modules_list_state: ListState,
     state: WidgetState,
+    action_tx: Option<UnboundedSender<Action>>,
+    types_list_area: Rect,
+    modules_list_area: Rect,
 }
 
 impl Component for ShipModuleDesigner {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> color_eyre::Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    /// A click on a module type shows its modules, a click on a module highlights it
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> color_eyre::Result<Option<Action>> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Ok(None)
+        }
+
+        let module_type = get_list_entry_at(
+            self.types_list_area,
+            self.types_list_state.offset(),
+            self.module_types.iter().map(|_| 1),
+            mouse.column,
+            mouse.row,
+        );
+        if let Some(module_type) = module_type {
+            self.types_list_state.select(Some(module_type));
+            self.modules_list_state.select(None);
+            if let Some(tx) = self.action_tx.as_ref() {
+                tx.send(Action::SetMode(Mode::SelectingShipModuleType))?;
+            }
+            return Ok(Some(Action::ContinueSelecting))
+        }
+
+        let module = get_list_entry_at(
+            self.modules_list_area,
+            self.modules_list_state.offset(),
+            self.modules.iter().map(|_| 1),
+            mouse.column,
+            mouse.row,
+        );
+        if let Some(module) = module {
+            self.state = WidgetState::SelectingModule;
+            self.modules_list_state.select(Some(module));
+            return Ok(Some(Action::SetMode(Mode::SelectingShipModule)))
+        }
+        Ok(None)
+    }
+
     fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
         if !self.is_initialised {
             self.is_initialised = true;
@@ -51,6 +101,10 @@ impl Component for ShipModuleDesigner {
             }
             Action::ContinueSelecting => {
                 self.state = WidgetState::SelectingModule;
+                if let Some((module_type, _)) = self.types_list_state.selected()
+                    .and_then(|i| self.module_types.get(i)) {
+                    return Ok(Some(Action::ScheduleLoadShipModulesForType(module_type.clone())))
+                }
                 return Ok(None)
             }
             Action::SelectNext => {
@@ -104,6 +158,12 @@ impl Component for ShipModuleDesigner {
                 }
             }
             Action::LoadShipModuleTypes(types) => { self.module_types = types }
+            Action::LoadShipModulesForType(modules) => {
+                self.modules = modules;
+                if self.modules_list_state.selected().is_none() && !self.modules.is_empty() {
+                    self.modules_list_state.select(Some(0))
+                }
+            }
             _ => {}
         }
 
@@ -154,8 +214,36 @@ impl Component for ShipModuleDesigner {
             );
 
 
+        self.types_list_area = a_chunks[0];
         f.render_stateful_widget(types_list, a_chunks[0], &mut self.types_list_state);
 
+        let modules_list = List::new(
+            self.modules.iter().map(
+                |(i, c)| {
+                    Line::styled(
+                        i,
+                        Style::default().fg(*c),
+                    )
+                }
+            )
+        )
+            .highlight_symbol(">>")
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default()
+                        .fg(if self.state == WidgetState::SelectingModule {
+                            Color::LightBlue
+                        } else {
+                            Color::White
+                        }))
+            );
+
+        self.modules_list_area = a_chunks[1];
+        f.render_stateful_widget(modules_list, a_chunks[1], &mut self.modules_list_state);
+
         Ok(())
     }
 
@@ -1,20 +1,26 @@
 use color_eyre::owo_colors::OwoColorize;
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Text;
+use ratatui::text::{Span, Text};
 use ratatui::widgets::{Block, Borders, BorderType, List, ListDirection, ListState, Paragraph, Row, Table};
-use ratatui::widgets::canvas::Canvas;
+use ratatui::widgets::canvas::{Canvas, Points};
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::action::Action;
 use crate::components::Component;
+use crate::components::utils::widget_utils;
 use crate::game::celestial_bodies::{Displayable, Orbitable};
 use crate::game::celestial_bodies::solar_system::SolarSystem;
+use crate::game::sensors::knowledge::Contact;
+use crate::mode::Mode;
 use crate::tabs::Tabs;
 use crate::tui::Frame;
 
 pub struct SystemMenu {
     state: ListState,
     system: Option<SolarSystem>,
+    contacts: Vec<Contact>,
     is_focused: bool,
     map_focused: bool,
     list_length: usize,
@@ -22,6 +28,11 @@ pub struct SystemMenu {
     map_shift_x: f64,
     map_shift_y: f64,
     map_zoom: f64,
+    action_tx: Option<UnboundedSender<Action>>,
+    list_area: Rect,
+    map_area: Rect,
+    /// Last position of the mouse while the map is dragged
+    drag_position: Option<(u16, u16)>,
 }
 
 impl Default for SystemMenu {
@@ -33,12 +44,17 @@ impl Default for SystemMenu {
             list_length: 0,
             state,
             system: None,
+            contacts: Vec::new(),
             is_focused: false,
             map_focused: false,
             properties: vec![],
             map_shift_x: 0.0,
             map_shift_y: 0.0,
             map_zoom: 1.0,
+            action_tx: None,
+            list_area: Rect::default(),
+            map_area: Rect::default(),
+            drag_position: None,
         }
     }
 }
@@ -48,9 +64,77 @@ impl SystemMenu {
         self.list_length = 1 + system.get_n_planets();
         self.system = Some(system);
     }
+
+    /// Shows the properties of a body of the list, the star is the first
+    fn show_body(&mut self, index: usize) {
+        let system = match self.system.as_ref() {
+            Some(system) => system,
+            None => return,
+        };
+        if index == 0 {
+            self.properties = system.get_star().get_properties();
+        } else if let Some(planet) = system.get_satellites().get(index - 1) {
+            self.properties = planet.get_properties();
+        }
+    }
 }
 
 impl Component for SystemMenu {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> color_eyre::Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    /// A click on a body selects it. Dragging the map with the left button moves the view, and
+    /// the mouse wheel zooms it
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> color_eyre::Result<Option<Action>> {
+        let is_on_map = widget_utils::is_inside(self.map_area, mouse.column, mouse.row);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let body = widget_utils::get_list_entry_at(
+                    self.list_area,
+                    self.state.offset(),
+                    (0..self.list_length).map(|_| 1),
+                    mouse.column,
+                    mouse.row,
+                );
+                if let Some(body) = body {
+                    self.state.select(Some(body));
+                    self.is_focused = true;
+                    self.map_focused = false;
+                    if let Some(tx) = self.action_tx.as_ref() {
+                        tx.send(Action::SetMode(Mode::SelectingBodyInSystemTree))?;
+                    }
+                    return Ok(Some(Action::Select))
+                }
+                if is_on_map {
+                    self.drag_position = Some((mouse.column, mouse.row));
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                if let Some((column, row)) = self.drag_position {
+                    // The map always shows 30 units across, before zooming
+                    let width = self.map_area.width.saturating_sub(2).max(1) as f64;
+                    let height = self.map_area.height.saturating_sub(2).max(1) as f64;
+                    self.map_shift_x -= (mouse.column as f64 - column as f64) * 30.0 / width;
+                    self.map_shift_y += (mouse.row as f64 - row as f64) * 30.0 / height;
+                    self.drag_position = Some((mouse.column, mouse.row));
+                }
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.drag_position = None;
+            }
+            MouseEventKind::ScrollUp if is_on_map => {
+                return Ok(Some(Action::ZoomIn))
+            }
+            MouseEventKind::ScrollDown if is_on_map => {
+                return Ok(Some(Action::ZoomOut))
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+
     fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
         match action {
             Action::IngameTick => {
@@ -67,6 +151,9 @@ impl Component for SystemMenu {
                 self.set_system(system);
 
             }
+            Action::LoadSensorContacts(contacts) => {
+                self.contacts = contacts;
+            }
             Action::SelectNext => {
                 let selected = self.state.selected().unwrap();
                 if selected == (self.list_length - 1) {
@@ -87,14 +174,7 @@ impl Component for SystemMenu {
                 if self.is_focused {
                     self.is_focused = false;
 
-                    let selected = self.state.selected().unwrap();
-                    if selected == 0 {
-                        let star = self.system.clone().unwrap().get_star();
-                        self.properties = star.get_properties();
-                    } else {
-                        let planets = self.system.clone().unwrap().get_satellites();
-                        self.properties = planets[selected - 1].get_properties();
-                    }
+                    self.show_body(self.state.selected().unwrap());
 
                     return Ok(Some(
                         Action::UpdateObjectView
@@ -103,6 +183,18 @@ impl Component for SystemMenu {
                     self.map_focused = false;
                 }
             }
+            Action::FocusBody(name) => {
+                let index = self.system.as_ref().and_then(|system| {
+                    std::iter::once(system.get_star().get_name())
+                        .chain(system.get_satellites().iter().map(|p| p.get_name()))
+                        .position(|n| n == name)
+                });
+                if let Some(index) = index {
+                    self.state.select(Some(index));
+                    self.show_body(index);
+                    return Ok(Some(Action::UpdateObjectView))
+                }
+            }
             Action::Up => {
                 self.map_shift_y += 2.0 * self.map_zoom
             }
@@ -249,6 +341,20 @@ impl Component for SystemMenu {
                         self.map_zoom,
                     )
                 }
+
+                let mut drawn = Vec::<(f64, f64)>::new();
+                self.contacts.iter().for_each(|c| {
+                    let (x, y) = c.get_position();
+                    let stacked = drawn.iter().filter(|p| **p == (x, y)).count() as f64;
+                    drawn.push((x, y));
+
+                    ctx.draw(&Points { coords: &[(x, y)], color: c.get_menu_color() });
+                    ctx.print(
+                        x + 0.2 * self.map_zoom,
+                        y - 0.6 * (stacked + 1.0) * self.map_zoom,
+                        Span::styled(c.get_name(), Style::default().fg(c.get_menu_color())),
+                    );
+                });
             });
 
         let help = Paragraph::new(
@@ -266,6 +372,8 @@ impl Component for SystemMenu {
                 .border_type(BorderType::Rounded)
         );
 
+        self.list_area = chunks[0];
+        self.map_area = s_chunks[0];
         f.render_stateful_widget(list, chunks[0], &mut self.state);
         f.render_widget(object_view, s_chunks[1]);
         f.render_widget(system_image, s_chunks[0]);
@@ -1,13 +1,23 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, BorderType, Tabs};
 
 use crate::action::Action;
 use crate::components::Component;
+use crate::components::utils::widget_utils;
 use crate::tui::Frame;
 
+/// Padding drawn on both sides of every tab name
+const TAB_PADDING: &str = " == ";
+/// Divider drawn between the tab names
+const TAB_DIVIDER: &str = "|";
+
 pub struct TopMenu {
     tabs: Vec<String>,
     selected: usize,
+    /// Area the tabs were last drawn in, used to find the clicked tab
+    area: Rect,
 }
 
 impl Default for TopMenu {
@@ -15,11 +25,43 @@ impl Default for TopMenu {
         Self {
             tabs: vec![],
             selected: 1,
+            area: Rect::default(),
+        }
+    }
+}
+
+impl TopMenu {
+    /// Finds the tab name under a column of the tabs bar, the arrows at both ends included
+    fn get_title_at(&self, column: u16) -> Option<usize> {
+        let padding = Line::from(TAB_PADDING).width() as u16;
+        let divider = Line::from(TAB_DIVIDER).width() as u16;
+        let mut left = self.area.x + 1;
+        for (i, title) in self.tabs.iter().enumerate() {
+            let right = left + 2 * padding + Line::from(title.as_str()).width() as u16;
+            if column >= left && column < right {
+                return Some(i)
+            }
+            left = right + divider;
         }
+        None
     }
 }
 
 impl Component for TopMenu {
+    fn handle_mouse_events(&mut self, mouse: MouseEvent) -> color_eyre::Result<Option<Action>> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left)
+            || !widget_utils::is_inside(self.area, mouse.column, mouse.row) {
+            return Ok(None)
+        }
+
+        Ok(match self.get_title_at(mouse.column) {
+            Some(0) => Some(Action::NavigatePrevTab),
+            Some(i) if i == self.tabs.len() - 1 => Some(Action::NavigateNextTab),
+            Some(i) => Some(Action::NavigateToTab(i - 1)),
+            None => None,
+        })
+    }
+
     fn update(&mut self, action: Action) -> color_eyre::Result<Option<Action>> {
         match action {
             Action::LoadTabs(tabs) => {
@@ -40,6 +82,9 @@ impl Component for TopMenu {
                     self.selected = self.tabs.len() - 2;
                 }
             }
+            Action::NavigateToTab(tab) if tab + 2 < self.tabs.len() => {
+                self.selected = tab + 1;
+            }
             _ => {}
         }
 
@@ -62,9 +107,10 @@ impl Component for TopMenu {
                     .borders(Borders::ALL)
             )
             .select(self.selected)
-            .divider("|")
-            .padding(" == ", " == ");
+            .divider(TAB_DIVIDER)
+            .padding(TAB_PADDING, TAB_PADDING);
 
+        self.area = chunks[0];
         f.render_widget(tabs, chunks[0]);
 
         Ok(())
@@ -73,4 +119,4 @@ impl Component for TopMenu {
     fn is_drawn_in_tab(&self, tab: &crate::tabs::Tabs) -> bool {
         true
     }
-}
+}
\ No newline at end of file
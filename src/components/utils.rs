@@ -1,4 +1,6 @@
 pub mod widget_utils {
+    use ratatui::layout::Rect;
+
     pub fn select_next_in_list(current_item: usize, list_length: usize) -> usize {
         if list_length == 0 { return 0 }
         if current_item != list_length - 1 {
@@ -16,4 +18,118 @@ pub mod widget_utils {
             list_length - 1
         }
     }
+
+    /// Tells whether a point of the screen lies inside an area
+    pub fn is_inside(area: Rect, column: u16, row: u16) -> bool {
+        column >= area.left() && column < area.right() && row >= area.top() && row < area.bottom()
+    }
+
+    /// Finds the entry of a bordered list under a point of the screen
+    ///
+    /// # Arguments
+    /// * `area` - The area the list was last drawn in, borders included
+    /// * `offset` - Index of the first entry shown in the list
+    /// * `heights` - Height of every entry of the list, in lines
+    /// * `column` - Column of the point
+    /// * `row` - Row of the point
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Index of the entry, or `None` if there is no entry under the point
+    pub fn get_list_entry_at(
+        area: Rect,
+        offset: usize,
+        heights: impl IntoIterator<Item = usize>,
+        column: u16,
+        row: u16,
+    ) -> Option<usize> {
+        let inner = Rect::new(
+            area.x + 1,
+            area.y + 1,
+            area.width.saturating_sub(2),
+            area.height.saturating_sub(2),
+        );
+        if !is_inside(inner, column, row) {
+            return None
+        }
+
+        let mut top = inner.y as usize;
+        for (i, height) in heights.into_iter().enumerate().skip(offset) {
+            top += height;
+            if (row as usize) < top {
+                return Some(i)
+            }
+        }
+        None
+    }
+}
+
+pub mod text_utils {
+    /// Scores how well a text matches what the player typed. Every word of the query has to
+    /// appear in the text with its letters in order, though not necessarily next to each other.
+    /// Letters at the start of a word and letters following each other score more
+    ///
+    /// # Arguments
+    /// * `query` - What the player typed, the case is ignored
+    /// * `text` - The text to match
+    ///
+    /// # Returns
+    /// * `Option<i64>` - The score, higher for better matches, or `None` if the text does not
+    ///   match
+    pub fn get_fuzzy_score(query: &str, text: &str) -> Option<i64> {
+        let text: Vec<char> = text.to_lowercase().chars().collect();
+        let mut score = 0;
+        for word in query.to_lowercase().split_whitespace() {
+            let mut position = 0;
+            let mut previous: Option<usize> = None;
+            for c in word.chars() {
+                let found = position + text[position..].iter().position(|t| *t == c)?;
+                score += 1;
+                if found == 0 || !text[found - 1].is_alphanumeric() {
+                    score += 5;
+                }
+                if previous == Some(found.wrapping_sub(1)) {
+                    score += 3;
+                }
+                previous = Some(found);
+                position = found + 1;
+            }
+        }
+        Some(score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::layout::Rect;
+
+    use crate::components::utils::text_utils::get_fuzzy_score;
+    use crate::components::utils::widget_utils::get_list_entry_at;
+
+    #[test]
+    fn test_commands_are_matched_fuzzily() {
+        assert_eq!(get_fuzzy_score("", "Research Ion drive"), Some(0));
+        assert!(get_fuzzy_score("ion drv", "Research Ion drive").is_some());
+        assert!(get_fuzzy_score("mine capital", "Build Mine on Capital").is_some());
+        assert_eq!(get_fuzzy_score("drive ion x", "Research Ion drive"), None);
+
+        let whole_words = get_fuzzy_score("mine", "Build Mine on Terra").unwrap();
+        let scattered = get_fuzzy_score("mine", "Go to Mimas Nine").unwrap();
+        assert!(whole_words > scattered);
+    }
+
+    #[test]
+    fn test_list_entries_are_found_under_the_mouse() {
+        let area = Rect::new(10, 5, 20, 6);
+        // The borders and the space below the last entry hold no entries
+        assert_eq!(get_list_entry_at(area, 0, [1, 1], 10, 6), None);
+        assert_eq!(get_list_entry_at(area, 0, [1, 1], 15, 5), None);
+        assert_eq!(get_list_entry_at(area, 0, [1, 1], 15, 8), None);
+
+        assert_eq!(get_list_entry_at(area, 0, [1, 2, 1], 15, 6), Some(0));
+        assert_eq!(get_list_entry_at(area, 0, [1, 2, 1], 15, 8), Some(1));
+        assert_eq!(get_list_entry_at(area, 0, [1, 2, 1], 15, 9), Some(2));
+        // A scrolled list starts with the entry at the offset
+        assert_eq!(get_list_entry_at(area, 2, [1, 2, 1], 15, 6), Some(2));
+    }
 }
\ No newline at end of file
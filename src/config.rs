@@ -11,9 +11,11 @@ use serde::{
 };
 use serde_json::Value as JsonValue;
 
-use crate::{action::Action, mode::Mode};
+use crate::{action::Action, game::game_options::GameOptions, mode::Mode, saves::AutosaveOptions};
 
 const CONFIG: &str = include_str!("../.config/config.json5");
+/// Maximum amount of actions shown in the footer, the help window shows all of them
+const MAX_KEY_HINTS: usize = 6;
 
 #[derive(Clone, Debug, Deserialize, Default)]
 pub struct AppConfig {
@@ -31,6 +33,10 @@ pub struct Config {
   pub keybindings: KeyBindings,
   #[serde(default)]
   pub styles: Styles,
+  #[serde(default)]
+  pub game: GameOptions,
+  #[serde(default)]
+  pub autosave: AutosaveOptions,
 }
 
 impl Config {
@@ -82,6 +88,62 @@ impl Config {
 #[derive(Clone, Debug, Default, Deref, DerefMut)]
 pub struct KeyBindings(pub HashMap<Mode, HashMap<Vec<KeyEvent>, Action>>);
 
+impl KeyBindings {
+  /// Lists the actions bound in a mode, sorted by their description
+  ///
+  /// # Returns
+  /// * `Vec<(String, String)>` - Every key sequence of an action, joined with `/`, and the
+  ///   description of the action
+  pub fn get_hints(&self, mode: &Mode) -> Vec<(String, String)> {
+    let mut hints = self.group_by_description(mode);
+    hints.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+    hints.into_iter().map(|(description, keys, _)| (keys, description)).collect()
+  }
+
+  /// Lists the most important actions bound in a mode for the footer, sorted by their priority.
+  /// The actions without a priority are only listed by the help window
+  ///
+  /// # Returns
+  /// * `Vec<(String, String)>` - Every key sequence of an action, joined with `/`, and the
+  ///   description of the action
+  pub fn get_key_hints(&self, mode: &Mode) -> Vec<(String, String)> {
+    let mut hints: Vec<_> = self.group_by_description(mode)
+      .into_iter()
+      .filter_map(|(description, keys, priority)| Some((priority?, description, keys)))
+      .collect();
+    hints.sort();
+    hints
+      .into_iter()
+      .take(MAX_KEY_HINTS)
+      .map(|(_, description, keys)| (keys, description))
+      .collect()
+  }
+
+  /// Groups the key sequences of the actions bound in a mode by the description of the action
+  ///
+  /// # Returns
+  /// * `Vec<(String, String, Option<u32>)>` - The description of the action, its sorted key
+  ///   sequences joined with `/` and its hint priority
+  fn group_by_description(&self, mode: &Mode) -> Vec<(String, String, Option<u32>)> {
+    let mut hints = Vec::<(String, Vec<String>, Option<u32>)>::new();
+    for (keys, action) in self.get(mode).into_iter().flatten() {
+      let description = action.get_description();
+      let keys = key_sequence_to_string(keys);
+      match hints.iter_mut().find(|(d, _, _)| *d == description) {
+        Some((_, sequences, _)) => sequences.push(keys),
+        None => hints.push((description, vec![keys], action.get_hint_priority())),
+      }
+    }
+    hints
+      .into_iter()
+      .map(|(description, mut sequences, priority)| {
+        sequences.sort();
+        (description, sequences.join("/"), priority)
+      })
+      .collect()
+  }
+}
+
 impl<'de> Deserialize<'de> for KeyBindings {
   fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
   where
@@ -243,6 +305,11 @@ pub fn key_event_to_string(key_event: &KeyEvent) -> String {
   key
 }
 
+/// Formats a key sequence the way it is written in the configuration file, e.g. `<g><ctrl-d>`
+pub fn key_sequence_to_string(keys: &[KeyEvent]) -> String {
+  keys.iter().map(|k| format!("<{}>", key_event_to_string(k))).collect()
+}
+
 pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
   if raw.chars().filter(|c| *c == '>').count() != raw.chars().filter(|c| *c == '<').count() {
     return Err(format!("Unable to parse `{}`", raw));
@@ -488,6 +555,39 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_hints_join_the_keys_of_an_action() {
+    let bindings: KeyBindings = json5::from_str(
+      r#"{ "Main": { "<q>": "Quit", "<Ctrl-d>": "Quit", "<g><t>": "NavigateNextTab" } }"#,
+    )
+    .unwrap();
+    assert_eq!(bindings.get_hints(&Mode::Main), vec![
+      (String::from("<g><t>"), String::from("Next tab")),
+      (String::from("<ctrl-d>/<q>"), String::from("Quit")),
+    ]);
+    assert!(bindings.get_hints(&Mode::SelectingResearch).is_empty());
+  }
+
+  #[test]
+  fn test_key_hints_are_prioritized() {
+    let bindings: KeyBindings = json5::from_str(
+      r#"{ "Main": {
+        "<q>": "Quit", "<h>": "Help", "<Tab>": "NavigateNextTab", "<Space>": "TogglePause",
+        "<a>": "Up", "<b>": "Down", "<c>": "Left", "<d>": "Right", "<Enter>": "Select"
+      } }"#,
+    )
+    .unwrap();
+    let hints = bindings.get_key_hints(&Mode::Main);
+    assert_eq!(hints.len(), MAX_KEY_HINTS);
+    assert_eq!(hints[..3], [
+      (String::from("<h>"), String::from("Help")),
+      (String::from("<enter>"), String::from("Confirm")),
+      (String::from("<space>"), String::from("Pause/resume")),
+    ]);
+    assert!(!hints.iter().any(|(_, description)| description == "Quit"));
+    assert_eq!(bindings.get_hints(&Mode::Main).len(), 9);
+  }
+
   #[test]
   fn test_invalid_keys() {
     assert!(parse_key_event("invalid-key").is_err());
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use serde_json::Value;
+use tokio::sync::oneshot;
+
+use crate::control::rpc::{ControlCall, RpcError};
+
+pub mod rpc;
+pub mod server;
+
+/// `ControlAddress` is where the control server listens, set with the `--control` flag
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ControlAddress {
+  /// A TCP port of the loopback interface, e.g. `127.0.0.1:7878` or `localhost:7878`
+  Tcp(SocketAddr),
+  /// A Unix socket, e.g. `unix:/tmp/astray.sock`
+  Unix(PathBuf),
+}
+
+impl FromStr for ControlAddress {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Some(path) = s.strip_prefix("unix:") {
+      return Ok(ControlAddress::Unix(PathBuf::from(path)))
+    }
+
+    let address = match s.strip_prefix("localhost:") {
+      Some(port) => port.parse::<u16>()
+        .map(|port| SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port))
+        .map_err(|e| format!("invalid port `{port}`: {e}"))?,
+      None => s.parse::<SocketAddr>().map_err(|e| format!("invalid address `{s}`: {e}"))?,
+    };
+    if !address.ip().is_loopback() {
+      return Err(format!(
+        "{address} is not a loopback address, the game is only controlled locally"
+      ))
+    }
+    Ok(ControlAddress::Tcp(address))
+  }
+}
+
+impl fmt::Display for ControlAddress {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      ControlAddress::Tcp(address) => write!(f, "{address}"),
+      ControlAddress::Unix(path) => write!(f, "unix:{}", path.display()),
+    }
+  }
+}
+
+/// `ControlRequest` is a call of a connected tool, waiting for the game to answer it
+///
+/// **Fields**
+/// - call (`ControlCall`) - what the tool asked for
+/// - reply (`oneshot::Sender<Result<Value, RpcError>>`) - takes the result back to the
+///   connection
+pub struct ControlRequest {
+  pub call: ControlCall,
+  pub reply: oneshot::Sender<Result<Value, RpcError>>,
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use pretty_assertions::assert_eq;
+
+  use crate::control::ControlAddress;
+
+  #[test]
+  fn test_only_local_addresses_are_accepted() {
+    assert_eq!(
+      "unix:/tmp/astray.sock".parse::<ControlAddress>(),
+      Ok(ControlAddress::Unix(PathBuf::from("/tmp/astray.sock"))),
+    );
+    assert_eq!(
+      "localhost:7878".parse::<ControlAddress>().unwrap().to_string(),
+      "127.0.0.1:7878",
+    );
+    assert!("[::1]:7878".parse::<ControlAddress>().is_ok());
+    assert!("0.0.0.0:7878".parse::<ControlAddress>().is_err());
+    assert!("localhost".parse::<ControlAddress>().is_err());
+  }
+}
\ No newline at end of file
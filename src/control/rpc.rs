@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::action::Action;
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::event_log::format_date;
+use crate::game::events::random_event::EventPrompt;
+use crate::game::game_state::GameState;
+use crate::game::simulation::tick_metrics::TickMetrics;
+
+/// Version of JSON-RPC the server speaks
+const JSONRPC_VERSION: &str = "2.0";
+/// Amount of events `get_events` returns if the call does not say
+const DEFAULT_EVENT_AMOUNT: usize = 20;
+
+/// The request is not valid JSON
+pub const PARSE_ERROR: i64 = -32700;
+/// The request is not a JSON-RPC request
+pub const INVALID_REQUEST: i64 = -32600;
+/// The game has no such method
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// The parameters do not fit the method
+pub const INVALID_PARAMS: i64 = -32602;
+/// The game refused the call, e.g. a research which can not be started yet
+pub const GAME_ERROR: i64 = -32000;
+
+/// Methods of the server, the same as the variants of `ControlCall`
+const METHODS: [&str; 11] = [
+  "get_status",
+  "get_metrics",
+  "get_researches",
+  "get_colony",
+  "get_events",
+  "start_research",
+  "start_construction",
+  "answer_event",
+  "pause",
+  "resume",
+  "set_speed",
+];
+
+/// `RpcError` is the error object of a JSON-RPC response
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RpcError {
+  pub code: i64,
+  pub message: String,
+}
+
+impl RpcError {
+  pub fn new(code: i64, message: impl Into<String>) -> Self {
+    Self { code, message: message.into() }
+  }
+}
+
+/// `ControlCall` is a method of the control server with its parameters. The queries are answered
+/// from the state of the game, the commands are turned into the actions the interface dispatches
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum ControlCall {
+  /// The date, whether the game is paused, its speed and the event waiting for an answer
+  GetStatus {},
+  /// A snapshot of the player's empire, the same as a row of the simulation metrics
+  GetMetrics {},
+  /// The researches which can be started, the ones in progress and the researched ones
+  GetResearches {},
+  /// Population, stability, resources, buildings and construction queue of a colony
+  GetColony { name: String },
+  /// The latest events of the player, newest first
+  GetEvents {
+    #[serde(default = "default_event_amount")]
+    amount: usize,
+  },
+  StartResearch { research: String },
+  StartConstruction { colony: String, building: String },
+  /// Chooses an option of the event waiting for an answer, the first option is 0
+  AnswerEvent { choice: usize },
+  Pause {},
+  Resume {},
+  /// Sets the amount of interface ticks between two in-game days, lower is faster
+  SetSpeed { ticks_per_day: u32 },
+}
+
+fn default_event_amount() -> usize {
+  DEFAULT_EVENT_AMOUNT
+}
+
+fn to_answer(value: &impl Serialize) -> Result<ControlReply, RpcError> {
+  serde_json::to_value(value)
+    .map(ControlReply::Answer)
+    .map_err(|e| RpcError::new(GAME_ERROR, e.to_string()))
+}
+
+/// `ControlReply` is how the game answers a call
+pub enum ControlReply {
+  /// The result of a query
+  Answer(Value),
+  /// The action carrying out a command, the call succeeds once it is dispatched
+  Dispatch(Action),
+}
+
+/// `GameStatus` is the result of `get_status`
+///
+/// **Fields**
+/// - date (`u64`) - amount of days passed since the start of the game
+/// - date_text (`String`) - the same day, as shown in the game
+/// - is_paused (`bool`) - `true` if the days do not pass
+/// - ticks_per_day (`u32`) - amount of interface ticks between two in-game days
+/// - event (`Option<EventPrompt>`) - the event waiting for an answer, the game does not go on
+///   until it is answered
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct GameStatus {
+  pub date: u64,
+  pub date_text: String,
+  pub is_paused: bool,
+  pub ticks_per_day: u32,
+  pub event: Option<EventPrompt>,
+}
+
+impl GameStatus {
+  pub fn new(state: &GameState, is_paused: bool, ticks_per_day: u32) -> Self {
+    Self {
+      date: state.get_date(),
+      date_text: format_date(state.get_date()),
+      is_paused,
+      ticks_per_day,
+      event: state.get_event_manager().get_prompt(state.get_player()),
+    }
+  }
+}
+
+impl ControlCall {
+  /// Answers a query or checks a command against the state of the game
+  ///
+  /// # Arguments
+  /// * `state` - The game
+  /// * `status` - The state of the interface, answers `get_status`
+  ///
+  /// # Returns
+  /// * `Result<ControlReply, RpcError>` - The answer or the action to dispatch, or an error if
+  ///   the game can not carry out the command
+  pub fn handle(self, state: &GameState, status: GameStatus) -> Result<ControlReply, RpcError> {
+    let research_manager = state.get_player().get_research_manager();
+    Ok(match self {
+      ControlCall::GetStatus {} => to_answer(&status)?,
+      ControlCall::GetMetrics {} => to_answer(&TickMetrics::collect(state))?,
+      ControlCall::GetResearches {} => ControlReply::Answer(json!({
+        "available": research_manager.get_available_research_names().into_iter()
+          .map(|(id, name)| json!({ "id": id, "name": name }))
+          .collect::<Vec<Value>>(),
+        "researching": research_manager.get_researches_in_progress(),
+        "researched": research_manager.get_researched(),
+      })),
+      ControlCall::GetColony { name } => {
+        let colony = state.get_colony_by_name(name.clone())
+          .ok_or_else(|| RpcError::new(GAME_ERROR, format!("unknown colony `{name}`")))?;
+        ControlReply::Answer(json!({
+          "name": colony.get_name(),
+          "population": colony.get_population(),
+          "stability": colony.get_stability().get_value(),
+          "resources": colony.get_resources().into_iter()
+            .map(|(resource, amount)| (resource.get_name(), json!(amount)))
+            .collect::<serde_json::Map<String, Value>>(),
+          "buildings": colony.get_buildings().into_iter()
+            .map(|(building, amount, _)| (building, json!(amount)))
+            .collect::<serde_json::Map<String, Value>>(),
+          "construction": colony.get_construction().into_iter()
+            .map(|(building, progress)| json!({ "building": building, "progress": progress }))
+            .collect::<Vec<Value>>(),
+        }))
+      }
+      ControlCall::GetEvents { amount } => ControlReply::Answer(json!(
+        state.get_latest_events(amount).into_iter().map(|(text, _)| text).collect::<Vec<_>>()
+      )),
+      ControlCall::StartResearch { research } => {
+        if !research_manager.get_available_researches().contains(&research) {
+          return Err(RpcError::new(GAME_ERROR, if research_manager.has_research(&research) {
+            format!("research `{research}` can not be started now")
+          } else {
+            format!("unknown research `{research}`")
+          }))
+        }
+        ControlReply::Dispatch(Action::StartResearch(research))
+      }
+      ControlCall::StartConstruction { colony, building } => {
+        if state.get_colony_by_name(colony.clone()).is_none() {
+          return Err(RpcError::new(GAME_ERROR, format!("unknown colony `{colony}`")))
+        }
+        if BuildingType::from_name(&building).is_none() {
+          return Err(RpcError::new(GAME_ERROR, format!("unknown building `{building}`")))
+        }
+        ControlReply::Dispatch(Action::StartConstruction((colony, building)))
+      }
+      ControlCall::AnswerEvent { choice } => {
+        let prompt = state.get_event_manager().get_prompt(state.get_player())
+          .ok_or_else(|| RpcError::new(GAME_ERROR, "no event is waiting for an answer"))?;
+        match prompt.options.get(choice) {
+          Some((_, true)) => ControlReply::Dispatch(Action::ChooseEventOption(choice)),
+          Some((_, false)) => {
+            return Err(RpcError::new(GAME_ERROR, format!("option {choice} is not affordable")))
+          }
+          None => return Err(RpcError::new(GAME_ERROR, format!("there is no option {choice}"))),
+        }
+      }
+      // The game stays paused until the event is answered
+      ControlCall::Pause {} | ControlCall::Resume {} if status.event.is_some() => {
+        return Err(RpcError::new(GAME_ERROR, "an event is waiting for an answer"))
+      }
+      ControlCall::Pause {} => ControlReply::Dispatch(Action::SetPaused(true)),
+      ControlCall::Resume {} => ControlReply::Dispatch(Action::SetPaused(false)),
+      ControlCall::SetSpeed { ticks_per_day } => {
+        ControlReply::Dispatch(Action::SetGameSpeed(ticks_per_day))
+      }
+    })
+  }
+}
+
+/// Parses a line sent by a tool into a call
+///
+/// # Returns
+/// * `(Value, Result<ControlCall, RpcError>)` - The id of the request, `null` if it has none,
+///   and the call or the reason it is not valid
+pub fn parse_request(line: &str) -> (Value, Result<ControlCall, RpcError>) {
+  let request: Value = match serde_json::from_str(line) {
+    Ok(request) => request,
+    Err(e) => return (Value::Null, Err(RpcError::new(PARSE_ERROR, e.to_string()))),
+  };
+  let id = request.get("id").cloned().unwrap_or(Value::Null);
+
+  if request.get("jsonrpc").and_then(|v| v.as_str()) != Some(JSONRPC_VERSION) {
+    return (id, Err(RpcError::new(INVALID_REQUEST, "`jsonrpc` has to be \"2.0\"")))
+  }
+  let method = match request.get("method").and_then(|m| m.as_str()) {
+    Some(method) => method,
+    None => return (id, Err(RpcError::new(INVALID_REQUEST, "`method` is missing"))),
+  };
+  if !METHODS.contains(&method) {
+    return (id, Err(RpcError::new(METHOD_NOT_FOUND, format!("unknown method `{method}`"))))
+  }
+
+  let params = match request.get("params") {
+    None | Some(Value::Null) => json!({}),
+    Some(params) => params.clone(),
+  };
+  let call = serde_json::from_value(json!({ "method": method, "params": params }))
+    .map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()));
+  (id, call)
+}
+
+/// Formats the response to a request as a single line
+pub fn format_response(id: Value, result: Result<Value, RpcError>) -> String {
+  let response = match result {
+    Ok(result) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": result }),
+    Err(error) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "error": error }),
+  };
+  response.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Value};
+
+  use crate::action::Action;
+  use crate::control::rpc::{
+    ControlCall, ControlReply, format_response, GAME_ERROR, GameStatus, INVALID_PARAMS,
+    METHOD_NOT_FOUND, parse_request, PARSE_ERROR,
+  };
+  use crate::game::celestial_bodies::Displayable;
+  use crate::game::game_options::GameOptions;
+  use crate::game::game_state::GameState;
+
+  #[test]
+  fn test_requests_are_parsed() {
+    let (id, call) = parse_request(
+      r#"{"jsonrpc":"2.0","id":7,"method":"start_research","params":{"research":"ion-drive"}}"#
+    );
+    assert_eq!(id, json!(7));
+    assert_eq!(call, Ok(ControlCall::StartResearch { research: String::from("ion-drive") }));
+
+    let (_, call) = parse_request(r#"{"jsonrpc":"2.0","id":1,"method":"get_events"}"#);
+    assert_eq!(call, Ok(ControlCall::GetEvents { amount: 20 }));
+
+    let code = |line: &str| parse_request(line).1.unwrap_err().code;
+    assert_eq!(code("{"), PARSE_ERROR);
+    assert_eq!(code(r#"{"jsonrpc":"2.0","id":1,"method":"warp"}"#), METHOD_NOT_FOUND);
+    assert_eq!(code(r#"{"jsonrpc":"2.0","id":1,"method":"get_colony"}"#), INVALID_PARAMS);
+
+    assert_eq!(
+      format_response(json!(1), Ok(Value::Bool(true))),
+      r#"{"id":1,"jsonrpc":"2.0","result":true}"#,
+    );
+  }
+
+  #[test]
+  fn test_commands_are_checked_against_the_game() {
+    let state = GameState::new(GameOptions {
+      computer_empires: 0,
+      seed: Some(5),
+      ..GameOptions::default()
+    }).unwrap();
+    let status = || GameStatus::new(&state, false, 10);
+    let capital = state.get_colonies()[0].get_name();
+
+    let build = ControlCall::StartConstruction {
+      colony: capital.clone(),
+      building: String::from("Mine"),
+    };
+    assert!(matches!(
+      build.handle(&state, status()),
+      Ok(ControlReply::Dispatch(Action::StartConstruction(_))),
+    ));
+
+    let research = ControlCall::StartResearch { research: String::from("no-such-research") };
+    assert_eq!(research.handle(&state, status()).err().unwrap().code, GAME_ERROR);
+
+    let colony = ControlCall::GetColony { name: capital };
+    match colony.handle(&state, status()) {
+      Ok(ControlReply::Answer(answer)) => assert!(answer["buildings"]["Mine"].is_u64()),
+      _ => panic!("the colony was not found"),
+    }
+  }
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+use color_eyre::eyre::{Result, WrapErr};
+use log::info;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::oneshot;
+
+use crate::control::{ControlAddress, ControlRequest};
+use crate::control::rpc::{format_response, GAME_ERROR, parse_request, RpcError};
+
+/// Starts listening for tools and serves every connected one in its own task. A tool sends a
+/// JSON-RPC request per line and gets a response per line, in the same order
+///
+/// # Arguments
+/// * `address` - Where to listen
+///
+/// # Returns
+/// * `Result<(UnboundedReceiver<ControlRequest>, String)>` - The calls of the tools, for the game
+///   to answer, and the address the server listens at
+pub async fn start(
+  address: &ControlAddress,
+) -> Result<(UnboundedReceiver<ControlRequest>, String)> {
+  let (request_tx, request_rx) = mpsc::unbounded_channel();
+  let local_address = match address {
+    ControlAddress::Tcp(address) => {
+      let listener = TcpListener::bind(address).await
+        .wrap_err_with(|| format!("{address}: unable to listen"))?;
+      let local_address = listener.local_addr()?.to_string();
+      tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+          tokio::spawn(serve(stream, request_tx.clone()));
+        }
+      });
+      local_address
+    }
+    #[cfg(unix)]
+    ControlAddress::Unix(path) => {
+      // A socket left over by a game which did not exit cleanly would stop the server
+      if path.exists() && std::os::unix::net::UnixStream::connect(path).is_err() {
+        std::fs::remove_file(path)
+          .wrap_err_with(|| format!("{}: unable to remove stale socket", path.display()))?;
+      }
+      let listener = tokio::net::UnixListener::bind(path)
+        .wrap_err_with(|| format!("{}: unable to listen", path.display()))?;
+      tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+          tokio::spawn(serve(stream, request_tx.clone()));
+        }
+      });
+      address.to_string()
+    }
+    #[cfg(not(unix))]
+    ControlAddress::Unix(_) => {
+      return Err(color_eyre::eyre::eyre!("Unix sockets are not supported on this system"))
+    }
+  };
+  info!("Control server listening at {local_address}");
+  Ok((request_rx, local_address))
+}
+
+/// Answers the requests of a connected tool until it disconnects
+async fn serve(stream: impl AsyncRead + AsyncWrite, request_tx: UnboundedSender<ControlRequest>) {
+  let (reader, mut writer) = tokio::io::split(stream);
+  let mut lines = BufReader::new(reader).lines();
+  while let Ok(Some(line)) = lines.next_line().await {
+    if line.trim().is_empty() {
+      continue
+    }
+    let (id, call) = parse_request(&line);
+    let result = match call {
+      Ok(call) => {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        if request_tx.send(ControlRequest { call, reply: reply_tx }).is_err() {
+          break
+        }
+        reply_rx.await
+          .unwrap_or_else(|_| Err(RpcError::new(GAME_ERROR, "the game is shutting down")))
+      }
+      Err(error) => Err(error),
+    };
+    let response = format_response(id, result) + "\n";
+    if writer.write_all(response.as_bytes()).await.is_err() {
+      break
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Value};
+  use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+  use tokio::net::TcpStream;
+
+  use crate::control::ControlAddress;
+  use crate::control::rpc::ControlCall;
+  use crate::control::server::start;
+
+  #[tokio::test]
+  async fn test_requests_are_answered_in_order() {
+    let address: ControlAddress = "127.0.0.1:0".parse().unwrap();
+    let (mut requests, local_address) = start(&address).await.unwrap();
+    tokio::spawn(async move {
+      while let Some(request) = requests.recv().await {
+        let answer = match request.call {
+          ControlCall::GetStatus {} => json!("running"),
+          _ => json!(true),
+        };
+        request.reply.send(Ok(answer)).unwrap();
+      }
+    });
+
+    let stream = TcpStream::connect(local_address).await.unwrap();
+    let (reader, mut writer) = stream.into_split();
+    writer.write_all(concat!(
+      r#"{"jsonrpc":"2.0","id":1,"method":"get_status"}"#, "\n",
+      r#"{"jsonrpc":"2.0","id":2,"method":"set_speed","params":{"ticks_per_day":2}}"#, "\n",
+      "nonsense\n",
+    ).as_bytes()).await.unwrap();
+
+    let mut lines = BufReader::new(reader).lines();
+    let mut responses = Vec::new();
+    for _ in 0..3 {
+      let line = lines.next_line().await.unwrap().unwrap();
+      responses.push(serde_json::from_str::<Value>(&line).unwrap());
+    }
+    assert_eq!(responses[0]["result"], json!("running"));
+    assert_eq!(responses[1]["id"], json!(2));
+    assert_eq!(responses[2]["error"]["code"], json!(-32700));
+  }
+}
\ No newline at end of file
@@ -1,19 +1,11 @@
 use std::iter::Iterator;
 
+use rand::Rng;
+
 pub mod star;
 pub mod planet;
 pub mod solar_system;
-
-mod constants {
-    use std::fs;
-
-    use once_cell::sync::Lazy;
-
-    pub static STAR_NAMES: Lazy<Vec<String>> = Lazy::new(|| {
-        fs::read_to_string("./assets/namelists/star_namelist.txt").unwrap
-        ().split("\r\n").map(|s| s.to_string()).collect()
-    });
-}
+pub mod jump_point;
 
 pub enum CelestialBodyType {
     Star,
@@ -64,10 +56,11 @@ pub trait CelestialBody {
     /// 
     /// # Arguments
     /// * `host` - An optional reference to the host body
+    /// * `rng` - Random number generator the body is generated with
     /// 
     /// # Returns
     /// * `Self` - A generated instance of the implementer
-    fn generate(host: Self::HostType) -> Self;
+    fn generate(host: Self::HostType, rng: &mut impl Rng) -> Self;
 }
 
 /// The trait `Orbitable` is applied to the game objects that can be orbited
@@ -0,0 +1,42 @@
+use ordered_float::OrderedFloat;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+
+/// `JumpPoint` is a fold in space at the edge of a system, which leads to another system
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JumpPoint {
+    name: String,
+    /// Position of the jump point relative to the star, in AU
+    position: (OrderedFloat<f64>, OrderedFloat<f64>),
+}
+
+impl JumpPoint {
+    pub fn new(name: String, position: (f64, f64)) -> Self {
+        Self {
+            name,
+            position: (OrderedFloat(position.0), OrderedFloat(position.1)),
+        }
+    }
+
+    pub fn get_position(&self) -> (f64, f64) {
+        (self.position.0.into_inner(), self.position.1.into_inner())
+    }
+}
+
+impl Displayable for JumpPoint {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![String::from("Leads to"), String::from("Unexplored system"), String::new()],
+        ]
+    }
+
+    fn get_menu_color(&self) -> Color {
+        Color::Magenta
+    }
+}
\ No newline at end of file
@@ -11,8 +11,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::game::celestial_bodies::{CanOrbit, CelestialBody, CelestialBodyType, Displayable};
 use crate::game::celestial_bodies::solar_system::SolarSystem;
+use crate::game::exploration::body_survey::{BodySurvey, SurveyKind};
 use crate::game::helpers::{consts, orbit_dynamics};
 use crate::game::helpers::astrophysics::calculate_habitable_zone_from_luminosity;
+use crate::game::resource::resource::ResourceDeposit;
+
+/// Signature of a planet the size of the Earth, larger planets are easier to detect
+const EARTH_SIGNATURE: f32 = 25.0;
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Planet {
@@ -23,6 +28,10 @@ pub struct Planet {
     orbit_period: OrderedFloat<f32>,
     habitable_zone: RangeInclusive<OrderedFloat<f32>>,
     orbit_position: OrderedFloat<f32>,
+    resource_deposit: ResourceDeposit,
+    /// How much the viewer of the planet has surveyed it
+    #[serde(default)]
+    survey: BodySurvey,
 }
 
 impl CelestialBody for Planet {
@@ -40,20 +49,19 @@ impl CelestialBody for Planet {
         self.radius.to_f32().unwrap()
     }
 
-    fn generate(host: SolarSystem) -> Self {
-        let mut rng = rand::thread_rng();
+    fn generate(host: SolarSystem, rng: &mut impl Rng) -> Self {
         let n = host.get_n_planets() + 1;
         let letter: char = "ABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().nth(n - 1).unwrap();
 
         let mass = rand_distr::Normal::new(
             1.5,
             0.7,
-        ).unwrap().sample(&mut rng.clone()) * consts::EARTH_M_KG;
+        ).unwrap().sample(rng) * consts::EARTH_M_KG;
         
         let density = rand_distr::Normal::new(
             5500.0,
             750.0,
-        ).unwrap().sample(&mut rng.clone());
+        ).unwrap().sample(rng);
         
         let volume = mass / density;
         let radius = (volume / ((4.0 / 3.0) * std::f32::consts::PI)).cbrt();
@@ -65,7 +73,7 @@ impl CelestialBody for Planet {
             inner_limit + rand_distr::Normal::new(
                 0.4,
                 0.2,
-            ).unwrap().sample(&mut rng.clone()) * consts::AU_M
+            ).unwrap().sample(rng) * consts::AU_M
         } else {
             host.get_nth_orbit_radius(n as u32)
         };
@@ -96,6 +104,8 @@ impl CelestialBody for Planet {
                 OrderedFloat(*habitable_zone.end()),
             ),
             orbit_position: rng.gen_range(0.0..std::f32::consts::TAU).into(),
+            resource_deposit: ResourceDeposit::generate_for_planet(rng),
+            survey: BodySurvey::default(),
         }
     }
 }
@@ -127,12 +137,22 @@ impl CanOrbit for Planet {
 
 impl Displayable for Planet {
     fn get_properties(&self) -> Vec<Vec<String>> {
-        vec![
-            vec![
-                String::from("Mass"),
-                format!("{:.3E} kg", self.mass),
-                format!("{:.3} earth masses", self.mass / consts::EARTH_M_KG),
-            ],
+        let unknown = |property: &str| {
+            vec![String::from(property), String::from("unknown"), String::new()]
+        };
+        let is_mapped = self.survey.is_complete(SurveyKind::Gravitational);
+        let is_sampled = self.survey.is_complete(SurveyKind::Geological);
+
+        let mut properties = vec![
+            if is_mapped {
+                vec![
+                    String::from("Mass"),
+                    format!("{:.3E} kg", self.mass),
+                    format!("{:.3} earth masses", self.mass / consts::EARTH_M_KG),
+                ]
+            } else {
+                unknown("Mass")
+            },
             vec![
                 String::from("Radius"),
                 format!("{:.3E} m", self.radius),
@@ -143,13 +163,37 @@ impl Displayable for Planet {
                 format!("{:.3E} m", self.orbit_radius),
                 format!("{:.3} AU", self.orbit_radius / consts::AU_M),
             ],
+            if is_mapped {
+                vec![
+                    String::from("Orbital period"),
+                    format!("{:.3E} s", self.orbit_period),
+                    format!("{:.3} yrs", self.orbit_period / consts::S_YR as f32),
+                ]
+            } else {
+                unknown("Orbital period")
+            },
             vec![
-                String::from("Orbital period"),
-                format!("{:.3E} s", self.orbit_period),
-                format!("{:.3} yrs", self.orbit_period / consts::S_YR as f32),
+                String::from(SurveyKind::Gravitational),
+                self.survey.get_progress_text(SurveyKind::Gravitational),
+                String::new(),
             ],
-            
-        ]
+            vec![
+                String::from(SurveyKind::Geological),
+                self.survey.get_progress_text(SurveyKind::Geological),
+                String::new(),
+            ],
+        ];
+
+        self.resource_deposit.get_amounts().iter().for_each(|(resource, amount)| {
+            let name: String = resource.clone().into();
+            properties.push(if is_sampled {
+                vec![name, format!("{amount}%"), String::new()]
+            } else {
+                unknown(&name)
+            });
+        });
+
+        properties
     }
 
     fn get_name(&self) -> String {
@@ -171,4 +215,30 @@ impl Planet {
     pub fn is_inside_habitable_zone(&self) -> bool {
         self.habitable_zone.contains(&self.orbit_radius)
     }
+
+    pub fn get_resource_deposit(&self) -> &ResourceDeposit {
+        &self.resource_deposit
+    }
+
+    /// Makes a copy of the planet as seen by someone who surveyed it this much
+    pub fn with_survey(&self, survey: BodySurvey) -> Self {
+        Self {
+            survey,
+            ..self.clone()
+        }
+    }
+
+    /// Calculates how easy the planet is to detect, which grows with its visible disk
+    pub fn get_signature(&self) -> f32 {
+        (self.get_radius() / consts::EARTH_R_M).powi(2) * EARTH_SIGNATURE
+    }
+
+    /// Returns the current position of the planet relative to its star, in AU
+    pub fn get_position(&self) -> (f64, f64) {
+        let radius_au = (self.get_orbit_radius() / consts::AU_M) as f64;
+        (
+            radius_au * self.get_orbit_position().cos() as f64,
+            radius_au * self.get_orbit_position().sin() as f64,
+        )
+    }
 }
\ No newline at end of file
@@ -1,23 +1,33 @@
+use std::sync::Arc;
+
 use ordered_float::OrderedFloat;
 use rand::distributions::Distribution;
+use rand::Rng;
 use rand_distr;
 use rand_distr::num_traits::ToPrimitive;
 use ratatui::prelude::Span;
 use ratatui::style::{Color, Style};
-use ratatui::widgets::canvas::{Circle, Context};
+use ratatui::widgets::canvas::{Circle, Context, Points};
 use serde::{Deserialize, Serialize};
 
 use crate::game::celestial_bodies::{CanOrbit, CelestialBody, CelestialBodyType, Displayable, Orbitable};
+use crate::game::celestial_bodies::jump_point::JumpPoint;
 use crate::game::celestial_bodies::planet::Planet;
 use crate::game::celestial_bodies::star::Star;
 use crate::game::helpers::astrophysics;
+use crate::game::exploration::body_survey::BodySurvey;
 use crate::game::helpers::consts::AU_M;
 
+/// Maximum amount of jump points leading out of a system
+const MAX_JUMP_POINTS: u32 = 3;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SolarSystem {
     star: Star,
     planets: Vec<Planet>,
     spacing_factor: OrderedFloat<f32>,
+    #[serde(default)]
+    jump_points: Vec<JumpPoint>,
 }
 
 impl SolarSystem {
@@ -59,10 +69,43 @@ impl SolarSystem {
             |p| p.is_inside_habitable_zone()
         ).cloned()
     }
+
+    /// Finds the current position of the star or a planet of the system, in AU
+    pub fn get_body_position(&self, name: &str) -> Option<(f64, f64)> {
+        if self.star.get_name() == name {
+            return Some((0.0, 0.0))
+        }
+        self.planets.iter().find(|p| p.get_name() == name).map(|p| p.get_position())
+    }
+
+    pub fn get_jump_points(&self) -> Vec<JumpPoint> {
+        self.jump_points.clone()
+    }
+
+    /// Makes a copy of the system holding only the known bodies, along with their surveys
+    ///
+    /// # Arguments
+    /// * `planets` - Returns the survey of a known planet, `None` for an unknown one
+    /// * `jump_points` - Tells whether a jump point is known
+    pub fn with_known_bodies(
+        &self,
+        planets: impl Fn(&Planet) -> Option<BodySurvey>,
+        jump_points: impl Fn(&JumpPoint) -> bool,
+    ) -> Self {
+        Self {
+            star: self.star.clone(),
+            planets: self.planets.iter()
+                .filter_map(|p| planets(p).map(|survey| p.with_survey(survey)))
+                .collect(),
+            spacing_factor: self.spacing_factor,
+            jump_points: self.jump_points.iter().filter(|j| jump_points(j)).cloned().collect(),
+        }
+    }
 }
 
 impl CelestialBody for SolarSystem {
-    type HostType = ();
+    /// A system has no host, it is generated with the names its star can be given
+    type HostType = Arc<Vec<String>>;
 
     fn get_type(&self) -> CelestialBodyType {
         CelestialBodyType::SolarSystem
@@ -80,27 +123,38 @@ impl CelestialBody for SolarSystem {
         self.planets.last().unwrap().get_orbit_radius()
     }
 
-    fn generate(host: ()) -> Self {
-        let mut rng = rand::thread_rng();
-
+    fn generate(host: Arc<Vec<String>>, rng: &mut impl Rng) -> Self {
         let spacing_factor = rand_distr::Normal::new(
             0.4,
             0.2
-        ).unwrap().sample(&mut rng);
+        ).unwrap().sample(rng);
 
         let mut system = Self {
-            star: Star::generate(()),
+            star: Star::generate(host, rng),
             planets: vec![],
             spacing_factor: OrderedFloat(spacing_factor),
+            jump_points: vec![],
         };
 
         let n_planets: i32 = rand_distr::Normal::new(
             5.0,
             1.0
-        ).unwrap().sample(&mut rng) as i32;
+        ).unwrap().sample(rng) as i32;
 
         for _ in 0..n_planets {
-            system.planets.push(Planet::generate(system.clone()));
+            system.planets.push(Planet::generate(system.clone(), rng));
+        }
+
+        let edge = system.planets.last()
+            .map(|p| (p.get_orbit_radius() / AU_M) as f64)
+            .unwrap_or(5.0);
+        for n in 1..=rng.gen_range(1..=MAX_JUMP_POINTS) {
+            let radius = edge * rng.gen_range(1.1..1.6);
+            let angle: f64 = rng.gen_range(0.0..std::f64::consts::TAU);
+            system.jump_points.push(JumpPoint::new(
+                format!("{} jump point {n}", system.get_name()),
+                (radius * angle.cos(), radius * angle.sin()),
+            ));
         }
 
         system
@@ -188,5 +242,15 @@ impl SolarSystem {
                 );
             }
         );
+
+        self.jump_points.iter().for_each(|j| {
+            let (x, y) = j.get_position();
+            ctx.draw(&Points { coords: &[(x, y)], color: j.get_menu_color() });
+            ctx.print(
+                x + 0.2 * scale,
+                y + 0.2 * scale,
+                Span::styled(j.get_name(), Style::default().fg(j.get_menu_color())),
+            );
+        });
     }
 }
@@ -1,12 +1,15 @@
+use std::sync::Arc;
+
 use ordered_float::OrderedFloat;
 use rand;
+use rand::Rng;
 use rand::distributions::Distribution;
 use rand::seq::SliceRandom;
 use rand_distr::num_traits::ToPrimitive;
 use ratatui::style;
 use serde::{Deserialize, Serialize};
 
-use crate::game::celestial_bodies::{CelestialBody, CelestialBodyType, constants, Displayable};
+use crate::game::celestial_bodies::{CelestialBody, CelestialBodyType, Displayable};
 use crate::game::helpers::{astrophysics, consts};
 use crate::game::helpers::astrophysics::{
     calculate_inner_radius_of_habitable_zone_from_luminosity,
@@ -56,7 +59,8 @@ impl Star {
 }
 
 impl CelestialBody for Star {
-    type HostType = ();
+    /// A star has no host, it is generated with the names it can be given
+    type HostType = Arc<Vec<String>>;
 
     fn get_type(&self) -> CelestialBodyType {
         CelestialBodyType::Star
@@ -70,14 +74,11 @@ impl CelestialBody for Star {
         self.radius.to_f32().unwrap()
     }
 
-    fn generate(host: ()) -> Self {
-        let mut rng = rand::thread_rng();
-
-
+    fn generate(host: Arc<Vec<String>>, rng: &mut impl Rng) -> Self {
         let mass_solar: f32 = rand_distr::Normal::new(
             1.2,
             0.2,
-        ).unwrap().sample(&mut rng);
+        ).unwrap().sample(rng);
 
         let mass = mass_solar
             .min(10.0)
@@ -100,7 +101,7 @@ impl CelestialBody for Star {
             _ => { StarClass::M }
         };
         
-        let name = constants::STAR_NAMES.choose(&mut rng).unwrap().clone();
+        let name = host.choose(rng).unwrap().clone();
         Self {
             name,
             class,
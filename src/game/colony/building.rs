@@ -65,6 +65,12 @@ pub enum BuildingType {
     Factory(FactoryType),
     Spaceport,
     DryDock,
+    /// Trains garrisons and infantry
+    Barracks,
+    /// Trains armor
+    VehicleDepot,
+    /// Shoots down the troops landing to invade the colony
+    PlanetaryDefense,
 }
 
 impl Into<Color> for BuildingType {
@@ -74,6 +80,9 @@ impl Into<Color> for BuildingType {
             BuildingType::Factory(_) => Color::LightRed,
             BuildingType::Spaceport => Color::LightCyan,
             BuildingType::DryDock => Color::LightMagenta,
+            BuildingType::Barracks => Color::LightGreen,
+            BuildingType::VehicleDepot => Color::Green,
+            BuildingType::PlanetaryDefense => Color::LightBlue,
         }
     }
 }
@@ -85,6 +94,9 @@ impl Into<String> for BuildingType {
             BuildingType::Factory(factory_type) => { factory_type.into() }
             BuildingType::Spaceport => { "Spaceport".to_string() }
             BuildingType::DryDock => { "Dry dock".to_string() }
+            BuildingType::Barracks => { "Barracks".to_string() }
+            BuildingType::VehicleDepot => { "Vehicle depot".to_string() }
+            BuildingType::PlanetaryDefense => { "Planetary defense".to_string() }
         }
     }
 }
@@ -95,6 +107,9 @@ impl BuildingType {
             BuildingType::Mine,
             BuildingType::Spaceport,
             BuildingType::DryDock,
+            BuildingType::Barracks,
+            BuildingType::VehicleDepot,
+            BuildingType::PlanetaryDefense,
             BuildingType::Factory(FactoryType::ElectronicsFactory),
             BuildingType::Factory(FactoryType::KeroseneFactory),
             BuildingType::Factory(FactoryType::HeatResistantAlloyFactory),
@@ -133,12 +148,22 @@ impl Displayable for BuildingType {
 }
 
 impl BuildingType {
+    /// Finds the building type shown with the name, e.g. `Dry dock`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::get_variants().into_iter()
+            .map(|(building_type, _)| building_type)
+            .find(|building_type| building_type.get_name() == name)
+    }
+
     pub fn get_construction_time(&self) -> u32 {
         match self {
             BuildingType::Mine => { 5 }
             BuildingType::Factory(ft) => { ft.get_construction_time() }
             BuildingType::Spaceport => { 150 }
             BuildingType::DryDock => { 130 }
+            BuildingType::Barracks => { 40 }
+            BuildingType::VehicleDepot => { 90 }
+            BuildingType::PlanetaryDefense => { 60 }
         }
     }
 
@@ -148,6 +173,22 @@ impl BuildingType {
             BuildingType::Factory(_) => { true }
             BuildingType::Spaceport => { false }
             BuildingType::DryDock => { false }
+            BuildingType::Barracks => { false }
+            BuildingType::VehicleDepot => { false }
+            BuildingType::PlanetaryDefense => { false }
+        }
+    }
+
+    /// Returns how many colonists the building employs
+    pub fn get_jobs(&self) -> i32 {
+        match self {
+            BuildingType::Mine => { 250 }
+            BuildingType::Factory(_) => { 500 }
+            BuildingType::Spaceport => { 1000 }
+            BuildingType::DryDock => { 500 }
+            BuildingType::Barracks => { 250 }
+            BuildingType::VehicleDepot => { 500 }
+            BuildingType::PlanetaryDefense => { 250 }
         }
     }
 }
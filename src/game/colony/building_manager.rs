@@ -1,13 +1,16 @@
 use std::collections::HashMap;
 
 use log::info;
+use rand::Rng;
+use rand::seq::SliceRandom;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
 use crate::game::celestial_bodies::Displayable;
 use crate::game::colony::building::{BuildingType, FactoryType};
 use crate::game::colony::construction_process::ConstructionProcess;
-use crate::game::resource::resource::{ResourceDeposit, ResourceTransaction};
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
+use crate::game::resource::resource::{ResourceDeposit, ResourceTransaction, ResourceType};
 use crate::game::resource::resource_manager::ResourceManager;
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -15,6 +18,10 @@ pub struct BuildingManager {
     buildings: HashMap<BuildingType, u32>,
     construction: Vec<ConstructionProcess>,
     construction_limit: u32,
+    /// Hundredths of a unit of every resource made by the factory yield bonus that did not add
+    /// up to a whole unit yet
+    #[serde(default)]
+    yield_remainders: HashMap<ResourceType, u32>,
 }
 
 impl Default for BuildingManager {
@@ -24,6 +31,9 @@ impl Default for BuildingManager {
                 (BuildingType::DryDock, 0),
                 (BuildingType::Mine, 0),
                 (BuildingType::Spaceport, 0),
+                (BuildingType::Barracks, 0),
+                (BuildingType::VehicleDepot, 0),
+                (BuildingType::PlanetaryDefense, 0),
                 (BuildingType::Factory(FactoryType::ElectronicsFactory), 0),
                 (BuildingType::Factory(FactoryType::KeroseneFactory), 0),
                 (BuildingType::Factory(FactoryType::HeatResistantAlloyFactory), 0),
@@ -38,6 +48,7 @@ impl Default for BuildingManager {
             ]),
             construction: Vec::new(),
             construction_limit: 10,
+            yield_remainders: HashMap::new(),
         }
     }
 }
@@ -47,6 +58,10 @@ impl BuildingManager {
         Self::default()
     }
 
+    pub fn get_amount(&self, building_type: &BuildingType) -> u32 {
+        *self.buildings.get(building_type).unwrap_or(&0)
+    }
+
     fn add_building(&mut self, building_type: &BuildingType) {
         let current_amount = *self.buildings.get(
             building_type
@@ -58,6 +73,26 @@ impl BuildingManager {
         );
     }
 
+    /// Counts the colonists all the buildings employ
+    pub fn get_jobs(&self) -> i32 {
+        self.buildings.iter().map(|(bt, amount)| bt.get_jobs() * *amount as i32).sum()
+    }
+
+    /// Destroys a random building
+    ///
+    /// # Returns
+    /// * `Option<BuildingType>` - The destroyed building, `None` if there were no buildings
+    pub fn destroy_random(&mut self, rng: &mut impl Rng) -> Option<BuildingType> {
+        let mut built: Vec<&BuildingType> = self.buildings.iter()
+            .filter(|(_, amount)| **amount > 0)
+            .map(|(bt, _)| bt)
+            .collect();
+        built.sort_by_key(|bt| bt.get_name());
+        let building = (*built.choose(rng)?).clone();
+        *self.buildings.get_mut(&building).unwrap() -= 1;
+        Some(building)
+    }
+
     pub fn start_construction(
         &mut self,
         building_type: BuildingType,
@@ -70,33 +105,60 @@ impl BuildingManager {
         info!("Started construction");
     }
 
-    pub fn update_construction(&mut self) {
+    /// Advances the first construction in the queue by a day
+    ///
+    /// # Returns
+    /// * `Option<BuildingType>` - The type of the building, if it got finished
+    pub fn update_construction(&mut self) -> Option<BuildingType> {
         if !self.construction.is_empty() {
             let is_finished = self.construction[0].update(1);
             if is_finished {
-                let building = self.construction[0].building_type();
-                *self.buildings.get_mut(building).unwrap() += 1;
+                let building = self.construction[0].building_type().clone();
+                *self.buildings.get_mut(&building).unwrap() += 1;
                 self.construction.remove(0);
+                return Some(building)
             }
         }
+        None
     }
 
-    pub fn update_production(&self, manager: &mut ResourceManager) {
-        let transactions: Vec<Vec<ResourceTransaction>> = self.buildings
-            .iter().filter(
-            |(bt, v)| {
-                bt.is_producing_resources() && self.buildings.get(bt).unwrap_or(&0) > &0u32
-            }).map(|(bt, v)| {
-            if let BuildingType::Factory(factory_type) = bt {
-                factory_type.clone().into()
-            } else {
-                unreachable!()
+    /// Runs every factory, the output which is too small to make a whole unit is kept for the
+    /// next run
+    ///
+    /// # Arguments
+    /// * `manager` - Resources of the colony
+    /// * `modifiers` - Bonuses of the researched technologies
+    pub fn update_production(&mut self, manager: &mut ResourceManager, modifiers: &TechModifiers) {
+        let yield_percent = modifiers.get_percent(ModifierType::FactoryYield);
+        // The factories compete for the same resources, so they always produce in the same order
+        let factories: Vec<(FactoryType, u32)> = BuildingType::get_variants().into_iter()
+            .map(|(bt, _)| {
+                let count = *self.buildings.get(&bt).unwrap_or(&0);
+                (bt, count)
+            })
+            .filter(|(bt, count)| bt.is_producing_resources() && *count > 0)
+            .map(|(bt, count)| {
+                if let BuildingType::Factory(factory_type) = bt {
+                    (factory_type, count)
+                } else {
+                    unreachable!()
+                }
+            }).collect();
+
+        for (factory_type, count) in factories {
+            let mut remainders = self.yield_remainders.clone();
+            let transactions: Vec<ResourceTransaction> = factory_type.into();
+            let transactions: Vec<ResourceTransaction> = transactions.into_iter()
+                .map(|tr| {
+                    let remainder = remainders.entry(tr.resource_type().clone()).or_insert(0);
+                    tr.scaled(count, yield_percent, remainder)
+                }).collect();
+
+            if transactions.iter().all(|tr| manager.is_applicable(tr)) {
+                manager.apply_many(transactions);
+                self.yield_remainders = remainders;
             }
-        }).collect();
-
-        transactions.iter().for_each(|tr| {
-            manager.apply_many(tr.clone());
-        })
+        }
     }
 
     pub fn update_mines(
@@ -104,17 +166,20 @@ impl BuildingManager {
         resource_manager: &mut ResourceManager,
         deposit: &ResourceDeposit,
         rounds: usize,
+        modifiers: &TechModifiers,
+        rng: &mut impl Rng,
     ) {
-        for _ in 0..rounds {
-            for _ in 0..(*self.buildings.get(&BuildingType::Mine)
-                .unwrap_or(&0) as i32) {
-                resource_manager.apply(
-                    ResourceTransaction::new(
-                        deposit.sample(),
-                        1,
-                    )
+        let mines = *self.buildings.get(&BuildingType::Mine).unwrap_or(&0) as f32;
+        let samples = (mines * rounds as f32
+            * modifiers.get_multiplier(ModifierType::MiningEfficiency)).round() as usize;
+
+        for _ in 0..samples {
+            resource_manager.apply(
+                ResourceTransaction::new(
+                    deposit.sample(rng),
+                    1,
                 )
-            }
+            )
         }
     }
 
@@ -140,4 +205,47 @@ impl BuildingManager {
             (building, progress)
         }).collect()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::colony::building::{BuildingType, FactoryType};
+    use crate::game::colony::building_manager::BuildingManager;
+    use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
+    use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+    use crate::game::resource::resource_manager::ResourceManager;
+
+    fn superconductors_after(days: u32, factories: u32, yield_bonus: i32) -> u32 {
+        let mut buildings = BuildingManager::new();
+        let factory = BuildingType::Factory(FactoryType::SuperconductorsFactory);
+        (0..factories).for_each(|_| buildings.add_building(&factory));
+        let mut resources = ResourceManager::new();
+        resources.apply(ResourceTransaction::new(ResourceType::PRPreciousMetals, 10_000));
+        let mut modifiers = TechModifiers::default();
+        modifiers.add(ModifierType::FactoryYield, yield_bonus);
+
+        for _ in 0..days {
+            buildings.update_production(&mut resources, &modifiers);
+        }
+        resources.get_resources().into_iter()
+            .find(|(rt, _)| *rt == ResourceType::SRSuperconductors)
+            .map(|(_, amount)| amount)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_every_factory_produces() {
+        assert_eq!(superconductors_after(10, 1, 0), 10);
+        assert_eq!(superconductors_after(10, 3, 0), 30);
+    }
+
+    #[test]
+    fn test_small_yield_bonus_adds_up_over_days() {
+        assert_eq!(superconductors_after(1, 1, 10), 1);
+        assert_eq!(superconductors_after(9, 1, 10), 9);
+        assert_eq!(superconductors_after(10, 1, 10), 11);
+        assert_eq!(superconductors_after(10, 3, 10), 33);
+    }
 }
\ No newline at end of file
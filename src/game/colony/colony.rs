@@ -1,12 +1,53 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 
 use crate::game::celestial_bodies::Displayable;
 use crate::game::colony::building::BuildingType;
 use crate::game::colony::building_manager::BuildingManager;
-use crate::game::resource::resource::{ResourceDeposit, ResourceType};
+use crate::game::colony::colony_event::ColonyEvent;
+use crate::game::colony::stability::Stability;
+use crate::game::empires::policy::Policy;
+use crate::game::ground::ground_forces::GroundForces;
+use crate::game::ground::ground_unit::GroundUnitType;
+use crate::game::personnel::officer::Officer;
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
+use crate::game::resource::resource::{ResourceDeposit, ResourceTransaction, ResourceType};
 use crate::game::resource::resource_manager::ResourceManager;
 
+/// Sensor strength of a colony's ground observatories, without any sensor arrays
+const BASE_SENSOR_STRENGTH: f32 = 2.0;
+/// Sensor strength added by every Sensors component stored in the colony
+const SENSOR_STRENGTH_PER_COMPONENT: f32 = 0.05;
+/// Amount of stored Sensors components above which the arrays don't get any stronger
+const MAX_SENSOR_COMPONENTS: u32 = 200;
+/// Share of the colonists working in services, who don't need a building to have a job
+const SERVICE_JOBS_SHARE: f32 = 0.5;
+/// Amount of colonists drinking a unit of water every resource tick
+const COLONISTS_PER_WATER: i32 = 1_000;
+/// Stability of colonies on planets inside the habitable zone of their star
+const HABITABLE_STABILITY: i32 = 10;
+/// Stability of colonies on planets outside the habitable zone of their star
+const INHOSPITABLE_STABILITY: i32 = -15;
+/// Stability lost when the whole population is unemployed
+const UNEMPLOYMENT_STABILITY: i32 = -40;
+/// Stability lost while the colonists don't get enough water
+const SHORTAGE_STABILITY: i32 = -15;
+/// Share of the population killed by a revolt
+const REVOLT_CASUALTIES: f32 = 0.05;
+/// Amount of colonists for every garrison unit the barracks train before they train infantry
+const COLONISTS_PER_GARRISON: i32 = 500;
+/// Amount of colonists for every ground unit the colony can raise
+const COLONISTS_PER_GROUND_UNIT: i32 = 100;
+/// Share of the buildings destroyed when the colony is captured
+const CAPTURE_DAMAGE: f32 = 0.25;
+/// Share of the population killed when the colony is captured
+const CAPTURE_CASUALTIES: f32 = 0.1;
+/// Stability lost when the colony is captured
+const CAPTURE_STABILITY_LOSS: i32 = 30;
+
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Colony {
     planet_name: String,
@@ -14,42 +55,251 @@ pub struct Colony {
     building_manager: BuildingManager,
     resource_manager: ResourceManager,
     resource_deposit: ResourceDeposit,
+    /// `true` if the planet is inside the habitable zone of its star
+    #[serde(default)]
+    is_habitable: bool,
+    #[serde(default)]
+    stability: Stability,
+    /// `true` if the colonists didn't get enough water at the last resource tick
+    #[serde(default)]
+    has_shortage: bool,
+    /// Troops stationed in the colony
+    #[serde(default)]
+    ground_forces: GroundForces,
+    /// Days of training done for the next unit of every type
+    #[serde(default)]
+    training: BTreeMap<GroundUnitType, u32>,
 }
 
 impl Colony {
     pub fn new(
         planet_name: String,
         population: i32,
+        resource_deposit: ResourceDeposit,
+        is_habitable: bool,
     ) -> Self {
         Self {
             planet_name,
             population,
             building_manager: BuildingManager::new(),
             resource_manager: ResourceManager::new(),
-            resource_deposit: ResourceDeposit::generate_for_planet(),
+            resource_deposit,
+            is_habitable,
+            stability: Stability::default(),
+            has_shortage: false,
+            ground_forces: GroundForces::default(),
+            training: BTreeMap::new(),
         }
     }
 
-    pub fn tick(&mut self) {
-        self.building_manager.update_construction();
+    /// Advances the construction and the training of the ground units by a day
+    ///
+    /// # Returns
+    /// * `Option<ColonyEvent>` - The event of a finished building, if any
+    pub fn tick(&mut self) -> Option<ColonyEvent> {
+        let building = self.building_manager.update_construction();
+        self.train_ground_units();
+        building.map(|b| ColonyEvent::ConstructionFinished {
+            colony: self.get_name(),
+            building: b.get_name(),
+        })
+    }
+
+    /// Lets the barracks and vehicle depots train ground units. The barracks train garrisons
+    /// until the colony has enough of them, then infantry. Every building adds a day of training,
+    /// and a finished unit waits until the colony can pay for it.
+    fn train_ground_units(&mut self) {
+        let garrisons = (self.population / COLONISTS_PER_GARRISON) as u32;
+        let units = [
+            if self.ground_forces.get_amount(GroundUnitType::Garrison) < garrisons {
+                GroundUnitType::Garrison
+            } else {
+                GroundUnitType::Infantry
+            },
+            GroundUnitType::Armor,
+        ];
+
+        for unit in units {
+            let limit = (self.population / COLONISTS_PER_GROUND_UNIT) as u32;
+            let buildings = self.building_manager.get_amount(&unit.get_training_building());
+            if buildings == 0 || self.ground_forces.get_total() >= limit {
+                continue;
+            }
+
+            let progress = self.training.entry(unit).or_insert(0);
+            *progress += buildings;
+            if *progress >= unit.get_training_time() && self.pay(unit.get_cost()) {
+                self.training.remove(&unit);
+                self.ground_forces.add(unit, 1);
+            }
+        }
+    }
+
+    pub fn get_ground_forces(&self) -> &GroundForces {
+        &self.ground_forces
+    }
+
+    pub fn get_ground_forces_mut(&mut self) -> &mut GroundForces {
+        &mut self.ground_forces
+    }
+
+    pub fn get_defense_batteries(&self) -> u32 {
+        self.building_manager.get_amount(&BuildingType::PlanetaryDefense)
     }
 
-    pub fn resource_tick(&mut self) {
+    /// Hands the colony over to its conquerors. A share of the buildings is destroyed in the
+    /// fighting, the rest is kept.
+    ///
+    /// # Arguments
+    /// * `garrison` - The surviving invaders, who stay in the colony
+    /// * `rng` - The random number generator of the game
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Names of the destroyed buildings
+    pub fn capture(&mut self, garrison: GroundForces, rng: &mut impl Rng) -> Vec<String> {
+        let buildings: u32 = self.get_buildings().iter().map(|(_, amount, _)| amount).sum();
+        let destroyed = (0..(buildings as f32 * CAPTURE_DAMAGE).ceil() as u32)
+            .filter_map(|_| self.building_manager.destroy_random(rng))
+            .map(|b| b.get_name())
+            .collect();
+
+        self.population -= (self.population as f32 * CAPTURE_CASUALTIES).round() as i32;
+        self.stability.lower(CAPTURE_STABILITY_LOSS);
+        self.ground_forces = garrison;
+        self.training.clear();
+        destroyed
+    }
+
+    /// Mines and produces resources, then the colonists drink their water
+    ///
+    /// # Arguments
+    /// * `modifiers` - Bonuses of the researched technologies
+    /// * `policies` - Policies enacted by the empire
+    /// * `rng` - Random number generator of the game, the mined resources are picked with it
+    pub fn resource_tick(
+        &mut self,
+        modifiers: &TechModifiers,
+        policies: &[Policy],
+        rng: &mut impl Rng,
+    ) {
+        let mut modifiers = modifiers.clone();
+        policies.iter().for_each(|p| p.apply(&mut modifiers));
+        let penalty = self.stability.get_output_penalty();
+        modifiers.add(ModifierType::MiningEfficiency, -penalty);
+        modifiers.add(ModifierType::FactoryYield, -penalty);
+
         self.building_manager.update_mines(
             &mut self.resource_manager,
             &self.resource_deposit,
             10,
+            &modifiers,
+            rng,
         );
 
         self.building_manager.update_production(
             &mut self.resource_manager,
+            &modifiers,
         );
+
+        let percent = 100 + policies.iter().map(|p| p.get_consumption_percent()).sum::<i32>();
+        let water = (self.population * percent.max(0) / 100 + COLONISTS_PER_WATER - 1)
+            / COLONISTS_PER_WATER;
+        self.has_shortage = !self.pay(vec![
+            ResourceTransaction::new(ResourceType::PRWater, -water)
+        ]);
+    }
+
+    /// Returns the share of the colonists who have no job
+    pub fn get_unemployment(&self) -> f32 {
+        if self.population <= 0 {
+            return 0.0;
+        }
+        let jobs = (self.population as f32 * SERVICE_JOBS_SHARE).round() as i32
+            + self.building_manager.get_jobs();
+        (self.population - jobs).max(0) as f32 / self.population as f32
+    }
+
+    pub fn get_stability(&self) -> &Stability {
+        &self.stability
+    }
+
+    /// Moves the stability of the colony a day closer to the one it settles at
+    ///
+    /// # Arguments
+    /// * `policies` - Policies enacted by the empire
+    /// * `governor` - The officer governing the colony, if any
+    /// * `war_weariness` - Stability the colonists lose because of the wars of the empire
+    ///
+    /// # Returns
+    /// * `Option<ColonyEvent>` - The event if the colony fell into unrest or calmed down
+    pub fn update_stability(
+        &mut self,
+        policies: &[Policy],
+        governor: Option<&Officer>,
+        war_weariness: i32,
+    ) -> Option<ColonyEvent> {
+        let mut factors = vec![(
+            String::from("Habitability"),
+            if self.is_habitable { HABITABLE_STABILITY } else { INHOSPITABLE_STABILITY },
+        )];
+        let unemployment = (self.get_unemployment() * UNEMPLOYMENT_STABILITY as f32).round();
+        if unemployment < 0.0 {
+            factors.push((String::from("Unemployment"), unemployment as i32));
+        }
+        if self.has_shortage {
+            factors.push((String::from("Water shortage"), SHORTAGE_STABILITY));
+        }
+        if war_weariness > 0 {
+            factors.push((String::from("War weariness"), -war_weariness));
+        }
+        policies.iter().for_each(|p| factors.push((p.get_name(), p.get_stability_bonus())));
+        if let Some(governor) = governor.filter(|g| g.get_stability_bonus() > 0) {
+            factors.push((String::from("Governor"), governor.get_stability_bonus()));
+        }
+
+        if !self.stability.update(factors) {
+            return None;
+        }
+        let colony = self.get_name();
+        Some(if self.stability.is_in_unrest() {
+            ColonyEvent::UnrestStarted { colony }
+        } else {
+            ColonyEvent::UnrestEnded { colony }
+        })
+    }
+
+    /// Returns `true` if the colony is unstable enough to revolt under these policies
+    pub fn can_revolt(&self, policies: &[Policy]) -> bool {
+        self.stability.can_revolt() && !policies.iter().any(|p| p.prevents_revolts())
+    }
+
+    /// The colonists revolt, destroying a random building and losing some of their own
+    pub fn revolt(&mut self, rng: &mut impl Rng) -> ColonyEvent {
+        let building = self.building_manager.destroy_random(rng);
+        self.population -= (self.population as f32 * REVOLT_CASUALTIES).round() as i32;
+        ColonyEvent::Revolt {
+            colony: self.get_name(),
+            building: building.map(|b| b.get_name()),
+        }
     }
 
     pub fn get_population(&self) -> i32 {
         self.population
     }
 
+    /// Enlists some of the colonists as ship crew, removing them from the population
+    ///
+    /// # Arguments
+    /// * `amount` - Maximum amount of colonists to enlist
+    ///
+    /// # Returns
+    /// * `u32` - Amount of colonists enlisted
+    pub fn enlist_crew(&mut self, amount: u32) -> u32 {
+        let crew = amount.min(self.population.max(0) as u32);
+        self.population -= crew as i32;
+        crew
+    }
+
     pub fn get_buildings(&self) -> Vec<(String, u32, Color)> {
         self.building_manager.get_buildings()
     }
@@ -58,6 +308,40 @@ impl Colony {
         self.resource_manager.get_resources()
     }
 
+    pub fn get_resource_amount(&self, resource_type: &ResourceType) -> u32 {
+        self.resource_manager.get_amount(resource_type)
+    }
+
+    pub fn can_afford(&self, transactions: &[ResourceTransaction]) -> bool {
+        transactions.iter().all(|t| self.resource_manager.is_applicable(t))
+    }
+
+    /// Spends the colony's resources, either all the transactions are applied or none
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the colony could afford the transactions
+    pub fn pay(&mut self, transactions: Vec<ResourceTransaction>) -> bool {
+        if !self.can_afford(&transactions) {
+            return false;
+        }
+        self.resource_manager.apply_many(transactions);
+        true
+    }
+
+    /// Counts the buildings able to repair ships, that is dry docks and spaceports
+    pub fn get_repair_facilities(&self) -> u32 {
+        self.building_manager.get_amount(&BuildingType::DryDock)
+            + self.building_manager.get_amount(&BuildingType::Spaceport)
+    }
+
+    /// Calculates the strength of the colony's sensors. The stored Sensors components are
+    /// assembled into sensor arrays, up to a limit.
+    pub fn get_sensor_strength(&self) -> f32 {
+        let components = self.get_resource_amount(&ResourceType::CSensors)
+            .min(MAX_SENSOR_COMPONENTS);
+        BASE_SENSOR_STRENGTH + components as f32 * SENSOR_STRENGTH_PER_COMPONENT
+    }
+
     pub fn get_construction(&self) -> Vec<(String, u32)> {
         self.building_manager.get_construction()
     }
@@ -72,6 +356,34 @@ impl Colony {
         let mut lines = Vec::<(String, Color)>::new();
         lines.push((format!("Name: {}", self.get_name()), Color::Cyan));
         lines.push((format!("Population: {}", self.get_population()), Color::Gray));
+        lines.push((
+            format!(
+                "Stability: {} (settling at {})",
+                self.stability.get_value(),
+                self.stability.get_target(),
+            ),
+            if self.stability.can_revolt() {
+                Color::LightRed
+            } else if self.stability.is_in_unrest() {
+                Color::LightYellow
+            } else {
+                Color::Gray
+            },
+        ));
+        self.stability.get_factors().iter().for_each(|(factor, value)| {
+            lines.push((
+                format!("  {}: {:+}", factor, value),
+                if *value < 0 { Color::LightRed } else { Color::LightGreen },
+            ));
+        });
+        lines.push((
+            format!("Unemployment: {:.0}%", self.get_unemployment() * 100.0),
+            Color::Gray,
+        ));
+        lines.push((
+            format!("Ground forces: {}", self.ground_forces.get_description()),
+            Color::Gray,
+        ));
 
         self.get_resources().iter()
             .for_each(|(resource, amount)| {
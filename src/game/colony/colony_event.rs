@@ -0,0 +1,56 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// `ColonyEvent` is a change in the mood of a colony's people or in its buildings
+pub enum ColonyEvent {
+    /// The stability of a colony dropped low enough to slow down its mines and factories
+    UnrestStarted { colony: String },
+    /// A colony in unrest calmed down
+    UnrestEnded { colony: String },
+    /// The people of a colony revolted, destroying a building if the colony had any
+    Revolt { colony: String, building: Option<String> },
+    /// A building under construction got finished
+    ConstructionFinished { colony: String, building: String },
+}
+
+impl Displayable for ColonyEvent {
+    fn get_name(&self) -> String {
+        match self {
+            ColonyEvent::UnrestStarted { colony } => {
+                format!("Unrest broke out on {colony}")
+            }
+            ColonyEvent::UnrestEnded { colony } => {
+                format!("The unrest on {colony} calmed down")
+            }
+            ColonyEvent::Revolt { colony, building: Some(building) } => {
+                format!("The people of {colony} revolted and destroyed a {building}")
+            }
+            ColonyEvent::Revolt { colony, building: None } => {
+                format!("The people of {colony} revolted")
+            }
+            ColonyEvent::ConstructionFinished { colony, building } => {
+                format!("Construction of a {building} on {colony} finished")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            ColonyEvent::UnrestStarted { .. } => Color::LightYellow,
+            ColonyEvent::UnrestEnded { .. } => Color::LightGreen,
+            ColonyEvent::Revolt { .. } => Color::LightRed,
+            ColonyEvent::ConstructionFinished { .. } => Color::LightCyan,
+        }
+    }
+}
+
+impl GameEvent for ColonyEvent {
+    fn get_category(&self) -> EventCategory {
+        match self {
+            ColonyEvent::ConstructionFinished { .. } => EventCategory::Construction,
+            _ => EventCategory::Colony,
+        }
+    }
+}
\ No newline at end of file
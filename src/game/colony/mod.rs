@@ -1,4 +1,6 @@
 pub mod colony;
 pub mod building;
 mod building_manager;
-mod construction_process;
\ No newline at end of file
+mod construction_process;
+pub mod stability;
+pub mod colony_event;
\ No newline at end of file
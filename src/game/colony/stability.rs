@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+/// Stability of a newly founded colony
+const INITIAL_STABILITY: i32 = 60;
+/// Stability a colony settles at when nothing troubles or pleases its people
+const BASE_STABILITY: i32 = 60;
+/// Highest possible stability
+const MAX_STABILITY: i32 = 100;
+/// Stability below which a colony is in unrest and its mines and factories slow down
+pub const UNREST_STABILITY: i32 = 40;
+/// Stability below which a colony in unrest may revolt
+pub const REVOLT_STABILITY: i32 = 20;
+/// How much the stability moves towards the settled one every day
+const STABILITY_CHANGE_PER_DAY: i32 = 1;
+
+/// `Stability` describes how content the people of a colony are. Every day it moves towards the
+/// stability the colony settles at, which is the sum of everything affecting the colony.
+///
+/// **Fields**
+/// - value (`i32`) - current stability, from 0 to 100
+/// - factors (`Vec<(String, i32)>`) - everything affecting the settled stability at the last
+///   update, along with how much it adds or takes away
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Stability {
+    value: i32,
+    factors: Vec<(String, i32)>,
+}
+
+impl Default for Stability {
+    fn default() -> Self {
+        Self {
+            value: INITIAL_STABILITY,
+            factors: Vec::new(),
+        }
+    }
+}
+
+impl Stability {
+    pub fn get_value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn get_factors(&self) -> &Vec<(String, i32)> {
+        &self.factors
+    }
+
+    /// Returns the stability the colony settles at over time
+    pub fn get_target(&self) -> i32 {
+        (BASE_STABILITY + self.factors.iter().map(|(_, v)| v).sum::<i32>())
+            .clamp(0, MAX_STABILITY)
+    }
+
+    pub fn is_in_unrest(&self) -> bool {
+        self.value < UNREST_STABILITY
+    }
+
+    pub fn can_revolt(&self) -> bool {
+        self.value < REVOLT_STABILITY
+    }
+
+    /// Returns how much the unrest slows down the mines and factories, in percent
+    pub fn get_output_penalty(&self) -> i32 {
+        (UNREST_STABILITY - self.value).max(0)
+    }
+
+    /// Lowers the stability at once, e.g. after a shock to the colony
+    pub fn lower(&mut self, amount: i32) {
+        self.value = (self.value - amount).max(0);
+    }
+
+    /// Moves the stability a day closer to the settled one
+    ///
+    /// # Arguments
+    /// * `factors` - Everything affecting the colony, along with how much it adds or takes away
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the colony fell into unrest or calmed down
+    pub fn update(&mut self, factors: Vec<(String, i32)>) -> bool {
+        let was_in_unrest = self.is_in_unrest();
+        self.factors = factors;
+        let target = self.get_target();
+        self.value += (target - self.value)
+            .clamp(-STABILITY_CHANGE_PER_DAY, STABILITY_CHANGE_PER_DAY);
+        was_in_unrest != self.is_in_unrest()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stability_settles_at_the_sum_of_factors() {
+        let mut stability = Stability::default();
+        let factors = vec![
+            (String::from("Shortages"), -15),
+            (String::from("War weariness"), -10),
+        ];
+        let mut changes = 0;
+        for _ in 0..100 {
+            if stability.update(factors.clone()) {
+                changes += 1;
+            }
+        }
+
+        assert_eq!(stability.get_value(), BASE_STABILITY - 25);
+        assert!(stability.is_in_unrest());
+        assert!(!stability.can_revolt());
+        assert_eq!(stability.get_output_penalty(), UNREST_STABILITY - (BASE_STABILITY - 25));
+        assert_eq!(changes, 1);
+    }
+}
\ No newline at end of file
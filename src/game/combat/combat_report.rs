@@ -0,0 +1,116 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, format_date, GameEvent};
+
+/// `CombatSide` summarizes how a single owner fared in an engagement
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CombatSide {
+    pub owner: String,
+    /// Amount of ships the owner had when the engagement started
+    pub ships: u32,
+    pub ships_lost: Vec<String>,
+    pub damage_dealt: u32,
+    pub missiles_intercepted: u32,
+}
+
+/// `CombatReport` summarizes a single engagement between hostile fleets
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CombatReport {
+    pub date: u64,
+    pub location: String,
+    pub rounds: u32,
+    pub sides: Vec<CombatSide>,
+    /// Modules destroyed during the engagement, in the form of `Ship: Module`
+    pub destroyed_modules: Vec<String>,
+}
+
+impl CombatReport {
+    /// Creates an empty report
+    ///
+    /// # Arguments
+    /// * `date` - The date of the engagement
+    /// * `location` - Name of the celestial body the engagement happened at
+    /// * `sides` - Owners taking part in the engagement along with their amount of ships
+    pub fn new(date: u64, location: String, sides: Vec<(String, u32)>) -> Self {
+        Self {
+            date,
+            location,
+            rounds: 0,
+            sides: sides.into_iter().map(|(owner, ships)| CombatSide {
+                owner,
+                ships,
+                ships_lost: Vec::new(),
+                damage_dealt: 0,
+                missiles_intercepted: 0,
+            }).collect(),
+            destroyed_modules: Vec::new(),
+        }
+    }
+
+    pub fn get_side_mut(&mut self, owner: &str) -> Option<&mut CombatSide> {
+        self.sides.iter_mut().find(|s| s.owner == owner)
+    }
+
+    /// Returns the owners who still have ships after the engagement
+    pub fn get_survivors(&self) -> Vec<String> {
+        self.sides.iter()
+            .filter(|s| (s.ships_lost.len() as u32) < s.ships)
+            .map(|s| s.owner.clone())
+            .collect()
+    }
+}
+
+impl Displayable for CombatReport {
+    fn get_name(&self) -> String {
+        format!(
+            "Battle at {}: {}",
+            self.location,
+            self.sides.iter()
+                .map(|s| format!("{} lost {}/{} ships", s.owner, s.ships_lost.len(), s.ships))
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        let mut properties = vec![
+            vec![format!("[{}] Battle at {}", format_date(self.date), self.location)],
+            vec![format!("Rounds fought: {}", self.rounds)],
+        ];
+
+        self.sides.iter().for_each(|s| {
+            properties.push(vec![format!(
+                "{}: {} ships, {} lost, {} damage dealt, {} missiles intercepted",
+                s.owner,
+                s.ships,
+                s.ships_lost.len(),
+                s.damage_dealt,
+                s.missiles_intercepted,
+            )]);
+            if !s.ships_lost.is_empty() {
+                properties.push(vec![format!("  Destroyed: {}", s.ships_lost.join(", "))]);
+            }
+        });
+
+        if !self.destroyed_modules.is_empty() {
+            properties.push(vec![format!(
+                "Modules knocked out: {}",
+                self.destroyed_modules.join(", "),
+            )]);
+        }
+
+        properties
+    }
+
+    fn get_menu_color(&self) -> Color {
+        Color::LightRed
+    }
+}
+
+impl GameEvent for CombatReport {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Combat
+    }
+}
\ No newline at end of file
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::combat::combat_report::CombatReport;
+use crate::game::fleets::fleet::Fleet;
+use crate::game::research::tech_modifiers::TechModifiers;
+use crate::game::shipbuilding::weapon::WeaponKind;
+
+/// Maximum amount of rounds fought in a single engagement, one engagement happens every tick
+pub const MAX_ROUNDS: u32 = 8;
+
+/// A single weapon fired at a single ship during a combat round
+struct Shot {
+    owner: String,
+    /// Index of the target fleet and ship
+    target: (usize, usize),
+    kind: WeaponKind,
+    damage: u32,
+    hit_chance: f32,
+}
+
+/// Resolves a single engagement between all the hostile fleets sharing a location. The fleets
+/// start at the range of the longest ranged weapon present and close in by one range band every
+/// round, until only one side is left or the round limit is reached. Destroyed ships are left
+/// in their fleets for the caller to remove.
+///
+/// # Arguments
+/// * `location` - Name of the celestial body the fleets are at
+/// * `date` - The current in-game date
+/// * `fleets` - All the fleets at the location
+/// * `is_hostile` - A function telling whether the first owner fights the second one
+/// * `modifiers` - Bonuses of the researched technologies of every owner
+/// * `accuracy` - Factors the hit chance of the fleets with commanders is multiplied by, by id
+/// * `rng` - The random number generator of the game
+///
+/// # Returns
+/// * `Option<CombatReport>` - The summary of the engagement, `None` if nobody was hostile
+pub fn resolve_engagement(
+    location: &str,
+    date: u64,
+    fleets: &mut [&mut Fleet],
+    is_hostile: impl Fn(&str, &str) -> bool,
+    modifiers: &HashMap<String, TechModifiers>,
+    accuracy: &HashMap<u32, f32>,
+    rng: &mut impl Rng,
+) -> Option<CombatReport> {
+    let no_modifiers = TechModifiers::default();
+    let modifiers_of = |owner: &str| modifiers.get(owner).unwrap_or(&no_modifiers);
+
+    let mut sides = Vec::<(String, u32)>::new();
+    fleets.iter().for_each(|f| {
+        let ships = f.get_ships().iter().filter(|s| !s.is_destroyed()).count() as u32;
+        match sides.iter_mut().find(|(owner, _)| owner == f.get_owner()) {
+            Some((_, amount)) => *amount += ships,
+            None => sides.push((f.get_owner().clone(), ships)),
+        }
+    });
+    sides.retain(|(_, ships)| *ships > 0);
+
+    let any_hostile = sides.iter().any(|(a, _)| sides.iter().any(|(b, _)| is_hostile(a, b)));
+    if !any_hostile {
+        return None;
+    }
+
+    let mut report = CombatReport::new(date, location.to_string(), sides);
+    let mut distance = fleets.iter()
+        .flat_map(|f| f.get_ships().iter())
+        .flat_map(|s| s.get_weapons().into_iter().map(|(w, _)| *w.range()))
+        .max()
+        .unwrap_or(1)
+        .max(1);
+
+    for round in 1..=MAX_ROUNDS {
+        let alive: Vec<(usize, usize)> = fleets.iter().enumerate()
+            .flat_map(|(fi, f)| {
+                f.get_ships().iter().enumerate()
+                    .filter(|(_, s)| !s.is_destroyed())
+                    .map(move |(si, _)| (fi, si))
+            })
+            .collect();
+
+        let mut shots = Vec::<Shot>::new();
+        for (fi, si) in alive.iter() {
+            let owner = fleets[*fi].get_owner().clone();
+            let targets: Vec<(usize, usize)> = alive.iter()
+                .filter(|(ti, _)| is_hostile(&owner, fleets[*ti].get_owner()))
+                .cloned()
+                .collect();
+            if targets.is_empty() {
+                continue;
+            }
+
+            for (weapon, strength) in fleets[*fi].get_ships()[*si].get_weapons() {
+                let damage = (*weapon.damage() as f32 * strength).round() as u32;
+                if *weapon.range() < distance || damage == 0 {
+                    continue;
+                }
+                let (ti, tsi) = *targets.choose(rng).unwrap();
+                let target = &fleets[ti].get_ships()[tsi];
+                let falloff = weapon.kind().get_accuracy_falloff()
+                    * (distance - 1) as f32 / *weapon.range() as f32;
+                let evasion = target.get_evasion(modifiers_of(fleets[ti].get_owner()));
+                let accuracy = accuracy.get(&fleets[*fi].get_id()).unwrap_or(&1.0);
+
+                shots.push(Shot {
+                    owner: owner.clone(),
+                    target: (ti, tsi),
+                    kind: *weapon.kind(),
+                    damage,
+                    hit_chance: weapon.accuracy() * accuracy * (1.0 - falloff) * (1.0 - evasion),
+                });
+            }
+        }
+
+        if shots.is_empty() {
+            if distance == 1 {
+                break;
+            }
+            distance -= 1;
+            continue;
+        }
+        report.rounds = round;
+
+        let mut point_defense = HashMap::<String, Vec<f32>>::new();
+        alive.iter().for_each(|(fi, si)| {
+            let pool = point_defense.entry(fleets[*fi].get_owner().clone()).or_default();
+            fleets[*fi].get_ships()[*si].get_point_defenses().iter().for_each(|pd| {
+                pool.extend(std::iter::repeat_n(*pd.interception(), *pd.shots() as usize))
+            });
+        });
+
+        for shot in shots {
+            let (ti, tsi) = shot.target;
+            let target_owner = fleets[ti].get_owner().clone();
+            if fleets[ti].get_ships()[tsi].is_destroyed() {
+                continue;
+            }
+
+            if shot.kind == WeaponKind::Missile {
+                let interception = point_defense.get_mut(&target_owner).and_then(|p| p.pop());
+                if let Some(chance) = interception {
+                    if rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+                        if let Some(side) = report.get_side_mut(&target_owner) {
+                            side.missiles_intercepted += 1;
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            if !rng.gen_bool(shot.hit_chance.clamp(0.0, 1.0) as f64) {
+                continue;
+            }
+
+            let ship = &mut fleets[ti].get_ships_mut()[tsi];
+            let knocked_out = ship.take_damage(shot.damage, rng);
+            let ship_name = ship.get_name();
+            let is_destroyed = ship.is_destroyed();
+
+            report.destroyed_modules.extend(
+                knocked_out.into_iter().map(|m| format!("{ship_name}: {m}"))
+            );
+            if let Some(side) = report.get_side_mut(&shot.owner) {
+                side.damage_dealt += shot.damage;
+            }
+            if is_destroyed {
+                if let Some(side) = report.get_side_mut(&target_owner) {
+                    side.ships_lost.push(ship_name);
+                }
+            }
+        }
+
+        fleets.iter_mut().for_each(|f| {
+            f.get_ships_mut().iter_mut()
+                .filter(|s| !s.is_destroyed())
+                .for_each(|s| s.recharge_shields())
+        });
+
+        let survivors = report.get_survivors();
+        if !survivors.iter().any(|a| survivors.iter().any(|b| is_hostile(a, b))) {
+            break;
+        }
+        distance = (distance - 1).max(1);
+    }
+
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::game::combat::engagement::resolve_engagement;
+    use crate::game::fleets::fleet::Fleet;
+    use crate::game::fleets::ship::Ship;
+    use crate::game::fleets::ship_design::ShipDesign;
+    use crate::game::shipbuilding::installed_module::ModuleKind;
+
+    fn ship(name: &str, damage: u32) -> Ship {
+        let design: ShipDesign = serde_json::from_value(serde_json::json!({
+            "id": "test",
+            "name": "Test",
+            "hull_points": 20,
+            "mass": 1000,
+            "modules": [],
+        })).unwrap();
+        let weapon = serde_json::from_value(serde_json::json!({
+            "name": "Gun",
+            "traits": [],
+            "is_unlocked": true,
+            "kind": "Kinetic",
+            "damage": damage,
+            "range": 1,
+            "accuracy": 1.0,
+        })).unwrap();
+        Ship::new(name.to_string(), &design, vec![ModuleKind::Weapon(weapon)])
+    }
+
+    fn fleet(owner: &str, ships: Vec<Ship>) -> Fleet {
+        Fleet::new(0, owner.to_string(), owner.to_string(), "Earth".to_string(), ships)
+    }
+
+    #[test]
+    fn test_friendly_fleets_do_not_fight() {
+        let mut a = fleet("a", vec![ship("A1", 5)]);
+        let mut b = fleet("a", vec![ship("A2", 5)]);
+        let report = resolve_engagement(
+            "Earth", 0, &mut [&mut a, &mut b], |x, y| x != y, &HashMap::new(), &HashMap::new(),
+            &mut StdRng::seed_from_u64(0),
+        );
+        assert_eq!(report, None);
+    }
+
+    #[test]
+    fn test_stronger_side_wins() {
+        let mut a = fleet("a", vec![ship("A1", 30), ship("A2", 30)]);
+        let mut b = fleet("b", vec![ship("B1", 1)]);
+        let report = resolve_engagement(
+            "Earth", 0, &mut [&mut a, &mut b], |x, y| x != y, &HashMap::new(), &HashMap::new(),
+            &mut StdRng::seed_from_u64(0),
+        ).unwrap();
+
+        assert_eq!(report.rounds, 1);
+        assert_eq!(report.get_survivors(), vec!["a".to_string()]);
+        assert_eq!(report.sides[1].ships_lost, vec!["B1".to_string()]);
+        assert_eq!(b.remove_destroyed(), vec!["B1".to_string()]);
+        assert!(b.is_empty());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod engagement;
+pub mod combat_report;
\ No newline at end of file
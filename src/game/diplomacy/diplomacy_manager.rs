@@ -0,0 +1,658 @@
+use std::collections::HashMap;
+
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::celestial_bodies::solar_system::SolarSystem;
+use crate::game::diplomacy::diplomatic_event::DiplomaticEvent;
+use crate::game::diplomacy::diplomatic_option::DiplomaticOption;
+use crate::game::diplomacy::incident::Incident;
+use crate::game::diplomacy::treaty::Treaty;
+use crate::game::empires::empire::{Empire, get_empire_name};
+use crate::game::event_log::EventLog;
+use crate::game::fleets::fleet::{Fleet, PLAYER_OWNER};
+use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+
+/// Amount of days a proposal waits for an answer before it is withdrawn
+const PROPOSAL_DURATION: u64 = 30;
+/// Amount of days an empire waits after a refusal before making another offer to the same empire
+const NEGOTIATION_COOLDOWN: u64 = 180;
+const MIN_OPINION: i32 = -100;
+const MAX_OPINION: i32 = 100;
+/// How much an opinion moves every month towards the one the empires settle at
+const OPINION_DRIFT: i32 = 2;
+/// How much every common enemy raises the opinion two empires settle at
+const COMMON_ENEMY_OPINION: i32 = 15;
+/// Amount of days between the monthly updates of the relations between the empires
+const DAYS_IN_MONTH: u64 = 30;
+/// Colonies of two empires closer than this many AU strain their relations
+const BORDER_DISTANCE: f64 = 0.5;
+/// Resources the capitals of two empires with a trade agreement receive every month
+const TRADE_GOODS: [ResourceType; 3] = [
+    ResourceType::PRLightMetals,
+    ResourceType::PRHeavyMetals,
+    ResourceType::PRWater,
+];
+/// Amount of every traded resource received every month
+const TRADE_GOODS_AMOUNT: i32 = 20;
+/// Research points a research sharing partner adds every day to the shared researches
+const SHARED_RESEARCH_POINTS: u32 = 1;
+
+/// `Proposal` is a treaty offered by one empire to another, waiting for an answer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub from: String,
+    pub to: String,
+    pub treaty: Treaty,
+    pub date: u64,
+}
+
+/// `DiplomacyManager` holds the treaties between the empires and what they think of each other
+///
+/// **Fields**
+/// - treaties (`HashMap<(String, String), Vec<Treaty>>`) - treaties signed by every pair of
+///   empires, the ids of the pair are sorted
+/// - opinions (`HashMap<(String, String), i32>`) - opinion of the first empire about the second
+///   one, from -100 to 100
+/// - proposals (`Vec<Proposal>`) - proposals which were not answered yet
+/// - refusals (`HashMap<(String, String), u64>`) - date of the last refused or expired offer of
+///   the first empire to the second one
+#[derive(Clone, Debug, Default)]
+pub struct DiplomacyManager {
+    treaties: HashMap<(String, String), Vec<Treaty>>,
+    opinions: HashMap<(String, String), i32>,
+    proposals: Vec<Proposal>,
+    refusals: HashMap<(String, String), u64>,
+}
+
+/// Describes an opinion in a word
+pub fn get_opinion_description(opinion: i32) -> &'static str {
+    match opinion {
+        50.. => "Friendly",
+        10..=49 => "Cordial",
+        -9..=9 => "Indifferent",
+        -49..=-10 => "Wary",
+        _ => "Hostile",
+    }
+}
+
+fn has_player_met(empires: &[Empire], id: &str) -> bool {
+    empires.iter()
+        .find(|e| e.is_player())
+        .is_some_and(|player| player.get_knowledge().has_met(id))
+}
+
+/// Tells whether the player hears about the relations between two empires, which happens when
+/// the player is one of them or has met both
+fn is_noticed(empires: &[Empire], first: &str, second: &str) -> bool {
+    first == PLAYER_OWNER
+        || second == PLAYER_OWNER
+        || (has_player_met(empires, first) && has_player_met(empires, second))
+}
+
+fn get_pair(first: &str, second: &str) -> (String, String) {
+    if first < second {
+        (first.to_string(), second.to_string())
+    } else {
+        (second.to_string(), first.to_string())
+    }
+}
+
+impl DiplomacyManager {
+    /// Returns the treaties signed by two empires, in the order of `Treaty::get_variants`
+    pub fn get_treaties(&self, first: &str, second: &str) -> Vec<Treaty> {
+        let signed = self.treaties.get(&get_pair(first, second));
+        Treaty::get_variants().into_iter()
+            .filter(|t| signed.is_some_and(|s| s.contains(t)))
+            .collect()
+    }
+
+    pub fn has_treaty(&self, first: &str, second: &str, treaty: Treaty) -> bool {
+        self.treaties.get(&get_pair(first, second)).is_some_and(|t| t.contains(&treaty))
+    }
+
+    /// Tells whether the fleets of the first owner attack the ones of the second owner, which
+    /// is the case for everyone without a peace treaty, including pirates
+    pub fn are_hostile(&self, first: &str, second: &str) -> bool {
+        first != second && !self.has_treaty(first, second, Treaty::Peace)
+    }
+
+    /// Returns the ids of the allies of an empire, sorted
+    pub fn get_allies(&self, empire: &str) -> Vec<String> {
+        let mut allies: Vec<String> = self.treaties.iter()
+            .filter(|(_, treaties)| treaties.contains(&Treaty::Alliance))
+            .filter_map(|((first, second), _)| {
+                if first == empire {
+                    Some(second.clone())
+                } else if second == empire {
+                    Some(first.clone())
+                } else {
+                    None
+                }
+            })
+            .collect();
+        allies.sort();
+        allies
+    }
+
+    /// Returns every pair of empires which signed a treaty, sorted
+    pub fn get_signed(&self, treaty: Treaty) -> Vec<(String, String)> {
+        let mut pairs: Vec<(String, String)> = self.treaties.iter()
+            .filter(|(_, treaties)| treaties.contains(&treaty))
+            .map(|(pair, _)| pair.clone())
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    /// Signs a treaty and withdraws the proposals of the same treaty between the empires
+    pub fn sign(&mut self, first: &str, second: &str, treaty: Treaty) {
+        let treaties = self.treaties.entry(get_pair(first, second)).or_default();
+        if !treaties.contains(&treaty) {
+            treaties.push(treaty);
+        }
+        self.proposals.retain(|p| {
+            p.treaty != treaty || get_pair(&p.from, &p.to) != get_pair(first, second)
+        });
+    }
+
+    /// Cancels a treaty, cancelling the peace cancels every other treaty too
+    pub fn cancel(&mut self, first: &str, second: &str, treaty: Treaty) {
+        if let Some(treaties) = self.treaties.get_mut(&get_pair(first, second)) {
+            if treaty == Treaty::Peace {
+                treaties.clear();
+            } else {
+                treaties.retain(|t| *t != treaty);
+            }
+        }
+    }
+
+    /// Breaks every treaty between two empires. The allies of the attacked empire go to war
+    /// with the attacker as well.
+    ///
+    /// # Arguments
+    /// * `by` - Id of the empire declaring the war
+    /// * `on` - Id of the attacked empire
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Ids of the allies who joined the war
+    pub fn declare_war(&mut self, by: &str, on: &str) -> Vec<String> {
+        let allies: Vec<String> = self.get_allies(on).into_iter()
+            .filter(|a| a != by && !self.are_hostile(a, by))
+            .collect();
+
+        self.cancel(by, on, Treaty::Peace);
+        self.record_incident(on, by, Incident::WarDeclared);
+        for ally in allies.iter() {
+            self.cancel(by, ally, Treaty::Peace);
+            self.record_incident(ally, by, Incident::WarDeclared);
+        }
+        self.proposals.retain(|p| get_pair(&p.from, &p.to) != get_pair(by, on));
+        allies
+    }
+
+    /// Returns what the first empire thinks of the second one
+    pub fn get_opinion(&self, of: &str, about: &str) -> i32 {
+        *self.opinions.get(&(of.to_string(), about.to_string())).unwrap_or(&0)
+    }
+
+    pub fn change_opinion(&mut self, of: &str, about: &str, change: i32) {
+        let opinion = self.opinions.entry((of.to_string(), about.to_string())).or_insert(0);
+        *opinion = (*opinion + change).clamp(MIN_OPINION, MAX_OPINION);
+    }
+
+    /// Lowers the opinion of the offended empire about the culprit
+    pub fn record_incident(&mut self, offended: &str, culprit: &str, incident: Incident) {
+        self.change_opinion(offended, culprit, -incident.get_opinion_penalty());
+    }
+
+    /// Returns the opinion the first empire settles at about the second one over time, which
+    /// rises with every treaty and every common enemy
+    fn get_settled_opinion(&self, of: &str, about: &str, empires: &[String]) -> i32 {
+        let treaties: i32 = self.get_treaties(of, about).iter()
+            .map(|t| t.get_opinion_bonus())
+            .sum();
+        let common_enemies = empires.iter()
+            .filter(|e| *e != of && *e != about)
+            .filter(|e| self.are_hostile(of, e) && self.are_hostile(about, e))
+            .count() as i32;
+        treaties + common_enemies * COMMON_ENEMY_OPINION
+    }
+
+    /// Moves the opinion of every empire about every other one a step closer to the one it
+    /// settles at, so that incidents are forgotten over time. Happens once a month.
+    pub fn drift_opinions(&mut self, empires: &[String]) {
+        for of in empires.iter() {
+            for about in empires.iter().filter(|e| *e != of) {
+                let settled = self.get_settled_opinion(of, about, empires);
+                let opinion = self.get_opinion(of, about);
+                let drift = (settled - opinion).clamp(-OPINION_DRIFT, OPINION_DRIFT);
+                self.change_opinion(of, about, drift);
+            }
+        }
+    }
+
+    /// Tells whether an empire may offer a treaty: peace is offered to enemies, every other
+    /// treaty needs peace first, and the same offer can be made only once at a time
+    pub fn can_propose(&self, from: &str, to: &str, treaty: Treaty) -> bool {
+        let is_at_war = self.are_hostile(from, to);
+        from != to
+            && !self.has_treaty(from, to, treaty)
+            && (treaty == Treaty::Peace) == is_at_war
+            && !self.proposals.iter().any(|p| p.from == from && p.to == to && p.treaty == treaty)
+    }
+
+    /// Tells whether an empire likes the proposing empire enough to sign a treaty with it
+    pub fn would_accept(&self, to: &str, from: &str, treaty: Treaty) -> bool {
+        self.get_opinion(to, from) >= treaty.get_required_opinion()
+    }
+
+    /// Offers a treaty, which waits for an answer
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the treaty could be offered
+    pub fn propose(&mut self, from: &str, to: &str, treaty: Treaty, date: u64) -> bool {
+        if !self.can_propose(from, to, treaty) {
+            return false;
+        }
+        self.proposals.push(Proposal {
+            from: from.to_string(),
+            to: to.to_string(),
+            treaty,
+            date,
+        });
+        true
+    }
+
+    /// Returns the proposals waiting for the answer of an empire, oldest first
+    pub fn get_proposals_to(&self, to: &str) -> Vec<&Proposal> {
+        self.proposals.iter().filter(|p| p.to == to).collect()
+    }
+
+    /// Signs a treaty offered to an empire
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the treaty was offered and signed
+    pub fn accept(&mut self, from: &str, to: &str, treaty: Treaty) -> bool {
+        let is_offered = self.proposals.iter()
+            .any(|p| p.from == from && p.to == to && p.treaty == treaty);
+        if is_offered {
+            self.sign(from, to, treaty);
+        }
+        is_offered
+    }
+
+    /// Withdraws a proposal without signing it
+    pub fn refuse(&mut self, from: &str, to: &str, treaty: Treaty, date: u64) {
+        self.proposals.retain(|p| p.from != from || p.to != to || p.treaty != treaty);
+        self.refusals.insert((from.to_string(), to.to_string()), date);
+    }
+
+    /// Withdraws the proposals nobody answered for too long, which counts as a refusal
+    pub fn expire_proposals(&mut self, date: u64) {
+        let expired: Vec<Proposal> = self.proposals.iter()
+            .filter(|p| date >= p.date + PROPOSAL_DURATION)
+            .cloned()
+            .collect();
+        expired.into_iter().for_each(|p| self.refuse(&p.from, &p.to, p.treaty, date));
+    }
+
+    /// Tells whether an empire is still waiting for an answer from another empire, or was
+    /// refused by it recently
+    pub fn is_negotiating(&self, from: &str, to: &str, date: u64) -> bool {
+        self.proposals.iter().any(|p| p.from == from && p.to == to)
+            || self.refusals.get(&(from.to_string(), to.to_string()))
+                .is_some_and(|refused| date < refused + NEGOTIATION_COOLDOWN)
+    }
+
+    /// Puts the treaties into effect, then once a month lets the empires reconsider what they
+    /// think of each other
+    ///
+    /// # Arguments
+    /// * `date` - The current date
+    /// * `empires` - Every empire of the game
+    /// * `fleets` - Every fleet of the game, armed fleets at a colony of another empire are
+    ///   trespassing unless the empires are allied
+    /// * `system` - The system the colonies are in
+    pub fn update(
+        &mut self,
+        date: u64,
+        empires: &mut [Empire],
+        fleets: &[Fleet],
+        system: &SolarSystem,
+    ) {
+        self.expire_proposals(date);
+
+        for (first, second) in self.get_signed(Treaty::ResearchSharing) {
+            for (learner, teacher) in [(&first, &second), (&second, &first)] {
+                let partner = match empires.iter().find(|e| e.get_id() == teacher) {
+                    Some(empire) => empire.get_research_manager().get_state().clone(),
+                    None => continue,
+                };
+                if let Some(empire) = empires.iter_mut().find(|e| e.get_id() == learner) {
+                    empire.get_research_manager_mut().learn_from(&partner, SHARED_RESEARCH_POINTS);
+                }
+            }
+        }
+
+        if !date.is_multiple_of(DAYS_IN_MONTH) {
+            return;
+        }
+
+        for (first, second) in self.get_signed(Treaty::TradeAgreement) {
+            for id in [first, second] {
+                let goods = TRADE_GOODS.iter()
+                    .map(|r| ResourceTransaction::new(r.clone(), TRADE_GOODS_AMOUNT))
+                    .collect();
+                let capital = empires.iter_mut().find(|e| *e.get_id() == id).and_then(|e| {
+                    let capital = e.get_capital().clone();
+                    e.get_colony_mut(&capital)
+                });
+                if let Some(capital) = capital {
+                    capital.pay(goods);
+                }
+            }
+        }
+
+        let mut incidents = Vec::<(String, String, Incident)>::new();
+        for first in empires.iter() {
+            for second in empires.iter().filter(|e| e.get_id() != first.get_id()) {
+                let is_close = first.get_colonies().iter().any(|a| {
+                    second.get_colonies().iter().any(|b| {
+                        match (
+                            system.get_body_position(&a.get_name()),
+                            system.get_body_position(&b.get_name()),
+                        ) {
+                            (Some(a), Some(b)) => (a.0 - b.0).hypot(a.1 - b.1) < BORDER_DISTANCE,
+                            _ => false,
+                        }
+                    })
+                });
+                if is_close {
+                    incidents.push((
+                        first.get_id().clone(),
+                        second.get_id().clone(),
+                        Incident::BorderTension,
+                    ));
+                }
+
+                let is_trespassing = !self.are_hostile(first.get_id(), second.get_id())
+                    && !self.has_treaty(first.get_id(), second.get_id(), Treaty::Alliance)
+                    && fleets.iter().any(|f| {
+                        f.get_owner() == second.get_id()
+                            && !f.is_in_transit()
+                            && first.has_colony(f.get_location())
+                            && f.get_ships().iter().any(|s| !s.get_weapons().is_empty())
+                    });
+                if is_trespassing {
+                    incidents.push((
+                        first.get_id().clone(),
+                        second.get_id().clone(),
+                        Incident::Trespassing,
+                    ));
+                }
+            }
+        }
+        incidents.into_iter().for_each(|(offended, culprit, incident)| {
+            self.record_incident(&offended, &culprit, incident)
+        });
+        let ids: Vec<String> = empires.iter().map(|e| e.get_id().clone()).collect();
+        self.drift_opinions(&ids);
+    }
+
+    /// Declares a war and tells the player about it, the allies of the attacked empire join
+    /// the war
+    ///
+    /// # Arguments
+    /// * `by` - Id of the empire declaring the war
+    /// * `on` - Id of the attacked empire
+    /// * `empires` - Every empire of the game
+    /// * `event_log` - Where the player hears about the war
+    /// * `date` - The current date
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the empires were at peace and now are at war
+    pub fn go_to_war(
+        &mut self,
+        by: &str,
+        on: &str,
+        empires: &[Empire],
+        event_log: &mut EventLog,
+        date: u64,
+    ) -> bool {
+        if !empires.iter().any(|e| e.get_id() == on) || self.are_hostile(by, on) {
+            return false
+        }
+
+        let allies = self.declare_war(by, on);
+        if is_noticed(empires, by, on) {
+            let event = DiplomaticEvent::WarDeclared {
+                by: get_empire_name(empires, by),
+                on: get_empire_name(empires, on),
+            };
+            event_log.push(date, &event);
+        }
+        for ally in allies {
+            if is_noticed(empires, &ally, by) {
+                let event = DiplomaticEvent::AllyJoinedWar {
+                    ally: get_empire_name(empires, &ally),
+                    against: get_empire_name(empires, by),
+                };
+                event_log.push(date, &event);
+            }
+        }
+        true
+    }
+
+    /// Offers a treaty to an empire. Computer players answer at once, the offers to the player
+    /// wait until the player answers them or they expire.
+    ///
+    /// # Arguments
+    /// * `from` - Id of the empire offering the treaty
+    /// * `to` - Id of the empire the treaty is offered to
+    /// * `treaty` - The offered treaty
+    /// * `empires` - Every empire of the game
+    /// * `event_log` - Where the player hears about the offer and the answer
+    /// * `date` - The current date
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the treaty could be offered
+    pub fn offer_treaty(
+        &mut self,
+        from: &str,
+        to: &str,
+        treaty: Treaty,
+        empires: &[Empire],
+        event_log: &mut EventLog,
+        date: u64,
+    ) -> bool {
+        if !empires.iter().any(|e| e.get_id() == to) || !self.propose(from, to, treaty, date) {
+            return false
+        }
+
+        let (first, second) = (get_empire_name(empires, from), get_empire_name(empires, to));
+        if to == PLAYER_OWNER {
+            let event = DiplomaticEvent::TreatyProposed { from: first, to: second, treaty };
+            event_log.push(date, &event);
+        } else if self.would_accept(to, from, treaty) {
+            self.accept(from, to, treaty);
+            if is_noticed(empires, from, to) {
+                let event = DiplomaticEvent::TreatySigned { first, second, treaty };
+                event_log.push(date, &event);
+            }
+        } else {
+            self.refuse(from, to, treaty, date);
+            if from == PLAYER_OWNER {
+                let event = DiplomaticEvent::TreatyRefused { by: second, treaty };
+                event_log.push(date, &event);
+            }
+        }
+        true
+    }
+
+    /// Signs a treaty offered to an empire and tells the player about it
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the treaty was offered and signed
+    pub fn accept_offer(
+        &mut self,
+        from: &str,
+        to: &str,
+        treaty: Treaty,
+        empires: &[Empire],
+        event_log: &mut EventLog,
+        date: u64,
+    ) -> bool {
+        if !self.accept(from, to, treaty) {
+            return false
+        }
+        if is_noticed(empires, from, to) {
+            let event = DiplomaticEvent::TreatySigned {
+                first: get_empire_name(empires, from),
+                second: get_empire_name(empires, to),
+                treaty,
+            };
+            event_log.push(date, &event);
+        }
+        true
+    }
+
+    /// Cancels a treaty other than the peace, which only a war can end, and tells the player
+    /// about it
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the treaty was signed and is now cancelled
+    pub fn cancel_treaty(
+        &mut self,
+        by: &str,
+        with: &str,
+        treaty: Treaty,
+        empires: &[Empire],
+        event_log: &mut EventLog,
+        date: u64,
+    ) -> bool {
+        if treaty == Treaty::Peace || !self.has_treaty(by, with, treaty) {
+            return false
+        }
+        self.cancel(by, with, treaty);
+        if is_noticed(empires, by, with) {
+            let event = DiplomaticEvent::TreatyCancelled {
+                by: get_empire_name(empires, by),
+                with: get_empire_name(empires, with),
+                treaty,
+            };
+            event_log.push(date, &event);
+        }
+        true
+    }
+
+    /// Returns the id, name and color of every other empire the player has met
+    pub fn get_known_empires(&self, empires: &[Empire]) -> Vec<(String, String, Color)> {
+        empires.iter()
+            .filter(|e| !e.is_player() && has_player_met(empires, e.get_id()))
+            .map(|e| (e.get_id().clone(), e.get_name(), e.get_menu_color()))
+            .collect()
+    }
+
+    /// Returns what the player knows about an empire and its relations with the player
+    pub fn get_empire_info(&self, id: &str, empires: &[Empire]) -> Vec<Vec<String>> {
+        let empire = match empires.iter().find(|e| e.get_id() == id) {
+            Some(empire) => empire,
+            None => return Vec::new(),
+        };
+        let mut info = empire.get_properties();
+
+        let treaties = self.get_treaties(PLAYER_OWNER, id);
+        info.push(vec![format!(
+            "Relations: {}",
+            if self.are_hostile(PLAYER_OWNER, id) { "At war" } else { "At peace" },
+        )]);
+        info.push(vec![String::from("Treaties:")]);
+        treaties.iter()
+            .filter(|t| **t != Treaty::Peace)
+            .for_each(|t| info.push(vec![format!("  - {}", t.get_name())]));
+
+        let opinion = self.get_opinion(id, PLAYER_OWNER);
+        info.push(vec![format!(
+            "Opinion of you: {opinion} ({})",
+            get_opinion_description(opinion),
+        )]);
+
+        let allies: Vec<String> = self.get_allies(id).iter()
+            .map(|a| get_empire_name(empires, a))
+            .collect();
+        if !allies.is_empty() {
+            info.push(vec![format!("Allies: {}", allies.join(", "))]);
+        }
+        self.get_proposals_to(PLAYER_OWNER).iter()
+            .filter(|p| p.from == id)
+            .for_each(|p| info.push(vec![format!("Offers you: {}", p.treaty.get_name())]));
+        info
+    }
+
+    /// Returns what the player can do about the relations with an empire
+    pub fn get_diplomatic_options(&self, id: &str) -> Vec<DiplomaticOption> {
+        let mut options: Vec<DiplomaticOption> = self.get_proposals_to(PLAYER_OWNER).iter()
+            .filter(|p| p.from == id)
+            .map(|p| DiplomaticOption::Accept(p.treaty))
+            .collect();
+        if !self.are_hostile(PLAYER_OWNER, id) {
+            options.push(DiplomaticOption::DeclareWar);
+        }
+        options.extend(
+            Treaty::get_variants().into_iter()
+                .filter(|t| self.can_propose(PLAYER_OWNER, id, *t))
+                .map(DiplomaticOption::Propose)
+        );
+        options.extend(
+            self.get_treaties(PLAYER_OWNER, id).into_iter()
+                .filter(|t| *t != Treaty::Peace)
+                .map(DiplomaticOption::Cancel)
+        );
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::diplomacy::diplomacy_manager::DiplomacyManager;
+    use crate::game::diplomacy::treaty::Treaty;
+
+    #[test]
+    fn test_war_breaks_treaties_and_drags_in_allies() {
+        let mut diplomacy = DiplomacyManager::default();
+        diplomacy.sign("a", "b", Treaty::Peace);
+        diplomacy.sign("a", "b", Treaty::TradeAgreement);
+        diplomacy.sign("a", "c", Treaty::Peace);
+        diplomacy.sign("b", "c", Treaty::Peace);
+        diplomacy.sign("b", "c", Treaty::Alliance);
+
+        let allies = diplomacy.declare_war("a", "b");
+
+        assert_eq!(allies, vec![String::from("c")]);
+        assert_eq!(diplomacy.get_treaties("a", "b"), vec![]);
+        assert!(diplomacy.are_hostile("c", "a"));
+        assert!(!diplomacy.are_hostile("b", "c"));
+        assert!(diplomacy.get_opinion("b", "a") < 0);
+        assert_eq!(diplomacy.get_opinion("a", "b"), 0);
+    }
+
+    #[test]
+    fn test_proposals_need_the_right_relations() {
+        let mut diplomacy = DiplomacyManager::default();
+        assert!(!diplomacy.propose("a", "b", Treaty::TradeAgreement, 0));
+        assert!(diplomacy.propose("a", "b", Treaty::Peace, 0));
+        assert!(!diplomacy.propose("a", "b", Treaty::Peace, 1));
+
+        assert!(diplomacy.accept("a", "b", Treaty::Peace));
+        assert!(diplomacy.get_proposals_to("b").is_empty());
+        assert!(!diplomacy.are_hostile("a", "b"));
+
+        assert!(diplomacy.propose("b", "a", Treaty::Alliance, 2));
+        assert!(!diplomacy.would_accept("a", "b", Treaty::Alliance));
+        diplomacy.expire_proposals(40);
+        assert!(diplomacy.get_proposals_to("a").is_empty());
+    }
+}
\ No newline at end of file
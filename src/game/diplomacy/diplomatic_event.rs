@@ -0,0 +1,59 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+use crate::game::diplomacy::treaty::Treaty;
+
+/// `DiplomaticEvent` is a change in the relations between empires, every field holds the name
+/// of an empire
+pub enum DiplomaticEvent {
+    WarDeclared { by: String, on: String },
+    /// An ally of the attacked empire joined the war
+    AllyJoinedWar { ally: String, against: String },
+    TreatyProposed { from: String, to: String, treaty: Treaty },
+    TreatySigned { first: String, second: String, treaty: Treaty },
+    TreatyRefused { by: String, treaty: Treaty },
+    TreatyCancelled { by: String, with: String, treaty: Treaty },
+}
+
+impl Displayable for DiplomaticEvent {
+    fn get_name(&self) -> String {
+        match self {
+            DiplomaticEvent::WarDeclared { by, on } => {
+                format!("{by} declared war on {on}!")
+            }
+            DiplomaticEvent::AllyJoinedWar { ally, against } => {
+                format!("{ally} honoured its alliance and went to war with {against}")
+            }
+            DiplomaticEvent::TreatyProposed { from, to, treaty } => {
+                format!("{from} offers {to}: {}", treaty.get_name())
+            }
+            DiplomaticEvent::TreatySigned { first, second, treaty } => {
+                format!("{first} and {second} signed: {}", treaty.get_name())
+            }
+            DiplomaticEvent::TreatyRefused { by, treaty } => {
+                format!("{by} refused the offer: {}", treaty.get_name())
+            }
+            DiplomaticEvent::TreatyCancelled { by, with, treaty } => {
+                format!("{by} cancelled with {with}: {}", treaty.get_name())
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            DiplomaticEvent::WarDeclared { .. } => Color::LightRed,
+            DiplomaticEvent::AllyJoinedWar { .. } => Color::LightRed,
+            DiplomaticEvent::TreatyProposed { .. } => Color::LightYellow,
+            DiplomaticEvent::TreatySigned { .. } => Color::LightGreen,
+            DiplomaticEvent::TreatyRefused { .. } => Color::Gray,
+            DiplomaticEvent::TreatyCancelled { .. } => Color::Yellow,
+        }
+    }
+}
+
+impl GameEvent for DiplomaticEvent {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Diplomacy
+    }
+}
\ No newline at end of file
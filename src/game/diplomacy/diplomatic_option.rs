@@ -0,0 +1,35 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::diplomacy::treaty::Treaty;
+
+/// `DiplomaticOption` is something the player can do about the relations with an empire
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DiplomaticOption {
+    DeclareWar,
+    Propose(Treaty),
+    Cancel(Treaty),
+    /// Signs a treaty the empire offered
+    Accept(Treaty),
+}
+
+impl Displayable for DiplomaticOption {
+    fn get_name(&self) -> String {
+        match self {
+            DiplomaticOption::DeclareWar => String::from("Declare war"),
+            DiplomaticOption::Propose(treaty) => format!("Propose: {}", treaty.get_name()),
+            DiplomaticOption::Cancel(treaty) => format!("Cancel: {}", treaty.get_name()),
+            DiplomaticOption::Accept(treaty) => format!("Accept: {}", treaty.get_name()),
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            DiplomaticOption::DeclareWar => Color::LightRed,
+            DiplomaticOption::Propose(treaty) => treaty.get_menu_color(),
+            DiplomaticOption::Cancel(_) => Color::Yellow,
+            DiplomaticOption::Accept(_) => Color::LightGreen,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+/// `Incident` is something an empire did that the other empire resents
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Incident {
+    /// The empires have colonies close to each other
+    BorderTension,
+    /// Warships of the other empire orbit a colony without an alliance
+    Trespassing,
+    /// The fleets of the empires fought
+    Battle,
+    /// The other empire declared war
+    WarDeclared,
+}
+
+impl Incident {
+    /// Returns how much the opinion of the offended empire drops
+    pub fn get_opinion_penalty(&self) -> i32 {
+        match self {
+            Incident::BorderTension => 3,
+            Incident::Trespassing => 5,
+            Incident::Battle => 10,
+            Incident::WarDeclared => 50,
+        }
+    }
+}
\ No newline at end of file
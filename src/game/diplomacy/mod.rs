@@ -0,0 +1,5 @@
+pub mod treaty;
+pub mod incident;
+pub mod diplomacy_manager;
+pub mod diplomatic_event;
+pub mod diplomatic_option;
\ No newline at end of file
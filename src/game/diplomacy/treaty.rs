@@ -0,0 +1,69 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+
+/// `Treaty` is an agreement between two empires. Two empires without a peace treaty are at war.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Treaty {
+    /// The fleets of the empires do not fight each other
+    Peace,
+    /// Every month the capitals of both empires receive goods from the other one
+    TradeAgreement,
+    /// Researches the partner has already made progress faster
+    ResearchSharing,
+    /// The allies see what the other one's sensors see, and an enemy of one is an enemy of both
+    Alliance,
+}
+
+impl Treaty {
+    pub fn get_variants() -> Vec<Treaty> {
+        vec![Treaty::Peace, Treaty::TradeAgreement, Treaty::ResearchSharing, Treaty::Alliance]
+    }
+
+    /// Returns the lowest opinion of the proposing empire an empire needs to accept the treaty
+    pub fn get_required_opinion(&self) -> i32 {
+        match self {
+            Treaty::Peace => -30,
+            Treaty::TradeAgreement => 0,
+            Treaty::ResearchSharing => 15,
+            Treaty::Alliance => 40,
+        }
+    }
+
+    /// Returns how much the treaty raises the opinion both sides settle at over time
+    pub fn get_opinion_bonus(&self) -> i32 {
+        match self {
+            Treaty::Peace => 5,
+            Treaty::TradeAgreement => 10,
+            Treaty::ResearchSharing => 10,
+            Treaty::Alliance => 15,
+        }
+    }
+}
+
+impl From<Treaty> for String {
+    fn from(value: Treaty) -> Self {
+        match value {
+            Treaty::Peace => String::from("Peace"),
+            Treaty::TradeAgreement => String::from("Trade agreement"),
+            Treaty::ResearchSharing => String::from("Research sharing"),
+            Treaty::Alliance => String::from("Alliance"),
+        }
+    }
+}
+
+impl Displayable for Treaty {
+    fn get_name(&self) -> String {
+        String::from(*self)
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            Treaty::Peace => Color::LightGreen,
+            Treaty::TradeAgreement => Color::LightYellow,
+            Treaty::ResearchSharing => Color::LightCyan,
+            Treaty::Alliance => Color::LightBlue,
+        }
+    }
+}
\ No newline at end of file
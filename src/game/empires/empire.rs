@@ -0,0 +1,436 @@
+use rand::Rng;
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::colony::Colony;
+use crate::game::colony::colony_event::ColonyEvent;
+use crate::game::empires::policy::Policy;
+use crate::game::events::event_effect::EventEffect;
+use crate::game::fleets::fleet::{Fleet, PLAYER_OWNER};
+use crate::game::fleets::ship_design::ShipDesign;
+use crate::game::mods::namelists::Namelists;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::personnel::personnel_event::PersonnelEvent;
+use crate::game::personnel::personnel_manager::{INITIAL_OFFICERS, PersonnelManager};
+use crate::game::research::research_manager::ResearchManager;
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers, TemporaryModifier};
+use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+use crate::game::sensors::knowledge::Knowledge;
+
+/// Amount of colonists who settle a new planet
+pub const COLONISTS: i32 = 500;
+/// Stability every colony of an empire loses for every war the empire is fighting
+const WAR_WEARINESS_PER_WAR: i32 = 10;
+/// Chance of a colony on the brink revolting every day
+const REVOLT_CHANCE: f64 = 1.0 / 60.0;
+
+/// Returns the resources an existing colony spends to settle a new planet
+pub fn get_colonization_cost() -> Vec<ResourceTransaction> {
+    vec![
+        ResourceTransaction::new(ResourceType::PRLightMetals, -150),
+        ResourceTransaction::new(ResourceType::PRHeavyMetals, -100),
+        ResourceTransaction::new(ResourceType::PRWater, -100),
+    ]
+}
+
+/// Returns the resources needed to build a ship, the hull costs metals by its mass and every
+/// module needs electronics
+pub fn get_ship_cost(design: &ShipDesign) -> Vec<ResourceTransaction> {
+    vec![
+        ResourceTransaction::new(ResourceType::PRHeavyMetals, -(*design.mass() as i32 / 4)),
+        ResourceTransaction::new(ResourceType::PRLightMetals, -(*design.mass() as i32 / 8)),
+        ResourceTransaction::new(ResourceType::SRElectronics, -(design.modules().len() as i32)),
+    ]
+}
+
+/// Returns the name of an empire, or its id if there is no such empire
+pub fn get_empire_name(empires: &[Empire], id: &str) -> String {
+    empires.iter()
+        .find(|e| e.get_id() == id)
+        .map(|e| e.get_name())
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// `Empire` is a nation owning colonies and fleets, played either by the player or by the computer
+///
+/// **Fields**
+/// - id (`String`) - identifies the empire, fleets use it as their owner
+/// - name (`String`) - name of the empire shown to the player
+/// - color (`Color`) - color the empire is shown in
+/// - capital (`String`) - name of the planet the empire started on
+/// - colonies (`Vec<Colony>`) - every colony of the empire, the capital first
+/// - research_manager (`ResearchManager`) - technologies and research progress of the empire
+/// - knowledge (`Knowledge`) - everything the empire has learned with its sensors and surveys
+/// - policies (`Vec<Policy>`) - the policies enacted in every colony of the empire
+/// - personnel (`PersonnelManager`) - the officers serving the empire
+/// - temporary_modifiers (`Vec<TemporaryModifier>`) - bonuses and maluses which wear off
+#[derive(Clone)]
+pub struct Empire {
+    id: String,
+    name: String,
+    color: Color,
+    capital: String,
+    colonies: Vec<Colony>,
+    research_manager: ResearchManager,
+    knowledge: Knowledge,
+    policies: Vec<Policy>,
+    personnel: PersonnelManager,
+    temporary_modifiers: Vec<TemporaryModifier>,
+}
+
+impl Empire {
+    /// Founds an empire with a single colony on its capital
+    pub fn new(
+        id: String,
+        name: String,
+        color: Color,
+        capital: Colony,
+        research_manager: ResearchManager,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            color,
+            capital: capital.get_name(),
+            colonies: vec![capital],
+            research_manager,
+            knowledge: Knowledge::default(),
+            policies: Vec::new(),
+            personnel: PersonnelManager::default(),
+            temporary_modifiers: Vec::new(),
+        }
+    }
+
+    pub fn get_id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn is_player(&self) -> bool {
+        self.id == PLAYER_OWNER
+    }
+
+    pub fn get_capital(&self) -> &String {
+        &self.capital
+    }
+
+    pub fn get_colonies(&self) -> &Vec<Colony> {
+        &self.colonies
+    }
+
+    pub fn get_colonies_mut(&mut self) -> &mut Vec<Colony> {
+        &mut self.colonies
+    }
+
+    pub fn get_colony_mut(&mut self, name: &str) -> Option<&mut Colony> {
+        self.colonies.iter_mut().find(|c| c.get_name() == name)
+    }
+
+    pub fn has_colony(&self, name: &str) -> bool {
+        self.colonies.iter().any(|c| c.get_name() == name)
+    }
+
+    pub fn add_colony(&mut self, colony: Colony) {
+        self.colonies.push(colony);
+    }
+
+    /// Removes a colony the empire lost. If it was the capital, the oldest of the remaining
+    /// colonies becomes the capital.
+    pub fn remove_colony(&mut self, name: &str) -> Option<Colony> {
+        let index = self.colonies.iter().position(|c| c.get_name() == name)?;
+        let colony = self.colonies.remove(index);
+        if self.capital == name {
+            if let Some(capital) = self.colonies.first() {
+                self.capital = capital.get_name();
+            }
+        }
+        Some(colony)
+    }
+
+    pub fn get_research_manager(&self) -> &ResearchManager {
+        &self.research_manager
+    }
+
+    pub fn get_research_manager_mut(&mut self) -> &mut ResearchManager {
+        &mut self.research_manager
+    }
+
+    pub fn get_knowledge(&self) -> &Knowledge {
+        &self.knowledge
+    }
+
+    pub fn get_knowledge_mut(&mut self) -> &mut Knowledge {
+        &mut self.knowledge
+    }
+
+    pub fn get_policies(&self) -> &Vec<Policy> {
+        &self.policies
+    }
+
+    /// Returns every policy along with whether the empire has enacted it
+    pub fn get_policy_states(&self) -> Vec<(Policy, bool)> {
+        Policy::get_variants().into_iter().map(|p| (p, self.has_policy(p))).collect()
+    }
+
+    pub fn has_policy(&self, policy: Policy) -> bool {
+        self.policies.contains(&policy)
+    }
+
+    /// Enacts or repeals a policy
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the policy was changed, `false` if it already was as requested
+    pub fn set_policy(&mut self, policy: Policy, is_enacted: bool) -> bool {
+        if self.has_policy(policy) == is_enacted {
+            return false;
+        }
+        if is_enacted {
+            self.policies.push(policy);
+        } else {
+            self.policies.retain(|p| *p != policy);
+        }
+        true
+    }
+
+    /// Builds, mines and produces in every colony, then updates their stability. Colonies on
+    /// the brink may revolt.
+    ///
+    /// # Arguments
+    /// * `is_resource_tick` - Whether the colonies mine and produce resources this tick
+    /// * `wars` - Amount of wars the empire is fighting, which wear its colonies out
+    /// * `rng` - Random number generator of the game
+    ///
+    /// # Returns
+    /// * `Vec<ColonyEvent>` - What happened in the colonies
+    pub fn update_colonies(
+        &mut self,
+        is_resource_tick: bool,
+        wars: usize,
+        rng: &mut impl Rng,
+    ) -> Vec<ColonyEvent> {
+        let modifiers = self.get_modifiers();
+        let war_weariness = WAR_WEARINESS_PER_WAR * wars as i32;
+        let mut events = Vec::<ColonyEvent>::new();
+
+        for c in self.colonies.iter_mut() {
+            let governor = self.personnel.get_assigned(&Assignment::Colony(c.get_name()));
+            events.extend(c.tick());
+            if is_resource_tick {
+                let mut modifiers = modifiers.clone();
+                if let Some(governor) = governor {
+                    governor.apply(&mut modifiers);
+                }
+                c.resource_tick(&modifiers, &self.policies, rng);
+            }
+
+            events.extend(c.update_stability(&self.policies, governor, war_weariness));
+            if c.can_revolt(&self.policies) && rng.gen_bool(REVOLT_CHANCE) {
+                events.push(c.revolt(rng));
+            }
+        }
+        events
+    }
+
+    /// Returns the stability of every colony
+    pub fn get_stability_report(&self) -> Vec<Vec<String>> {
+        self.colonies.iter().map(|c| {
+            let stability = c.get_stability();
+            vec![format!(
+                "{}: {} (settling at {}){}",
+                c.get_name(),
+                stability.get_value(),
+                stability.get_target(),
+                if stability.can_revolt() {
+                    " - on the brink of revolt"
+                } else if stability.is_in_unrest() {
+                    " - in unrest"
+                } else {
+                    ""
+                },
+            )]
+        }).collect()
+    }
+
+    pub fn get_personnel(&self) -> &PersonnelManager {
+        &self.personnel
+    }
+
+    pub fn get_personnel_mut(&mut self) -> &mut PersonnelManager {
+        &mut self.personnel
+    }
+
+    /// Calls back the officers whose posts are gone, then lets the officers serve for a day
+    ///
+    /// # Arguments
+    /// * `date` - The current in-game date
+    /// * `fleets` - Fleets of every empire
+    /// * `namelists` - Names the recruited officers are given
+    /// * `rng` - Random number generator of the game
+    ///
+    /// # Returns
+    /// * `Vec<PersonnelEvent>` - What happened to the officers
+    pub fn update_personnel(
+        &mut self,
+        date: u64,
+        fleets: &[Fleet],
+        namelists: &Namelists,
+        rng: &mut impl Rng,
+    ) -> Vec<PersonnelEvent> {
+        let posts = self.get_posts(fleets);
+        let max_officers = INITIAL_OFFICERS as usize + self.colonies.len();
+        let mut events = self.personnel.release_invalid(|a| posts.contains(a));
+        events.extend(self.personnel.tick(date, max_officers, namelists, rng));
+        events
+    }
+
+    /// Returns every post an officer of the empire can serve at
+    ///
+    /// # Arguments
+    /// * `fleets` - Fleets of every empire
+    pub fn get_posts(&self, fleets: &[Fleet]) -> Vec<Assignment> {
+        let mut posts: Vec<Assignment> = self.colonies.iter()
+            .map(|c| Assignment::Colony(c.get_name()))
+            .collect();
+        posts.extend(
+            self.research_manager.get_researches_in_progress().into_iter()
+                .map(Assignment::Research)
+        );
+        posts.extend(
+            fleets.iter()
+                .filter(|f| *f.get_owner() == self.id)
+                .map(|f| Assignment::Fleet(f.get_id()))
+        );
+        posts
+    }
+
+    /// Returns the id, name and color of every officer
+    pub fn get_officers(&self) -> Vec<(u32, String, Color)> {
+        self.personnel.get_officers().iter()
+            .map(|o| (o.get_id(), o.get_name(), o.get_menu_color()))
+            .collect()
+    }
+
+    /// Returns the properties of an officer along with their post
+    ///
+    /// # Arguments
+    /// * `id` - Id of the officer
+    /// * `fleets` - Fleets of every empire
+    pub fn get_officer_info(&self, id: u32, fleets: &[Fleet]) -> Vec<Vec<String>> {
+        let officer = match self.personnel.get_officer(id) {
+            Some(officer) => officer,
+            None => return vec![vec![String::from("The officer has left the service")]],
+        };
+        let mut info = officer.get_properties();
+        info.push(vec![match officer.get_assignment() {
+            Some(assignment) => self.describe_post(assignment, fleets),
+            None => String::from("Waiting for an assignment"),
+        }]);
+        info
+    }
+
+    /// Returns the posts an officer can be sent to, along with their descriptions. `None` calls
+    /// the officer back.
+    ///
+    /// # Arguments
+    /// * `id` - Id of the officer
+    /// * `fleets` - Fleets of every empire
+    pub fn get_assignment_options(
+        &self,
+        id: u32,
+        fleets: &[Fleet],
+    ) -> Vec<(Option<Assignment>, String)> {
+        let current = self.personnel.get_officer(id).and_then(|o| o.get_assignment());
+        let mut options: Vec<(Option<Assignment>, String)> = self.get_posts(fleets).into_iter()
+            .filter(|p| Some(p) != current)
+            .map(|p| {
+                let holder = self.personnel.get_assigned(&p)
+                    .map(|o| format!(" (replacing {})", o.get_name()))
+                    .unwrap_or_default();
+                let description = format!("{}{holder}", self.describe_post(&p, fleets));
+                (Some(p), description)
+            })
+            .collect();
+        if current.is_some() {
+            options.insert(0, (None, String::from("Call back")));
+        }
+        options
+    }
+
+    /// Describes a post of an officer
+    fn describe_post(&self, assignment: &Assignment, fleets: &[Fleet]) -> String {
+        match assignment {
+            Assignment::Colony(colony) => format!("Governor of {colony}"),
+            Assignment::Research(research) => format!(
+                "Leading the research of {}",
+                self.research_manager.get_all_researches().iter()
+                    .find(|r| r.id() == research)
+                    .map(|r| r.get_name())
+                    .unwrap_or(research.clone()),
+            ),
+            Assignment::Fleet(fleet) => format!(
+                "Commanding {}",
+                fleets.iter()
+                    .find(|f| f.get_id() == *fleet)
+                    .map(|f| f.get_name())
+                    .unwrap_or_default(),
+            ),
+        }
+    }
+
+    /// Returns the bonuses of the researched technologies along with the temporary ones
+    pub fn get_modifiers(&self) -> TechModifiers {
+        let mut modifiers = self.research_manager.get_modifiers();
+        self.temporary_modifiers.iter().for_each(|m| modifiers.add(m.modifier, m.percent));
+        modifiers
+    }
+
+    /// Applies an effect to a colony the same way as an event option does
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the colony exists and could pay for the effect
+    pub fn apply_effect(&mut self, colony: &str, effect: &EventEffect) -> bool {
+        if !self.has_colony(colony) {
+            return false;
+        }
+        match effect {
+            EventEffect::Resource { .. } => self.get_colony_mut(colony)
+                .is_some_and(|c| c.pay(effect.get_transaction().into_iter().collect())),
+            EventEffect::Modifier { modifier, percent, days } => {
+                self.add_temporary_modifier(*modifier, *percent, *days);
+                true
+            }
+        }
+    }
+
+    pub fn add_temporary_modifier(&mut self, modifier: ModifierType, percent: i32, days: u32) {
+        self.temporary_modifiers.push(TemporaryModifier { modifier, percent, days_left: days });
+    }
+
+    /// Counts down the days of the temporary modifiers, removing the ones which wore off
+    pub fn update_temporary_modifiers(&mut self) {
+        self.temporary_modifiers.iter_mut()
+            .for_each(|m| m.days_left = m.days_left.saturating_sub(1));
+        self.temporary_modifiers.retain(|m| m.days_left > 0);
+    }
+}
+
+impl Displayable for Empire {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![format!("Name: {}", self.name)],
+            vec![format!("Capital: {}", self.capital)],
+            vec![format!("Colonies: {}", self.colonies.len())],
+            vec![format!(
+                "Population: {}",
+                self.colonies.iter().map(|c| c.get_population()).sum::<i32>(),
+            )],
+        ]
+    }
+
+    fn get_menu_color(&self) -> Color {
+        self.color
+    }
+}
\ No newline at end of file
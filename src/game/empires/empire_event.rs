@@ -0,0 +1,32 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// `EmpireEvent` is something noteworthy an empire did
+pub enum EmpireEvent {
+    /// An empire settled a new planet
+    ColonyFounded { empire: String, planet: String },
+}
+
+impl Displayable for EmpireEvent {
+    fn get_name(&self) -> String {
+        match self {
+            EmpireEvent::ColonyFounded { empire, planet } => {
+                format!("{empire} founded a colony on {planet}")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            EmpireEvent::ColonyFounded { .. } => Color::LightBlue,
+        }
+    }
+}
+
+impl GameEvent for EmpireEvent {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Colony
+    }
+}
\ No newline at end of file
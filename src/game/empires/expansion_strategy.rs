@@ -0,0 +1,279 @@
+use std::cmp::Reverse;
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::colony::stability::{REVOLT_STABILITY, UNREST_STABILITY};
+use crate::game::diplomacy::treaty::Treaty;
+use crate::game::empires::empire::{get_colonization_cost, get_ship_cost};
+use crate::game::empires::policy::Policy;
+use crate::game::empires::strategy::{EmpireView, Order, Strategy};
+use crate::game::exploration::body_survey::SurveyKind;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::resource::resource::ResourceTransaction;
+
+/// Amount of days between two rounds of decisions
+const DECISION_INTERVAL: u64 = 5;
+/// Chance to build a mine rather than any other building
+const MINE_CHANCE: f64 = 0.4;
+/// Amount of colonies the empire stops expanding at
+const MAX_COLONIES: usize = 4;
+/// Amount of warships the empire keeps for every colony
+const WARSHIPS_PER_COLONY: usize = 2;
+/// Amount of ships a fleet needs to go on the offensive
+const ATTACK_FLEET_SIZE: usize = 4;
+/// Design of the warships the empire builds
+const WARSHIP_DESIGN: &str = "corvette";
+/// Design of the ships carrying the troops of the empire
+const TRANSPORT_DESIGN: &str = "transport";
+/// Amount of ground units the empire sends to invade a colony
+const INVASION_TROOPS: u32 = 6;
+/// Opinion of a rival the empire declares war at, even without an army ready
+const WAR_OPINION: i32 = -60;
+/// Opinion of an enemy the empire offers peace at
+const PEACE_OPINION: i32 = -20;
+/// Stability of a colony the empire enacts martial law at, before the colony may revolt
+const MARTIAL_LAW_STABILITY: i32 = REVOLT_STABILITY + 10;
+
+/// `ExpansionStrategy` is the default computer player. It keeps researching and building,
+/// settles the closest free planets, befriends the rivals it likes, and goes to war with the
+/// ones it dislikes once it has a fleet to send against their colonies.
+#[derive(Clone, Debug, Default)]
+pub struct ExpansionStrategy;
+
+impl Strategy for ExpansionStrategy {
+    fn decide(&mut self, view: &EmpireView, rng: &mut StdRng) -> Vec<Order> {
+        if !view.date.is_multiple_of(DECISION_INTERVAL) {
+            return Vec::new();
+        }
+        let mut orders = Vec::<Order>::new();
+        let empire = view.empire;
+        let capital = empire.get_capital().clone();
+
+        if !empire.get_research_manager().is_researching() {
+            if let Some(research) = view.researches.choose(rng) {
+                orders.push(Order::StartResearch(research.clone()));
+            }
+        }
+
+        for colony in empire.get_colonies().iter().filter(|c| c.get_construction().is_empty()) {
+            let building = if rng.gen_bool(MINE_CHANCE) {
+                BuildingType::Mine
+            } else {
+                BuildingType::get_variants().choose(rng).unwrap().0.clone()
+            };
+            orders.push(Order::StartConstruction { colony: colony.get_name(), building });
+        }
+
+        let martial_law = Policy::MartialLaw;
+        if empire.has_policy(martial_law) {
+            let is_calm = empire.get_colonies().iter().all(|c| {
+                c.get_stability().get_target() - martial_law.get_stability_bonus()
+                    >= UNREST_STABILITY
+            });
+            if is_calm {
+                orders.push(Order::SetPolicy { policy: martial_law, is_enacted: false });
+            }
+        } else if empire.get_colonies().iter()
+            .any(|c| c.get_stability().get_value() < MARTIAL_LAW_STABILITY) {
+            orders.push(Order::SetPolicy { policy: martial_law, is_enacted: true });
+        }
+
+        let capital_colony = empire.get_colonies().iter().find(|c| c.get_name() == capital);
+        let can_afford = |cost: &[ResourceTransaction]| capital_colony.is_some_and(|c| c.can_afford(cost));
+
+        if empire.get_colonies().len() < MAX_COLONIES && can_afford(&get_colonization_cost()) {
+            if let Some(planet) = view.free_planets.first() {
+                orders.push(Order::Colonize { from: capital.clone(), planet: planet.clone() });
+            }
+        }
+
+        let warships: Vec<_> = view.fleets.iter()
+            .filter(|f| f.get_survey_points(SurveyKind::Gravitational) == 0)
+            .filter(|f| f.get_survey_points(SurveyKind::Geological) == 0)
+            .filter(|f| f.get_troop_capacity() == 0)
+            .collect();
+        let transports: Vec<_> = view.fleets.iter()
+            .filter(|f| f.get_troop_capacity() > 0)
+            .collect();
+        let ships: usize = warships.iter().map(|f| f.get_ships().len()).sum();
+        let design = view.designs.iter().find(|d| d.id() == WARSHIP_DESIGN);
+        if let Some(design) = design {
+            if ships < empire.get_colonies().len() * WARSHIPS_PER_COLONY
+                && can_afford(&get_ship_cost(design)) {
+                orders.push(Order::BuildShip { colony: capital.clone(), design: design.id().clone() });
+            }
+        }
+
+        let is_army_ready = warships.iter().any(|f| {
+            !f.is_in_transit()
+                && empire.has_colony(f.get_location())
+                && f.get_ships().len() >= ATTACK_FLEET_SIZE
+        });
+        let id = empire.get_id();
+        for rival in view.rivals.iter() {
+            if view.diplomacy.is_negotiating(id, rival, view.date) {
+                continue;
+            }
+            let opinion = view.diplomacy.get_opinion(id, rival);
+            let treaties = view.diplomacy.get_treaties(id, rival);
+            if treaties.is_empty() {
+                if opinion >= PEACE_OPINION {
+                    orders.push(Order::ProposeTreaty {
+                        empire: rival.clone(),
+                        treaty: Treaty::Peace,
+                    });
+                }
+            } else if opinion <= WAR_OPINION
+                || (opinion < 0 && is_army_ready && treaties == vec![Treaty::Peace]) {
+                orders.push(Order::DeclareWar(rival.clone()));
+            } else {
+                let treaty = Treaty::get_variants().into_iter().find(|t| {
+                    view.diplomacy.can_propose(id, rival, *t) && opinion >= t.get_required_opinion()
+                });
+                if let Some(treaty) = treaty {
+                    orders.push(Order::ProposeTreaty { empire: rival.clone(), treaty });
+                }
+            }
+        }
+
+        let enemy_colonies: Vec<&String> = view.foreign_colonies.iter()
+            .filter(|(owner, _)| view.diplomacy.are_hostile(id, owner))
+            .map(|(_, colony)| colony)
+            .collect();
+
+        let troops = capital_colony.map_or(0, |c| {
+            c.get_ground_forces().clone().split_embarking(INVASION_TROOPS).get_total()
+        });
+        let design = view.designs.iter().find(|d| d.id() == TRANSPORT_DESIGN);
+        if let Some(design) = design {
+            if transports.is_empty()
+                && !enemy_colonies.is_empty()
+                && troops >= INVASION_TROOPS
+                && can_afford(&get_ship_cost(design)) {
+                orders.push(Order::BuildShip { colony: capital.clone(), design: design.id().clone() });
+            }
+        }
+
+        let mut attacks = Vec::<(&String, &String)>::new();
+        for fleet in warships.iter().filter(|f| !f.is_in_transit()) {
+            let is_home = empire.has_colony(fleet.get_location());
+            if is_home && fleet.get_ships().len() >= ATTACK_FLEET_SIZE {
+                if let Some(target) = enemy_colonies.choose(rng) {
+                    attacks.push((fleet.get_location(), *target));
+                    orders.push(Order::MoveFleet {
+                        fleet: fleet.get_id(),
+                        destination: (*target).clone(),
+                    });
+                }
+            } else if !is_home && fleet.get_ships().len() < ATTACK_FLEET_SIZE / 2 {
+                orders.push(Order::MoveFleet { fleet: fleet.get_id(), destination: capital.clone() });
+            }
+        }
+
+        let besieged: Vec<&String> = warships.iter()
+            .filter(|f| !f.is_in_transit() && enemy_colonies.contains(&f.get_location()))
+            .map(|f| f.get_location())
+            .collect();
+        for fleet in transports.iter().filter(|f| !f.is_in_transit()) {
+            let is_home = empire.has_colony(fleet.get_location());
+            let is_at_capital = *fleet.get_location() == capital;
+            let is_loaded = fleet.get_troop_count() >= INVASION_TROOPS.min(fleet.get_troop_capacity());
+            if is_at_capital && !is_loaded {
+                orders.push(Order::EmbarkTroops { fleet: fleet.get_id() });
+            } else if is_home && is_loaded {
+                let target = attacks.iter()
+                    .find(|(from, _)| *from == fleet.get_location())
+                    .map(|(_, target)| *target)
+                    .or(besieged.first().copied());
+                if let Some(target) = target {
+                    orders.push(Order::MoveFleet {
+                        fleet: fleet.get_id(),
+                        destination: target.clone(),
+                    });
+                }
+            } else if !is_at_capital && fleet.get_troop_count() == 0 {
+                orders.push(Order::MoveFleet { fleet: fleet.get_id(), destination: capital.clone() });
+            }
+        }
+
+        let personnel = empire.get_personnel();
+        let mut posts: Vec<Assignment> = empire.get_colonies().iter()
+            .map(|c| Assignment::Colony(c.get_name()))
+            .chain(
+                empire.get_research_manager().get_researches_in_progress().into_iter()
+                    .map(Assignment::Research)
+            )
+            .chain(warships.iter().map(|f| Assignment::Fleet(f.get_id())))
+            .filter(|p| personnel.get_assigned(p).is_none())
+            .collect();
+        for officer in personnel.get_officers().iter().filter(|o| o.get_assignment().is_none()) {
+            let best = posts.iter().enumerate()
+                .min_by_key(|(_, p)| {
+                    Reverse(p.get_skills().into_iter().map(|s| officer.get_skill(s)).max())
+                })
+                .map(|(i, _)| i);
+            if let Some(index) = best {
+                orders.push(Order::AssignOfficer {
+                    officer: officer.get_id(),
+                    assignment: Some(posts.remove(index)),
+                });
+            }
+        }
+
+        orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::game::colony::colony::Colony;
+    use crate::game::diplomacy::diplomacy_manager::DiplomacyManager;
+    use crate::game::empires::empire::Empire;
+    use crate::game::empires::expansion_strategy::ExpansionStrategy;
+    use crate::game::empires::strategy::{EmpireView, Strategy};
+    use crate::game::mods::asset_layers::AssetLayers;
+    use crate::game::research::research_manager::ResearchManager;
+    use crate::game::resource::resource::ResourceDeposit;
+
+    #[test]
+    fn test_decisions_depend_only_on_the_seed() {
+        let empire = Empire::new(
+            String::from("empire-1"),
+            String::from("Test Empire"),
+            Color::Yellow,
+            Colony::new(
+                String::from("Earth"),
+                1_000,
+                ResourceDeposit::generate_for_planet(&mut StdRng::seed_from_u64(0)),
+                true,
+            ),
+            ResearchManager::new(&AssetLayers::default()).unwrap(),
+        );
+        let view = EmpireView {
+            date: 0,
+            empire: &empire,
+            fleets: vec![],
+            free_planets: vec![String::from("Mars")],
+            foreign_colonies: vec![],
+            rivals: vec![],
+            diplomacy: &DiplomacyManager::default(),
+            researches: empire.get_research_manager().get_available_researches(),
+            designs: &[],
+        };
+
+        let first = ExpansionStrategy.decide(&view, &mut StdRng::seed_from_u64(42));
+        let second = ExpansionStrategy.decide(&view, &mut StdRng::seed_from_u64(42));
+
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+}
\ No newline at end of file
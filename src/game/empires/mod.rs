@@ -0,0 +1,5 @@
+pub mod empire;
+pub mod strategy;
+pub mod expansion_strategy;
+pub mod empire_event;
+pub mod policy;
\ No newline at end of file
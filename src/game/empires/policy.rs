@@ -0,0 +1,106 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
+
+/// `Policy` is an empire-wide decree, which trades the output of the colonies for their
+/// stability or the other way around. Any number of policies can be enacted at once.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Policy {
+    /// Troops keep the order in the streets, workers are drafted into the militia
+    MartialLaw,
+    /// Colonists get half of their usual water
+    Rationing,
+    /// Factories make goods for the colonists instead of the industry
+    ConsumerGoodsFocus,
+}
+
+impl Policy {
+    pub fn get_variants() -> Vec<Policy> {
+        vec![Policy::MartialLaw, Policy::Rationing, Policy::ConsumerGoodsFocus]
+    }
+
+    /// Returns how much the policy changes the stability the colonies settle at
+    pub fn get_stability_bonus(&self) -> i32 {
+        match self {
+            Policy::MartialLaw => 25,
+            Policy::Rationing => -10,
+            Policy::ConsumerGoodsFocus => 15,
+        }
+    }
+
+    /// Returns how much the policy changes the water the colonists consume, in percent
+    pub fn get_consumption_percent(&self) -> i32 {
+        match self {
+            Policy::Rationing => -50,
+            _ => 0,
+        }
+    }
+
+    /// Returns `true` if the policy prevents the colonies from revolting
+    pub fn prevents_revolts(&self) -> bool {
+        *self == Policy::MartialLaw
+    }
+
+    /// Applies the cost of the policy to the output of mines and factories
+    pub fn apply(&self, modifiers: &mut TechModifiers) {
+        match self {
+            Policy::MartialLaw => {
+                modifiers.add(ModifierType::MiningEfficiency, -20);
+                modifiers.add(ModifierType::FactoryYield, -20);
+            }
+            Policy::Rationing => {}
+            Policy::ConsumerGoodsFocus => {
+                modifiers.add(ModifierType::FactoryYield, -30);
+            }
+        }
+    }
+
+    pub fn get_description(&self) -> Vec<String> {
+        match self {
+            Policy::MartialLaw => vec![
+                String::from("Stability: +25"),
+                String::from("Mining efficiency: -20%"),
+                String::from("Factory yield: -20%"),
+                String::from("Colonies never revolt"),
+            ],
+            Policy::Rationing => vec![
+                String::from("Stability: -10"),
+                String::from("Water consumption: -50%"),
+            ],
+            Policy::ConsumerGoodsFocus => vec![
+                String::from("Stability: +15"),
+                String::from("Factory yield: -30%"),
+            ],
+        }
+    }
+}
+
+impl From<Policy> for String {
+    fn from(value: Policy) -> Self {
+        match value {
+            Policy::MartialLaw => String::from("Martial law"),
+            Policy::Rationing => String::from("Rationing"),
+            Policy::ConsumerGoodsFocus => String::from("Consumer goods focus"),
+        }
+    }
+}
+
+impl Displayable for Policy {
+    fn get_name(&self) -> String {
+        String::from(*self)
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![self.get_description()]
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            Policy::MartialLaw => Color::LightRed,
+            Policy::Rationing => Color::LightYellow,
+            Policy::ConsumerGoodsFocus => Color::LightGreen,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+use rand::rngs::StdRng;
+
+use crate::game::colony::building::BuildingType;
+use crate::game::diplomacy::diplomacy_manager::DiplomacyManager;
+use crate::game::diplomacy::treaty::Treaty;
+use crate::game::empires::empire::Empire;
+use crate::game::empires::policy::Policy;
+use crate::game::events::event_effect::EventEffect;
+use crate::game::fleets::fleet::Fleet;
+use crate::game::fleets::ship_design::ShipDesign;
+use crate::game::personnel::assignment::Assignment;
+
+/// `Order` is a single decision of an empire, which the game carries out if it is possible
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Order {
+    StartResearch(String),
+    StartConstruction { colony: String, building: BuildingType },
+    /// Builds a ship of a design at a colony, paid with the colony's resources
+    BuildShip { colony: String, design: String },
+    /// Settles a planet, paid with the resources of an existing colony
+    Colonize { from: String, planet: String },
+    MoveFleet { fleet: u32, destination: String },
+    /// Loads the troops of the colony a fleet is orbiting into its troop bays
+    EmbarkTroops { fleet: u32 },
+    DeclareWar(String),
+    /// Offers a treaty to another empire, which decides whether to sign it
+    ProposeTreaty { empire: String, treaty: Treaty },
+    /// Signs a treaty another empire offered
+    AcceptTreaty { empire: String, treaty: Treaty },
+    CancelTreaty { empire: String, treaty: Treaty },
+    /// Enacts or repeals a policy in every colony of the empire
+    SetPolicy { policy: Policy, is_enacted: bool },
+    /// Sends an officer to a post, or calls the officer back if the post is `None`
+    AssignOfficer { officer: u32, assignment: Option<Assignment> },
+    /// Applies an effect to a colony of the empire the same way as an event option does
+    ApplyEffect { colony: String, effect: EventEffect },
+    /// Chooses an option of the oldest event waiting for an answer. Only the player gets events.
+    AnswerEvent { choice: usize },
+}
+
+/// `EmpireView` is everything an empire can see when making its decisions. Every list is
+/// ordered the same way for the same game, so that the decisions depend only on the view and
+/// the random number generator.
+pub struct EmpireView<'a> {
+    pub date: u64,
+    pub empire: &'a Empire,
+    /// Fleets of the empire
+    pub fleets: Vec<&'a Fleet>,
+    /// Known planets nobody has settled yet, the closest to the capital first
+    pub free_planets: Vec<String>,
+    /// Known colonies of the other empires, along with the ids of their owners
+    pub foreign_colonies: Vec<(String, String)>,
+    /// Ids of the other empires the empire has met
+    pub rivals: Vec<String>,
+    pub diplomacy: &'a DiplomacyManager,
+    /// Researches which can be started right now
+    pub researches: Vec<String>,
+    pub designs: &'a [ShipDesign],
+}
+
+/// `Strategy` decides what a computer-controlled empire does. Strategies get their own random
+/// number generator, seeded from the seed of the game, so a game replayed with the same seed
+/// makes the same decisions.
+pub trait Strategy {
+    /// Decides what the empire does this day
+    ///
+    /// # Arguments
+    /// * `view` - Everything the empire can see
+    /// * `rng` - The random number generator of the empire
+    ///
+    /// # Returns
+    /// * `Vec<Order>` - The orders to carry out, in order
+    fn decide(&mut self, view: &EmpireView, rng: &mut StdRng) -> Vec<Order>;
+}
+
+/// `ComputerPlayer` plays an empire with a strategy
+///
+/// **Fields**
+/// - empire (`String`) - id of the played empire
+/// - strategy (`Box<dyn Strategy>`) - decides what the empire does
+/// - rng (`StdRng`) - the random number generator of the empire
+pub struct ComputerPlayer {
+    pub empire: String,
+    pub strategy: Box<dyn Strategy>,
+    pub rng: StdRng,
+}
\ No newline at end of file
@@ -0,0 +1,219 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+
+/// Amount of in-game ticks in one in-game year, each tick is one day
+pub const TICKS_PER_YEAR: u64 = 365;
+
+/// Formats an in-game date as a human readable text
+///
+/// # Arguments
+/// * `date` - Amount of ticks passed since the start of the game
+///
+/// # Returns
+/// * `String` - The date in the form of `Y1 D1`
+pub fn format_date(date: u64) -> String {
+    format!("Y{} D{}", date / TICKS_PER_YEAR + 1, date % TICKS_PER_YEAR + 1)
+}
+
+/// `EventCategory` is the kind of a logged event. The log can be filtered by it, and the game can
+/// be paused whenever an event of a chosen category happens
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum EventCategory {
+    /// Breakthroughs, setbacks and eureka moments of the researches in progress
+    Research,
+    /// Researched technologies and technology levels
+    Technology,
+    /// Finished buildings
+    Construction,
+    /// Founded colonies and the mood of their people
+    Colony,
+    /// Repaired fleets
+    Fleet,
+    /// Completed surveys
+    Exploration,
+    /// Battles, pirate raids and invasions
+    Combat,
+    Diplomacy,
+    Personnel,
+    /// Choices made in the events defined in the assets
+    Decision,
+    /// Messages and failures of the player's scripts
+    Script,
+}
+
+impl EventCategory {
+    pub fn get_variants() -> Vec<EventCategory> {
+        vec![
+            EventCategory::Research,
+            EventCategory::Technology,
+            EventCategory::Construction,
+            EventCategory::Colony,
+            EventCategory::Fleet,
+            EventCategory::Exploration,
+            EventCategory::Combat,
+            EventCategory::Diplomacy,
+            EventCategory::Personnel,
+            EventCategory::Decision,
+            EventCategory::Script,
+        ]
+    }
+}
+
+impl Displayable for EventCategory {
+    fn get_name(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            EventCategory::Research => Color::LightYellow,
+            EventCategory::Technology => Color::LightCyan,
+            EventCategory::Construction => Color::LightGreen,
+            EventCategory::Colony => Color::LightBlue,
+            EventCategory::Fleet => Color::Gray,
+            EventCategory::Exploration => Color::Magenta,
+            EventCategory::Combat => Color::LightRed,
+            EventCategory::Diplomacy => Color::Yellow,
+            EventCategory::Personnel => Color::Cyan,
+            EventCategory::Decision => Color::LightMagenta,
+            EventCategory::Script => Color::White,
+        }
+    }
+}
+
+/// `GameEvent` is something noteworthy that happened during the game and goes to the event log
+pub trait GameEvent: Displayable {
+    fn get_category(&self) -> EventCategory;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    date: u64,
+    category: EventCategory,
+    text: String,
+    color: Color,
+}
+
+impl LogEntry {
+    pub fn get_date(&self) -> u64 {
+        self.date
+    }
+
+    pub fn get_category(&self) -> EventCategory {
+        self.category
+    }
+
+    /// Returns the text of the entry, without the date
+    pub fn get_text(&self) -> &String {
+        &self.text
+    }
+}
+
+impl Displayable for LogEntry {
+    fn get_name(&self) -> String {
+        format!("[{}] {}", format_date(self.date), self.text)
+    }
+
+    fn get_menu_color(&self) -> Color {
+        self.color
+    }
+}
+
+/// `EventLog` keeps every noteworthy thing that happened during the game, in chronological order
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventLog {
+    entries: Vec<LogEntry>,
+}
+
+impl EventLog {
+    pub fn push(&mut self, date: u64, event: &impl GameEvent) {
+        self.entries.push(LogEntry {
+            date,
+            category: event.get_category(),
+            text: event.get_name(),
+            color: event.get_menu_color(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries pushed after the first `start` ones, oldest first
+    pub fn get_since(&self, start: usize) -> Vec<LogEntry> {
+        self.entries.iter().skip(start).cloned().collect()
+    }
+
+    /// Returns up to `amount` of the latest entries, newest first
+    pub fn get_latest(&self, amount: usize) -> Vec<(String, Color)> {
+        self.get_filtered(amount, &EventCategory::get_variants())
+    }
+
+    /// Returns up to `amount` of the latest entries of the given categories, newest first
+    pub fn get_filtered(
+        &self,
+        amount: usize,
+        categories: &[EventCategory],
+    ) -> Vec<(String, Color)> {
+        self.entries.iter().rev()
+            .filter(|e| categories.contains(&e.category))
+            .take(amount)
+            .map(|e| (e.get_name(), e.get_menu_color()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::style::Color;
+
+    use crate::game::celestial_bodies::Displayable;
+    use crate::game::event_log::{EventCategory, EventLog, GameEvent};
+
+    struct TestEvent(&'static str, EventCategory);
+
+    impl Displayable for TestEvent {
+        fn get_name(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn get_menu_color(&self) -> Color {
+            Color::White
+        }
+    }
+
+    impl GameEvent for TestEvent {
+        fn get_category(&self) -> EventCategory {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_log_is_filtered_by_category() {
+        let mut log = EventLog::default();
+        log.push(0, &TestEvent("Lab finished", EventCategory::Construction));
+        log.push(1, &TestEvent("Pirates!", EventCategory::Combat));
+        log.push(400, &TestEvent("Mine finished", EventCategory::Construction));
+
+        let names = |entries: Vec<(String, Color)>| -> Vec<String> {
+            entries.into_iter().map(|(name, _)| name).collect()
+        };
+        assert_eq!(
+            names(log.get_filtered(10, &[EventCategory::Construction])),
+            vec!["[Y2 D36] Mine finished", "[Y1 D1] Lab finished"],
+        );
+        assert_eq!(names(log.get_latest(1)), vec!["[Y2 D36] Mine finished"]);
+
+        let new = log.get_since(1);
+        assert_eq!(new.len(), 2);
+        assert_eq!(new[0].get_category(), EventCategory::Combat);
+        assert_eq!(new[0].get_date(), 1);
+    }
+}
\ No newline at end of file
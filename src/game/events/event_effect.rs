@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::research::tech_modifiers::ModifierType;
+use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+
+/// `EventEffect` is a consequence of the choice the player made in an event
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EventEffect {
+    /// Adds resources to the colony of the event or spends them, the capital if the event did
+    /// not happen to a colony
+    Resource { resource: ResourceType, amount: i32 },
+    /// Changes a statistic of the whole empire for some days
+    Modifier { modifier: ModifierType, percent: i32, days: u32 },
+}
+
+impl EventEffect {
+    /// Returns the transaction of a resource effect
+    pub fn get_transaction(&self) -> Option<ResourceTransaction> {
+        match self {
+            EventEffect::Resource { resource, amount } => {
+                Some(ResourceTransaction::new(resource.clone(), *amount))
+            }
+            EventEffect::Modifier { .. } => None,
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            EventEffect::Resource { resource, amount } => {
+                format!("{amount:+} {}", resource.get_name())
+            }
+            EventEffect::Modifier { modifier, percent, days } => {
+                format!("{}: {percent:+}% for {days} days", String::from(*modifier))
+            }
+        }
+    }
+}
\ No newline at end of file
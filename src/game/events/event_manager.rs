@@ -0,0 +1,261 @@
+use std::collections::{HashSet, VecDeque};
+
+use color_eyre::eyre::Result;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::empires::empire::Empire;
+use crate::game::events::event_effect::EventEffect;
+use crate::game::events::event_trigger::EventTrigger;
+use crate::game::events::random_event::{EventOutcome, EventPrompt, RandomEvent};
+use crate::game::exploration::body_survey::SurveyKind;
+use crate::game::mods::asset_layers::AssetLayers;
+use crate::game::resource::resource::ResourceTransaction;
+
+const EVENTS_PATH: &str = "events.json5";
+
+/// `EventManager` decides which events happen to the player and keeps the ones waiting for the
+/// player to choose an option
+///
+/// **Fields**
+/// - events (`Vec<RandomEvent>`) - every event defined in the assets
+/// - happened (`HashSet<String>`) - ids of the events which already happened
+/// - pending (`VecDeque<(usize, String)>`) - index of every event waiting for an answer and the
+///   name of the colony or the body it happened at, the oldest first
+#[derive(Clone, Debug, Default)]
+pub struct EventManager {
+    events: Vec<RandomEvent>,
+    happened: HashSet<String>,
+    pending: VecDeque<(usize, String)>,
+}
+
+impl EventManager {
+    pub fn new(assets: &AssetLayers) -> Result<Self> {
+        Ok(Self::from_events(RandomEvent::load_from_assets(assets, EVENTS_PATH)?))
+    }
+
+    pub fn from_events(events: Vec<RandomEvent>) -> Self {
+        Self { events, ..Self::default() }
+    }
+
+    fn can_happen(&self, index: usize) -> bool {
+        let event = &self.events[index];
+        (*event.is_repeatable() || !self.happened.contains(event.id()))
+            && !self.pending.iter().any(|(i, _)| *i == index)
+    }
+
+    fn start(&mut self, index: usize, target: String) {
+        self.happened.insert(self.events[index].id().clone());
+        self.pending.push_back((index, target));
+    }
+
+    /// Rolls the events triggered by the date and the state of the empire. Nothing happens while
+    /// an event is waiting for an answer, and at most one event happens a day.
+    ///
+    /// # Arguments
+    /// * `date` - The current in-game date
+    /// * `empire` - The empire of the player
+    /// * `rng` - The random number generator of the game
+    pub fn roll(&mut self, date: u64, empire: &Empire, rng: &mut impl Rng) {
+        if !self.pending.is_empty() {
+            return;
+        }
+
+        for index in 0..self.events.len() {
+            let event = &self.events[index];
+            if !self.can_happen(index) {
+                continue;
+            }
+            let targets: Vec<String> = match event.trigger() {
+                EventTrigger::Date { .. } if event.trigger().is_due(date) => {
+                    vec![empire.get_capital().clone()]
+                }
+                EventTrigger::Colony { .. } => empire.get_colonies().iter()
+                    .filter(|c| event.trigger().matches_colony(c))
+                    .map(|c| c.get_name())
+                    .collect(),
+                EventTrigger::ResearchFinished { research }
+                if empire.get_research_manager().is_researched(research) => {
+                    vec![empire.get_capital().clone()]
+                }
+                _ => continue,
+            };
+            if targets.is_empty() || !rng.gen_bool(*event.chance()) {
+                continue;
+            }
+            let target = targets.choose(rng).unwrap().clone();
+            self.start(index, target);
+            return;
+        }
+    }
+
+    /// Rolls the events triggered by a survey the player completed
+    ///
+    /// # Arguments
+    /// * `body` - Name of the surveyed body
+    /// * `kind` - The completed survey
+    /// * `rng` - The random number generator of the game
+    pub fn roll_survey(&mut self, body: &str, kind: SurveyKind, rng: &mut impl Rng) {
+        let index = (0..self.events.len()).find(|i| {
+            self.can_happen(*i)
+                && self.events[*i].trigger().matches_survey(kind)
+                && rng.gen_bool(*self.events[*i].chance())
+        });
+        if let Some(index) = index {
+            self.start(index, body.to_string());
+        }
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Returns the colony which pays for the event and receives its resources, that is the
+    /// colony it happened at or the capital
+    fn get_paying_colony(empire: &Empire, target: &str) -> String {
+        if empire.has_colony(target) {
+            target.to_string()
+        } else {
+            empire.get_capital().clone()
+        }
+    }
+
+    fn get_cost(&self, index: usize, choice: usize) -> Vec<ResourceTransaction> {
+        self.events[index].options()[choice].effects().iter()
+            .filter_map(|e| e.get_transaction())
+            .collect()
+    }
+
+    /// Returns the oldest event waiting for an answer
+    pub fn get_prompt(&self, empire: &Empire) -> Option<EventPrompt> {
+        let (index, target) = self.pending.front()?;
+        let event = &self.events[*index];
+        let payer = Self::get_paying_colony(empire, target);
+        let colony = empire.get_colonies().iter().find(|c| c.get_name() == payer);
+
+        Some(EventPrompt {
+            title: event.title().clone(),
+            text: event.get_text(target),
+            options: event.options().iter().enumerate().map(|(choice, option)| {
+                let cost = self.get_cost(*index, choice);
+                (option.get_description(), colony.is_some_and(|c| c.can_afford(&cost)))
+            }).collect(),
+        })
+    }
+
+    /// Answers the oldest event waiting for an answer and applies the effects of the choice
+    ///
+    /// # Arguments
+    /// * `choice` - Index of the chosen option
+    /// * `empire` - The empire of the player
+    ///
+    /// # Returns
+    /// * `Option<EventOutcome>` - The outcome, `None` if no event was waiting or the chosen
+    ///   option is not affordable
+    pub fn resolve(&mut self, choice: usize, empire: &mut Empire) -> Option<EventOutcome> {
+        let (index, target) = self.pending.front()?.clone();
+        let option = self.events[index].options().get(choice)?.clone();
+        let payer = Self::get_paying_colony(empire, &target);
+
+        let cost = self.get_cost(index, choice);
+        if !empire.get_colony_mut(&payer).is_some_and(|c| c.pay(cost)) {
+            return None;
+        }
+        for effect in option.effects() {
+            if let EventEffect::Modifier { modifier, percent, days } = effect {
+                empire.add_temporary_modifier(*modifier, *percent, *days);
+            }
+        }
+
+        self.pending.pop_front();
+        Some(EventOutcome {
+            title: self.events[index].title().clone(),
+            choice: option.get_description(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use ratatui::style::Color;
+
+    use crate::game::colony::colony::Colony;
+    use crate::game::empires::empire::Empire;
+    use crate::game::events::event_manager::{EVENTS_PATH, EventManager};
+    use crate::game::events::random_event::RandomEvent;
+    use crate::game::mods::asset_layers::AssetLayers;
+    use crate::game::research::research_manager::ResearchManager;
+    use crate::game::research::tech_modifiers::ModifierType;
+    use crate::game::resource::resource::{ResourceDeposit, ResourceType};
+
+    #[test]
+    fn test_event_assets_are_valid() {
+        assert!(RandomEvent::load_from_assets(&AssetLayers::default(), EVENTS_PATH).is_ok());
+    }
+
+    #[test]
+    fn test_events_happen_once_and_apply_the_chosen_option() {
+        let events: Vec<RandomEvent> = serde_json::from_value(serde_json::json!([{
+            "id": "comet",
+            "title": "Comet",
+            "description": "A comet passes {target}",
+            "trigger": { "type": "Date", "from": 10 },
+            "chance": 1.0,
+            "options": [
+                {
+                    "text": "Mine it",
+                    "effects": [
+                        { "type": "Resource", "resource": "PRWater", "amount": 50 },
+                        {
+                            "type": "Modifier",
+                            "modifier": "MiningEfficiency",
+                            "percent": 10,
+                            "days": 5,
+                        },
+                    ],
+                },
+                {
+                    "text": "Launch probes",
+                    "effects": [{ "type": "Resource", "resource": "PRWater", "amount": -100000 }],
+                },
+            ],
+        }])).unwrap();
+        let mut manager = EventManager::from_events(events);
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut empire = Empire::new(
+            String::from("player"),
+            String::from("Player"),
+            Color::LightGreen,
+            Colony::new(
+                String::from("Earth"),
+                1_000,
+                ResourceDeposit::generate_for_planet(&mut rng),
+                true,
+            ),
+            ResearchManager::new(&AssetLayers::default()).unwrap(),
+        );
+
+        manager.roll(5, &empire, &mut rng);
+        assert!(!manager.has_pending());
+        manager.roll(10, &empire, &mut rng);
+        let prompt = manager.get_prompt(&empire).unwrap();
+        assert_eq!(prompt.text, "A comet passes Earth");
+        let affordable: Vec<bool> = prompt.options.iter().map(|(_, a)| *a).collect();
+        assert_eq!(affordable, vec![true, false]);
+
+        let water = |e: &Empire| e.get_colonies()[0].get_resource_amount(&ResourceType::PRWater);
+        let before = water(&empire);
+        assert!(manager.resolve(1, &mut empire).is_none());
+        assert!(manager.resolve(0, &mut empire).is_some());
+        assert_eq!(water(&empire), before + 50);
+        let mining = empire.get_modifiers().get_multiplier(ModifierType::MiningEfficiency);
+        assert!((mining - 1.1).abs() < 1e-6);
+
+        manager.roll(11, &empire, &mut rng);
+        assert!(!manager.has_pending());
+    }
+}
\ No newline at end of file
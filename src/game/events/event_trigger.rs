@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::colony::building::BuildingType;
+use crate::game::colony::colony::Colony;
+use crate::game::exploration::body_survey::SurveyKind;
+
+/// `EventTrigger` is what makes an event possible. Date, colony and research triggers are
+/// checked every day, a survey trigger every time the player completes a survey.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EventTrigger {
+    /// The event may happen from a date on, until another date if it is given
+    Date {
+        from: u64,
+        #[serde(default)]
+        until: Option<u64>,
+    },
+    /// The event may happen to a colony in a certain state, every condition given must hold
+    Colony {
+        #[serde(default)]
+        min_population: Option<i32>,
+        #[serde(default)]
+        max_stability: Option<i32>,
+        #[serde(default)]
+        building: Option<BuildingType>,
+    },
+    /// The event may happen once the first level of a research is researched
+    ResearchFinished { research: String },
+    /// The event may happen when a survey of a body is completed, of either kind if none is given
+    BodySurveyed {
+        #[serde(default)]
+        survey: Option<SurveyKind>,
+    },
+}
+
+impl EventTrigger {
+    /// Returns `true` if the date trigger holds on a date, other triggers never do
+    pub fn is_due(&self, date: u64) -> bool {
+        match self {
+            EventTrigger::Date { from, until } => {
+                date >= *from && until.is_none_or(|u| date <= u)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the colony trigger holds for a colony, other triggers never do
+    pub fn matches_colony(&self, colony: &Colony) -> bool {
+        match self {
+            EventTrigger::Colony { min_population, max_stability, building } => {
+                min_population.is_none_or(|p| colony.get_population() >= p)
+                    && max_stability.is_none_or(|s| colony.get_stability().get_value() <= s)
+                    && building.as_ref().is_none_or(|b| {
+                        let name: String = b.clone().into();
+                        colony.get_buildings().iter()
+                            .any(|(n, amount, _)| *n == name && *amount > 0)
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the survey trigger holds for a completed survey, other triggers never do
+    pub fn matches_survey(&self, kind: SurveyKind) -> bool {
+        match self {
+            EventTrigger::BodySurveyed { survey } => survey.is_none_or(|s| s == kind),
+            _ => false,
+        }
+    }
+}
\ No newline at end of file
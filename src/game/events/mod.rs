@@ -0,0 +1,4 @@
+pub mod random_event;
+pub mod event_trigger;
+pub mod event_effect;
+pub mod event_manager;
\ No newline at end of file
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use derive_getters::Getters;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+use crate::game::events::event_effect::EventEffect;
+use crate::game::events::event_trigger::EventTrigger;
+use crate::game::mods::asset_layers::AssetLayers;
+
+/// `EventOption` is one of the choices the player has in an event
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct EventOption {
+    text: String,
+    #[serde(default)]
+    effects: Vec<EventEffect>,
+}
+
+impl EventOption {
+    /// Describes the choice along with its effects
+    pub fn get_description(&self) -> String {
+        if self.effects.is_empty() {
+            return self.text.clone();
+        }
+        format!(
+            "{} ({})",
+            self.text,
+            self.effects.iter().map(|e| e.get_description()).collect::<Vec<String>>().join(", "),
+        )
+    }
+}
+
+/// `RandomEvent` is something that may happen to the player, defined in the assets. The
+/// description may mention `{target}`, which is replaced with the name of the colony or the
+/// body the event happened at.
+///
+/// **Fields**
+/// - id (`String`) - identifies the event
+/// - title (`String`) - title of the event popup
+/// - description (`String`) - what happened
+/// - trigger (`EventTrigger`) - what makes the event possible
+/// - chance (`f64`) - chance of the event happening every time the trigger holds
+/// - is_repeatable (`bool`) - `true` if the event may happen more than once
+/// - options (`Vec<EventOption>`) - the choices the player has
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Getters)]
+pub struct RandomEvent {
+    id: String,
+    title: String,
+    description: String,
+    trigger: EventTrigger,
+    chance: f64,
+    #[serde(default)]
+    is_repeatable: bool,
+    options: Vec<EventOption>,
+}
+
+impl RandomEvent {
+    /// Loads the event definitions of the base game and the enabled mods, and checks that every
+    /// event can happen and be answered
+    pub fn load_from_assets(assets: &AssetLayers, filepath: &str) -> Result<Vec<Self>> {
+        let contents = assets.read_records(filepath)?;
+        let events: Vec<Self> = serde_json::from_str(&contents)
+            .wrap_err_with(|| format!("{filepath}: unable to parse file"))?;
+
+        let mut ids = HashSet::<&String>::new();
+        for event in events.iter() {
+            if !ids.insert(&event.id) {
+                return Err(eyre!("{filepath}: event `{}` is defined more than once", event.id));
+            }
+            if !(0.0..=1.0).contains(&event.chance) {
+                return Err(eyre!(
+                    "{filepath}: chance of event `{}` is not between 0 and 1",
+                    event.id,
+                ));
+            }
+            if event.options.is_empty() {
+                return Err(eyre!("{filepath}: event `{}` has no options", event.id));
+            }
+        }
+        Ok(events)
+    }
+
+    /// Returns the description with the target filled in
+    pub fn get_text(&self, target: &str) -> String {
+        self.description.replace("{target}", target)
+    }
+}
+
+/// `EventPrompt` is an event waiting for the player to choose an option
+///
+/// **Fields**
+/// - title (`String`) - title of the event
+/// - text (`String`) - what happened
+/// - options (`Vec<(String, bool)>`) - description of every option and whether the player can
+///   afford it
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EventPrompt {
+    pub title: String,
+    pub text: String,
+    pub options: Vec<(String, bool)>,
+}
+
+/// `EventOutcome` is the choice the player made in an event, kept in the event log
+pub struct EventOutcome {
+    pub title: String,
+    pub choice: String,
+}
+
+impl Displayable for EventOutcome {
+    fn get_name(&self) -> String {
+        format!("{}: {}", self.title, self.choice)
+    }
+
+    fn get_menu_color(&self) -> Color {
+        Color::LightMagenta
+    }
+}
+
+impl GameEvent for EventOutcome {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Decision
+    }
+}
\ No newline at end of file
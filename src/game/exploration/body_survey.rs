@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+
+/// Amount of survey points needed to map the gravity well of a body
+const GRAVITATIONAL_SURVEY_POINTS: u32 = 60;
+/// Amount of survey points needed to sample the crust of a body
+const GEOLOGICAL_SURVEY_POINTS: u32 = 100;
+
+/// `SurveyKind` is what a survey looks for
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SurveyKind {
+    /// Measures the mass and the orbit of a body, and finds the bodies and jump points around it
+    Gravitational,
+    /// Finds out what resources the crust of a body holds
+    Geological,
+}
+
+impl From<SurveyKind> for String {
+    fn from(value: SurveyKind) -> Self {
+        match value {
+            SurveyKind::Gravitational => String::from("Gravitational survey"),
+            SurveyKind::Geological => String::from("Geological survey"),
+        }
+    }
+}
+
+impl SurveyKind {
+    pub fn get_required_points(&self) -> u32 {
+        match self {
+            SurveyKind::Gravitational => GRAVITATIONAL_SURVEY_POINTS,
+            SurveyKind::Geological => GEOLOGICAL_SURVEY_POINTS,
+        }
+    }
+}
+
+/// `BodySurvey` holds the progress of both surveys of a single body
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BodySurvey {
+    gravitational: u32,
+    geological: u32,
+}
+
+impl BodySurvey {
+    /// Creates the survey of a body which is known inside out
+    pub fn complete() -> Self {
+        Self {
+            gravitational: GRAVITATIONAL_SURVEY_POINTS,
+            geological: GEOLOGICAL_SURVEY_POINTS,
+        }
+    }
+
+    fn get_points(&self, kind: SurveyKind) -> u32 {
+        match kind {
+            SurveyKind::Gravitational => self.gravitational,
+            SurveyKind::Geological => self.geological,
+        }
+    }
+
+    pub fn is_complete(&self, kind: SurveyKind) -> bool {
+        self.get_points(kind) >= kind.get_required_points()
+    }
+
+    pub fn get_percent(&self, kind: SurveyKind) -> u32 {
+        self.get_points(kind) * 100 / kind.get_required_points()
+    }
+
+    /// Advances a survey, never past its completion
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the survey was completed by this progress
+    pub fn add_progress(&mut self, kind: SurveyKind, points: u32) -> bool {
+        if self.is_complete(kind) || points == 0 {
+            return false;
+        }
+        let progress = match kind {
+            SurveyKind::Gravitational => &mut self.gravitational,
+            SurveyKind::Geological => &mut self.geological,
+        };
+        *progress = (*progress + points).min(kind.get_required_points());
+        self.is_complete(kind)
+    }
+
+    /// Formats the progress of a survey for the object view
+    pub fn get_progress_text(&self, kind: SurveyKind) -> String {
+        if self.is_complete(kind) {
+            String::from("Complete")
+        } else {
+            format!("{}%", self.get_percent(kind))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::exploration::body_survey::{BodySurvey, SurveyKind};
+
+    #[test]
+    fn test_survey_completes_once() {
+        let mut survey = BodySurvey::default();
+
+        assert!(!survey.add_progress(SurveyKind::Geological, 40));
+        assert_eq!(survey.get_progress_text(SurveyKind::Geological), "40%");
+        assert!(survey.add_progress(SurveyKind::Geological, 80));
+        assert!(!survey.add_progress(SurveyKind::Geological, 80));
+        assert_eq!(survey.get_percent(SurveyKind::Geological), 100);
+        assert!(!survey.is_complete(SurveyKind::Gravitational));
+    }
+}
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod body_survey;
\ No newline at end of file
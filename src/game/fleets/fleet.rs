@@ -0,0 +1,179 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::exploration::body_survey::SurveyKind;
+use crate::game::fleets::ship::Ship;
+use crate::game::ground::ground_forces::GroundForces;
+use crate::game::research::tech_modifiers::TechModifiers;
+
+/// Owner of everything controlled by the player
+pub const PLAYER_OWNER: &str = "player";
+/// Owner of the raiders which attack the player's colonies
+pub const PIRATE_OWNER: &str = "pirates";
+
+/// `Course` is a journey of a fleet to another celestial body
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Course {
+    destination: String,
+    days_left: u32,
+}
+
+/// `Fleet` is a group of ships of one owner, which moves and fights together
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Fleet {
+    id: u32,
+    name: String,
+    owner: String,
+    /// Name of the celestial body the fleet is orbiting
+    location: String,
+    ships: Vec<Ship>,
+    /// Where the fleet is heading, `None` if it stays in orbit
+    #[serde(default)]
+    course: Option<Course>,
+}
+
+impl Fleet {
+    pub fn new(id: u32, name: String, owner: String, location: String, ships: Vec<Ship>) -> Self {
+        Self { id, name, owner, location, ships, course: None }
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn get_owner(&self) -> &String {
+        &self.owner
+    }
+
+    pub fn get_location(&self) -> &String {
+        &self.location
+    }
+
+    /// A fleet in transit has left its location, it can neither fight nor be repaired there
+    pub fn is_in_transit(&self) -> bool {
+        self.course.is_some()
+    }
+
+    /// Sends the fleet to another celestial body
+    ///
+    /// # Arguments
+    /// * `destination` - Name of the celestial body to go to
+    /// * `days` - Amount of days the journey takes
+    pub fn set_course(&mut self, destination: String, days: u32) {
+        self.course = Some(Course { destination, days_left: days.max(1) });
+    }
+
+    /// Moves the fleet along its course by one day
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the fleet has arrived at its destination
+    pub fn advance(&mut self) -> bool {
+        match self.course.as_mut() {
+            Some(course) if course.days_left > 1 => {
+                course.days_left -= 1;
+                false
+            }
+            Some(_) => {
+                self.location = self.course.take().unwrap().destination;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the amount of survey points the fleet gathers every day
+    pub fn get_survey_points(&self, kind: SurveyKind) -> u32 {
+        self.ships.iter().map(|s| s.get_survey_points(kind)).sum()
+    }
+
+    /// Returns the amount of ground units the fleet can carry
+    pub fn get_troop_capacity(&self) -> u32 {
+        self.ships.iter().map(|s| s.get_troop_capacity()).sum()
+    }
+
+    /// Returns the amount of ground units the fleet carries
+    pub fn get_troop_count(&self) -> u32 {
+        self.ships.iter().map(|s| s.get_troops().get_total()).sum()
+    }
+
+    /// Loads troops into the ships of the fleet, as many as fit
+    pub fn embark(&mut self, forces: &mut GroundForces) {
+        self.ships.iter_mut().for_each(|s| s.embark(forces));
+    }
+
+    /// Unloads the troops of every ship
+    pub fn disembark(&mut self) -> GroundForces {
+        let mut forces = GroundForces::default();
+        self.ships.iter_mut().for_each(|s| forces.merge(s.disembark()));
+        forces
+    }
+
+    pub fn get_ships(&self) -> &Vec<Ship> {
+        &self.ships
+    }
+
+    pub fn get_ships_mut(&mut self) -> &mut Vec<Ship> {
+        &mut self.ships
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ships.is_empty()
+    }
+
+    /// Returns the strength of the best sensor in the fleet
+    pub fn get_sensor_strength(&self) -> f32 {
+        self.ships.iter().map(|s| s.get_sensor_strength()).fold(0.0, f32::max)
+    }
+
+    /// Calculates how easy the fleet is to detect, every ship adds its own signature
+    pub fn get_signature(&self, modifiers: &TechModifiers) -> f32 {
+        self.ships.iter().map(|s| s.get_signature(modifiers)).sum()
+    }
+
+    /// Removes the destroyed ships from the fleet
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Names of the removed ships
+    pub fn remove_destroyed(&mut self) -> Vec<String> {
+        let destroyed = self.ships.iter()
+            .filter(|s| s.is_destroyed())
+            .map(|s| s.get_name())
+            .collect();
+        self.ships.retain(|s| !s.is_destroyed());
+        destroyed
+    }
+}
+
+impl Displayable for Fleet {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        let mut properties = vec![
+            vec![format!("Name: {}", self.name)],
+            vec![format!("Owner: {}", self.owner)],
+            vec![match &self.course {
+                Some(course) => format!(
+                    "Location: {} -> {} ({} days)",
+                    self.location,
+                    course.destination,
+                    course.days_left,
+                ),
+                None => format!("Location: {}", self.location),
+            }],
+            vec![format!("Ships: {}", self.ships.len())],
+        ];
+        self.ships.iter().for_each(|s| properties.extend(s.get_properties()));
+        properties
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.owner == PLAYER_OWNER {
+            Color::LightGreen
+        } else {
+            Color::LightRed
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+use crate::game::exploration::body_survey::SurveyKind;
+
+/// `FleetEvent` is something noteworthy that happened to a fleet outside of combat
+pub enum FleetEvent {
+    /// Pirates arrived to raid a colony
+    PirateRaid { location: String, ships: u32 },
+    /// Pirates left, as nobody opposed them anymore
+    PiratesWithdrew { location: String },
+    /// A fleet was fully repaired at a dock
+    Repaired { fleet: String, location: String },
+    /// A survey of a planet was completed
+    SurveyCompleted { location: String, survey: SurveyKind },
+    /// A gravitational survey found jump points leading out of the system
+    JumpPointsFound { location: String, amount: u32 },
+}
+
+impl Displayable for FleetEvent {
+    fn get_name(&self) -> String {
+        match self {
+            FleetEvent::PirateRaid { location, ships } => {
+                format!("Pirates are raiding {location} with {ships} ships!")
+            }
+            FleetEvent::PiratesWithdrew { location } => {
+                format!("Pirates withdrew from {location}")
+            }
+            FleetEvent::Repaired { fleet, location } => {
+                format!("{fleet} was fully repaired at {location}")
+            }
+            FleetEvent::SurveyCompleted { location, survey } => {
+                format!("{} of {location} completed", String::from(*survey))
+            }
+            FleetEvent::JumpPointsFound { location, amount } => {
+                format!("Survey of {location} found {amount} jump points")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            FleetEvent::PirateRaid { .. } => Color::LightRed,
+            FleetEvent::PiratesWithdrew { .. } => Color::LightYellow,
+            FleetEvent::Repaired { .. } => Color::LightGreen,
+            FleetEvent::SurveyCompleted { .. } => Color::LightCyan,
+            FleetEvent::JumpPointsFound { .. } => Color::Magenta,
+        }
+    }
+}
+
+impl GameEvent for FleetEvent {
+    fn get_category(&self) -> EventCategory {
+        match self {
+            FleetEvent::PirateRaid { .. } | FleetEvent::PiratesWithdrew { .. } => {
+                EventCategory::Combat
+            }
+            FleetEvent::Repaired { .. } => EventCategory::Fleet,
+            FleetEvent::SurveyCompleted { .. } | FleetEvent::JumpPointsFound { .. } => {
+                EventCategory::Exploration
+            }
+        }
+    }
+}
\ No newline at end of file
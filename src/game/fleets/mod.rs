@@ -0,0 +1,4 @@
+pub mod fleet;
+pub mod ship;
+pub mod ship_design;
+pub mod fleet_event;
\ No newline at end of file
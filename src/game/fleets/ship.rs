@@ -0,0 +1,521 @@
+use rand::Rng;
+use rand::seq::IteratorRandom;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::exploration::body_survey::SurveyKind;
+use crate::game::fleets::ship_design::ShipDesign;
+use crate::game::ground::ground_forces::GroundForces;
+use crate::game::research::tech_modifiers::TechModifiers;
+use crate::game::shipbuilding::installed_module::{InstalledModule, ModuleKind};
+use crate::game::shipbuilding::point_defense::PointDefense;
+use crate::game::shipbuilding::sensor::SensorMode;
+use crate::game::shipbuilding::weapon::Weapon;
+
+/// How much evasion a ship gets for every unit of thrust per ton of its mass
+const EVASION_FACTOR: f32 = 20.0;
+/// Even the most agile ship can not dodge more than this share of the shots
+const MAX_EVASION: f32 = 0.5;
+/// Amount of crew members needed to restore one hit point every day
+const CREW_PER_REPAIR_POINT: u32 = 5;
+/// Share of the maximum hit points up to which the crew can patch a module without a dock
+const DAMAGE_CONTROL_LIMIT: f32 = 0.75;
+/// Amount of modules damaged by a reactor meltdown, besides the hull
+const MELTDOWN_SPREAD: usize = 2;
+/// Mass of a ship, in tons, which gives it a signature of 1
+const SIGNATURE_MASS: f32 = 100.0;
+/// Signature added by every unit of thrust of the working engines
+const SIGNATURE_PER_THRUST: f32 = 1.0;
+/// How many times a working active sensor enlarges the signature of its ship
+const ACTIVE_SENSOR_EMISSION: f32 = 4.0;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Ship {
+    name: String,
+    /// Name of the design the ship was built from
+    class: String,
+    mass: u32,
+    hull_points: u32,
+    max_hull_points: u32,
+    shield_points: u32,
+    crew: u32,
+    max_crew: u32,
+    modules: Vec<InstalledModule>,
+    /// Ground units carried in the troop bays
+    #[serde(default)]
+    troops: GroundForces,
+}
+
+impl Ship {
+    /// Builds a new ship with its shields fully charged
+    ///
+    /// # Arguments
+    /// * `name` - Name of the ship
+    /// * `design` - The design the ship is built from
+    /// * `modules` - Definitions of the modules listed in the design
+    pub fn new(name: String, design: &ShipDesign, modules: Vec<ModuleKind>) -> Self {
+        let mut ship = Self {
+            name,
+            class: design.name().clone(),
+            mass: *design.mass(),
+            hull_points: *design.hull_points(),
+            max_hull_points: *design.hull_points(),
+            shield_points: 0,
+            crew: *design.crew(),
+            max_crew: *design.crew(),
+            modules: modules.into_iter().map(InstalledModule::new).collect(),
+            troops: GroundForces::default(),
+        };
+        ship.shield_points = ship.get_shield_capacity();
+        ship
+    }
+
+    pub fn get_class(&self) -> &String {
+        &self.class
+    }
+
+    pub fn get_hull_points(&self) -> u32 {
+        self.hull_points
+    }
+
+    pub fn get_modules(&self) -> &Vec<InstalledModule> {
+        &self.modules
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.hull_points == 0
+    }
+
+    /// A ship needs repairs if anything but its shields is below the maximum
+    pub fn needs_repairs(&self) -> bool {
+        self.hull_points < self.max_hull_points
+            || self.crew < self.max_crew
+            || self.modules.iter().any(|m| m.is_damaged())
+    }
+
+    /// Lists the modules which still work, along with their efficiency
+    fn get_working_modules(&self) -> impl Iterator<Item = (&ModuleKind, f32)> {
+        self.modules.iter()
+            .filter(|m| !m.is_destroyed())
+            .map(|m| (m.get_module(), m.get_efficiency()))
+    }
+
+    pub fn get_power_output(&self) -> f32 {
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::Reactor(r) => *r.power_output() as f32 * efficiency,
+            _ => 0.0,
+        }).sum()
+    }
+
+    pub fn get_power_demand(&self) -> u32 {
+        self.get_working_modules().map(|(m, _)| match m {
+            ModuleKind::Weapon(w) => *w.power_draw(),
+            ModuleKind::Shield(s) => *s.power_draw(),
+            ModuleKind::Sensor(s) => *s.power_draw(),
+            _ => 0,
+        }).sum()
+    }
+
+    /// Calculates the share of the power demand the reactors can cover, the modules drawing
+    /// power work only as well as they are supplied
+    pub fn get_power_ratio(&self) -> f32 {
+        match self.get_power_demand() {
+            0 => 1.0,
+            demand => (self.get_power_output() / demand as f32).min(1.0),
+        }
+    }
+
+    /// Lists the working weapons
+    ///
+    /// # Returns
+    /// * `Vec<(&Weapon, f32)>` - The weapons along with the share of their damage they deal,
+    ///   lowered by module damage and missing power
+    pub fn get_weapons(&self) -> Vec<(&Weapon, f32)> {
+        let power = self.get_power_ratio();
+        self.get_working_modules().filter_map(|(m, efficiency)| match m {
+            ModuleKind::Weapon(w) => Some((w, efficiency * power)),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_point_defenses(&self) -> Vec<&PointDefense> {
+        self.get_working_modules().filter_map(|(m, _)| match m {
+            ModuleKind::PointDefense(pd) => Some(pd),
+            _ => None,
+        }).collect()
+    }
+
+    pub fn get_shield_capacity(&self) -> u32 {
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::Shield(s) => (*s.capacity() as f32 * efficiency).round() as u32,
+            _ => 0,
+        }).sum()
+    }
+
+    fn get_shield_recharge(&self) -> u32 {
+        let power = self.get_power_ratio();
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::Shield(s) => (*s.recharge() as f32 * efficiency * power).round() as u32,
+            _ => 0,
+        }).sum()
+    }
+
+    /// Restores the shields by the recharge rate of the working shield modules
+    pub fn recharge_shields(&mut self) {
+        self.shield_points = (self.shield_points + self.get_shield_recharge())
+            .min(self.get_shield_capacity());
+    }
+
+    pub fn get_thrust(&self, modifiers: &TechModifiers) -> f32 {
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::SublightEngine(e) => e.get_thrust(modifiers) * efficiency,
+            _ => 0.0,
+        }).sum()
+    }
+
+    /// Calculates the share of the shots the ship dodges thanks to its engines
+    pub fn get_evasion(&self, modifiers: &TechModifiers) -> f32 {
+        (self.get_thrust(modifiers) * EVASION_FACTOR / self.mass.max(1) as f32).min(MAX_EVASION)
+    }
+
+    /// Returns the strength of the best working sensor, active sensors are weakened by missing
+    /// power as well as by damage
+    pub fn get_sensor_strength(&self) -> f32 {
+        let power = self.get_power_ratio();
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::Sensor(s) => match s.mode() {
+                SensorMode::Passive => s.strength() * efficiency,
+                SensorMode::Active => s.strength() * efficiency * power,
+            },
+            _ => 0.0,
+        }).fold(0.0, f32::max)
+    }
+
+    /// Returns the amount of survey points the working survey sensors gather every day
+    pub fn get_survey_points(&self, kind: SurveyKind) -> u32 {
+        self.get_working_modules().map(|(m, efficiency)| match m {
+            ModuleKind::SurveySensor(s) if *s.kind() == kind => {
+                (*s.points() as f32 * efficiency).round() as u32
+            }
+            _ => 0,
+        }).sum()
+    }
+
+    /// Returns the amount of ground units the working troop bays can carry
+    pub fn get_troop_capacity(&self) -> u32 {
+        self.get_working_modules().map(|(m, _)| match m {
+            ModuleKind::TroopBay(t) => *t.capacity(),
+            _ => 0,
+        }).sum()
+    }
+
+    pub fn get_troops(&self) -> &GroundForces {
+        &self.troops
+    }
+
+    /// Loads the units able to board ships into the troop bays, as many as fit
+    ///
+    /// # Arguments
+    /// * `forces` - The troops of the colony the ship is orbiting
+    pub fn embark(&mut self, forces: &mut GroundForces) {
+        let free = self.get_troop_capacity().saturating_sub(self.troops.get_total());
+        self.troops.merge(forces.split_embarking(free));
+    }
+
+    /// Unloads all the carried troops
+    pub fn disembark(&mut self) -> GroundForces {
+        std::mem::take(&mut self.troops)
+    }
+
+    /// Calculates how easy the ship is to detect. Heavier ships and stronger engines are easier
+    /// to spot, and running an active sensor gives the ship away even more.
+    pub fn get_signature(&self, modifiers: &TechModifiers) -> f32 {
+        let signature = self.mass as f32 / SIGNATURE_MASS
+            + self.get_thrust(modifiers) * SIGNATURE_PER_THRUST;
+        let is_emitting = self.get_working_modules().any(|(m, _)| {
+            matches!(m, ModuleKind::Sensor(s) if *s.mode() == SensorMode::Active)
+        });
+
+        if is_emitting {
+            signature * ACTIVE_SENSOR_EMISSION
+        } else {
+            signature
+        }
+    }
+
+    /// Applies a hit to the ship. The damage is absorbed by the shields first, then by the armor,
+    /// and whatever gets through damages the hull, the crew and a random internal module. A hit
+    /// reactor may melt down, damaging the ship further.
+    ///
+    /// # Arguments
+    /// * `damage` - Damage of the hit
+    /// * `rng` - The random number generator of the game
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Names of the modules destroyed by the hit
+    pub fn take_damage(&mut self, damage: u32, rng: &mut impl Rng) -> Vec<String> {
+        let mut destroyed = Vec::<String>::new();
+
+        let absorbed = damage.min(self.shield_points);
+        self.shield_points -= absorbed;
+        let mut damage = damage - absorbed;
+
+        if let Some(armor) = self.modules.iter_mut()
+            .find(|m| !m.is_destroyed() && matches!(m.get_module(), ModuleKind::Armor(_))) {
+            if let ModuleKind::Armor(plating) = armor.get_module() {
+                damage = damage.saturating_sub(*plating.reduction());
+            }
+            damage = armor.take_damage(damage);
+            if armor.is_destroyed() {
+                destroyed.push(armor.get_name());
+            }
+        }
+
+        if damage > 0 {
+            self.damage_internals(damage, 1, rng, &mut destroyed);
+        }
+        self.shield_points = self.shield_points.min(self.get_shield_capacity());
+        self.troops.truncate(self.get_troop_capacity());
+
+        destroyed
+    }
+
+    /// Damages the hull, the crew and random internal modules, setting off reactor meltdowns
+    fn damage_internals(
+        &mut self,
+        damage: u32,
+        modules: usize,
+        rng: &mut impl Rng,
+        destroyed: &mut Vec<String>,
+    ) {
+        self.hull_points = self.hull_points.saturating_sub(damage);
+        self.crew = self.crew.saturating_sub(
+            damage * self.max_crew / self.max_hull_points.max(1) / 2
+        );
+
+        let hit: Vec<usize> = self.modules.iter().enumerate()
+            .filter(|(_, m)| !m.is_destroyed() && !matches!(m.get_module(), ModuleKind::Armor(_)))
+            .map(|(i, _)| i)
+            .choose_multiple(rng, modules);
+
+        let mut meltdowns = Vec::<u32>::new();
+        for i in hit {
+            let module = &mut self.modules[i];
+            module.take_damage(damage);
+
+            if let ModuleKind::Reactor(reactor) = module.get_module() {
+                let chance = reactor.damage_meltdown_chance() * (1.0 - module.get_efficiency());
+                if rng.gen_bool(chance.clamp(0.0, 1.0) as f64) {
+                    meltdowns.push(*reactor.meltdown_damage());
+                    module.destroy();
+                    destroyed.push(format!("{} (meltdown)", module.get_name()));
+                    continue;
+                }
+            }
+            if module.is_destroyed() {
+                destroyed.push(module.get_name());
+            }
+        }
+
+        for meltdown_damage in meltdowns {
+            if !self.is_destroyed() {
+                self.damage_internals(meltdown_damage, MELTDOWN_SPREAD, rng, destroyed);
+            }
+        }
+    }
+
+    /// Lets the crew patch up the damaged modules. Destroyed modules and the hull can only be
+    /// fixed at a dock.
+    pub fn damage_control(&mut self) {
+        let mut points = self.crew / CREW_PER_REPAIR_POINT;
+
+        let mut damaged: Vec<&mut InstalledModule> = self.modules.iter_mut()
+            .filter(|m| !m.is_destroyed()
+                && m.get_efficiency() < DAMAGE_CONTROL_LIMIT)
+            .collect();
+        damaged.sort_by(|a, b| a.get_efficiency().total_cmp(&b.get_efficiency()));
+
+        for module in damaged {
+            if points == 0 {
+                break;
+            }
+            let limit = (module.get_max_hit_points() as f32 * DAMAGE_CONTROL_LIMIT) as u32;
+            points -= module.repair(points.min(limit.saturating_sub(module.get_hit_points())));
+        }
+    }
+
+    /// Repairs the hull and the modules, including the destroyed ones
+    ///
+    /// # Arguments
+    /// * `hull_points` - Maximum amount of hull points to restore
+    /// * `module_points` - Maximum amount of module hit points to restore
+    ///
+    /// # Returns
+    /// * `(u32, u32)` - Amount of hull and module hit points restored
+    pub fn repair(&mut self, hull_points: u32, module_points: u32) -> (u32, u32) {
+        let hull = hull_points.min(self.max_hull_points - self.hull_points);
+        self.hull_points += hull;
+
+        let mut modules = 0;
+        for module in self.modules.iter_mut() {
+            modules += module.repair(module_points - modules);
+        }
+
+        (hull, modules)
+    }
+
+    /// Brings new crew members aboard, up to the full crew of the ship
+    ///
+    /// # Arguments
+    /// * `crew` - Maximum amount of crew members to bring aboard
+    ///
+    /// # Returns
+    /// * `u32` - Amount of crew members brought aboard
+    pub fn replenish_crew(&mut self, crew: u32) -> u32 {
+        let crew = crew.min(self.max_crew - self.crew);
+        self.crew += crew;
+        crew
+    }
+}
+
+impl Displayable for Ship {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        let mut properties = vec![
+            vec![format!("{} ({})", self.name, self.class)],
+            vec![format!("Hull: {}/{}", self.hull_points, self.max_hull_points)],
+            vec![format!("Shields: {}/{}", self.shield_points, self.get_shield_capacity())],
+            vec![format!("Crew: {}/{}", self.crew, self.max_crew)],
+            vec![format!(
+                "Power: {:.0}/{}",
+                self.get_power_output(),
+                self.get_power_demand(),
+            )],
+        ];
+        if self.get_troop_capacity() > 0 {
+            properties.push(vec![format!(
+                "Troops ({}/{}): {}",
+                self.troops.get_total(),
+                self.get_troop_capacity(),
+                self.troops.get_description(),
+            )]);
+        }
+
+        self.modules.iter().for_each(|m| {
+            properties.push(vec![format!(
+                "  {}: {}/{}{}",
+                m.get_name(),
+                m.get_hit_points(),
+                m.get_max_hit_points(),
+                if m.is_destroyed() { " (destroyed)" } else { "" },
+            )])
+        });
+
+        properties
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self.hull_points * 100 / self.max_hull_points.max(1) {
+            0..=25 => Color::LightRed,
+            26..=75 => Color::LightYellow,
+            _ => Color::LightGreen,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::game::fleets::ship::Ship;
+    use crate::game::fleets::ship_design::ShipDesign;
+    use crate::game::shipbuilding::installed_module::ModuleKind;
+
+    fn ship() -> Ship {
+        let design: ShipDesign = serde_json::from_value(serde_json::json!({
+            "id": "test",
+            "name": "Test",
+            "hull_points": 100,
+            "mass": 1000,
+            "crew": 50,
+            "modules": [],
+        })).unwrap();
+        let reactor = serde_json::from_value(serde_json::json!({
+            "name": "Reactor",
+            "traits": [],
+            "is_unlocked": true,
+            "power_output": 10,
+            "damage_meltdown_chance": 0.0,
+            "meltdown_damage": 0,
+            "hit_points": 20,
+        })).unwrap();
+        let weapon = serde_json::from_value(serde_json::json!({
+            "name": "Gun",
+            "traits": [],
+            "is_unlocked": true,
+            "kind": "Kinetic",
+            "damage": 10,
+            "range": 1,
+            "accuracy": 1.0,
+            "power_draw": 10,
+            "hit_points": 20,
+        })).unwrap();
+        Ship::new(
+            "Ship".to_string(),
+            &design,
+            vec![ModuleKind::Reactor(reactor), ModuleKind::Weapon(weapon)],
+        )
+    }
+
+    #[test]
+    fn test_damaged_reactor_starves_weapons() {
+        let mut ship = ship();
+        let mut rng = StdRng::seed_from_u64(0);
+        while ship.get_modules()[0].get_hit_points() == 20 {
+            ship.take_damage(10, &mut rng);
+        }
+
+        let reactor = ship.get_modules()[0].get_efficiency();
+        let weapon = ship.get_modules()[1].get_efficiency();
+        assert_eq!(ship.get_weapons()[0].1, weapon * reactor);
+    }
+
+    #[test]
+    fn test_damage_control_and_repair() {
+        let mut ship = ship();
+        let mut rng = StdRng::seed_from_u64(0);
+        while !ship.get_modules().iter().any(|m| m.get_efficiency() < 0.75) {
+            ship.take_damage(5, &mut rng);
+        }
+
+        for _ in 0..20 {
+            ship.damage_control();
+        }
+        assert!(ship.get_modules().iter().all(|m| m.get_efficiency() >= 0.75));
+        assert!(ship.needs_repairs());
+
+        ship.repair(1000, 1000);
+        ship.replenish_crew(1000);
+        assert!(!ship.needs_repairs());
+    }
+
+    #[test]
+    fn test_crew_is_only_replenished_when_brought_aboard() {
+        let mut ship = ship();
+        ship.crew = 0;
+        ship.repair(1000, 1000);
+        assert_eq!(ship.crew, 0);
+        assert!(ship.needs_repairs());
+
+        assert_eq!(ship.replenish_crew(5), 5);
+        assert_eq!(ship.replenish_crew(1000), ship.max_crew - 5);
+        assert_eq!(ship.replenish_crew(1000), 0);
+        assert!(!ship.needs_repairs());
+    }
+}
@@ -0,0 +1,76 @@
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::game::mods::asset_layers::{AssetError, AssetLayers};
+use crate::game::shipbuilding::ship_module::ShipModuleType;
+
+/// Path of the ship designs, relative to the assets directory
+pub const SHIP_DESIGNS_PATH: &str = "ship_designs.json5";
+
+/// `DesignModule` references a module definition by its type and name
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct DesignModule {
+    module_type: ShipModuleType,
+    name: String,
+}
+
+/// `ShipDesign` is a blueprint every ship of a class is built from
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
+pub struct ShipDesign {
+    id: String,
+    name: String,
+    /// Damage the hull can take before the ship is destroyed
+    hull_points: u32,
+    /// Mass of the ship in tons, heavier ships are easier to hit
+    mass: u32,
+    /// Crew needed to run the ship, it also performs damage control
+    #[serde(default)]
+    crew: u32,
+    modules: Vec<DesignModule>,
+}
+
+impl ShipDesign {
+    /// Loads the designs of the base game and the enabled mods
+    ///
+    /// # Returns
+    /// * `Result<Vec<Self>, AssetError>` - The designs, or why the file could not be loaded
+    pub fn load_from_assets(assets: &AssetLayers, asset: &str) -> Result<Vec<Self>, AssetError> {
+        let file_contents = assets.read_records(asset)?;
+
+        serde_json::from_str(&file_contents).map_err(|e| AssetError::Parse {
+            path: asset.to_string(),
+            message: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::game::fleets::ship_design::{SHIP_DESIGNS_PATH, ShipDesign};
+    use crate::game::mods::asset_layers::{AssetError, AssetLayers};
+    use crate::game::mods::game_mod::GameMod;
+
+    #[test]
+    fn test_invalid_designs_are_reported() {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let dir = std::env::temp_dir()
+            .join(format!("astray-designs-{}-{nanos}", std::process::id()));
+        let mod_dir = dir.join("broken-designs");
+        std::fs::create_dir_all(mod_dir.join("assets")).unwrap();
+        std::fs::write(mod_dir.join("mod.json5"), r#"{ "name": "Broken designs" }"#).unwrap();
+        std::fs::write(
+            mod_dir.join("assets/ship_designs.json5"),
+            r#"[{ "id": "frigate", "name": "Frigate" }]"#,
+        ).unwrap();
+
+        let layers = AssetLayers::new(vec![GameMod::load_from_dir(&mod_dir).unwrap()]);
+        let designs = ShipDesign::load_from_assets(&layers, SHIP_DESIGNS_PATH);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(ShipDesign::load_from_assets(&AssetLayers::default(), SHIP_DESIGNS_PATH)
+            .is_ok_and(|designs| !designs.is_empty()));
+        assert!(matches!(designs, Err(AssetError::Parse { .. })));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::event_log::EventCategory;
+
+/// `GameOptions` holds the rules a game is started with. They are read from the `game` section
+/// of the configuration file
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GameOptions {
+    /// Enables random breakthroughs, setbacks and eureka moments while researching
+    #[serde(default = "default_true")]
+    pub random_research: bool,
+    /// Enables pirate fleets raiding the colonies from time to time
+    #[serde(default)]
+    pub pirate_raids: bool,
+    /// Enables the events defined in the assets, which ask the player to make a choice
+    #[serde(default = "default_true")]
+    pub random_events: bool,
+    /// Runs the scripts of the `scripts` directory, which play along with the player
+    #[serde(default = "default_true")]
+    pub scripts: bool,
+    /// Hides the planets and fleets the player's sensors have not detected
+    #[serde(default = "default_true")]
+    pub fog_of_war: bool,
+    /// Amount of computer-controlled empires competing with the player
+    #[serde(default = "default_computer_empires")]
+    pub computer_empires: u32,
+    /// Seed of the random events and of the decisions of the computer players, a random one is
+    /// picked if it is not set
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Categories of the player's events which pause the game as soon as they happen
+    #[serde(default)]
+    pub pause_on: Vec<EventCategory>,
+    /// Ids of the mods the assets are loaded from, every one overrides the ones before it. The
+    /// mods enabled in the mod manager are used if it is empty
+    #[serde(default)]
+    pub mods: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_computer_empires() -> u32 {
+    1
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        Self {
+            random_research: true,
+            pirate_raids: false,
+            random_events: true,
+            scripts: true,
+            fog_of_war: true,
+            computer_empires: default_computer_empires(),
+            seed: None,
+            pause_on: Vec::new(),
+            mods: Vec::new(),
+        }
+    }
+}
@@ -1,3 +1,9 @@
+use std::collections::{BTreeMap, HashMap};
+
+use color_eyre::eyre::{eyre, Result};
+use rand::{Rng, SeedableRng, thread_rng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 use ratatui::style::Color;
 
 use crate::game::celestial_bodies::{CelestialBody, Displayable, Orbitable};
@@ -5,61 +11,296 @@ use crate::game::celestial_bodies::planet::Planet;
 use crate::game::celestial_bodies::solar_system::SolarSystem;
 use crate::game::colony::building::BuildingType;
 use crate::game::colony::colony::Colony;
+use crate::game::colony::colony_event::ColonyEvent;
+use crate::game::combat::engagement::resolve_engagement;
+use crate::game::combat::combat_report::CombatReport;
+use crate::game::diplomacy::diplomacy_manager::DiplomacyManager;
+use crate::game::diplomacy::incident::Incident;
+use crate::game::diplomacy::treaty::Treaty;
+use crate::game::empires::empire::{COLONISTS, Empire, get_colonization_cost, get_ship_cost};
+use crate::game::empires::empire_event::EmpireEvent;
+use crate::game::empires::expansion_strategy::ExpansionStrategy;
+use crate::game::empires::policy::Policy;
+use crate::game::empires::strategy::{ComputerPlayer, EmpireView, Order};
+use crate::game::event_log::{EventCategory, EventLog, GameEvent, LogEntry};
+use crate::game::events::event_manager::EventManager;
+use crate::game::exploration::body_survey::{BodySurvey, SurveyKind};
+use crate::game::fleets::fleet::{Fleet, PIRATE_OWNER, PLAYER_OWNER};
+use crate::game::fleets::fleet_event::FleetEvent;
+use crate::game::fleets::ship_design::{SHIP_DESIGNS_PATH, ShipDesign};
+use crate::game::ground::invasion::resolve_invasions;
+use crate::game::game_options::GameOptions;
+use crate::game::mods::asset_layers::AssetLayers;
+use crate::game::mods::namelists::Namelists;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::personnel::personnel_manager::INITIAL_OFFICERS;
+use crate::game::research::research_graph::ResearchGraph;
 use crate::game::research::research_manager::ResearchManager;
+use crate::game::research::research_progress::ResearchState;
+use crate::game::research::tech_modifiers::TechModifiers;
+use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+use crate::game::sensors::detection::{is_detected, SensorSource};
+use crate::game::sensors::knowledge::Contact;
 use crate::game::shipbuilding::ship_module::ShipModuleType;
 use crate::game::shipbuilding::ship_module_manager::ShipModuleManager;
 
+/// Chance of pirates raiding one of the colonies every tick
+const PIRATE_RAID_CHANCE: f64 = 1.0 / 180.0;
+/// Maximum amount of ships in a pirate raid
+const MAX_RAIDERS: u32 = 3;
+/// Amount of hit points every dry dock or spaceport restores every day
+const REPAIR_POINTS_PER_FACILITY: u32 = 10;
+/// Resource spent on every repaired hull point
+const HULL_REPAIR_RESOURCE: ResourceType = ResourceType::PRHeavyMetals;
+/// Resource spent on every repaired module hit point
+const MODULE_REPAIR_RESOURCE: ResourceType = ResourceType::PRLightMetals;
+/// Amount of colonists every dry dock or spaceport enlists as crew every day
+const CREW_PER_FACILITY: u32 = 20;
+/// Resource spent on every enlisted crew member
+const CREW_RESOURCE: ResourceType = ResourceType::PRWater;
+/// Distance covered by a fleet every day, in AU
+const FLEET_SPEED: f64 = 0.2;
+/// A gravitational survey maps the orbits up to this many times further from the star than the
+/// orbit of the surveyed planet
+const SURVEY_REACH: f64 = 2.5;
+/// Population of the capital of every empire when the game starts
+const CAPITAL_POPULATION: i32 = 5_000;
+/// Names and colors of the computer-controlled empires, in the order they are founded
+const COMPUTER_EMPIRES: [(&str, Color); 3] = [
+    ("Vorlan Hegemony", Color::Yellow),
+    ("Kessari Union", Color::LightMagenta),
+    ("Tal Dominion", Color::Cyan),
+];
+
 pub struct GameState {
+    options: GameOptions,
+    /// Base game and mods the assets of the game were loaded from
+    assets: AssetLayers,
+    namelists: Namelists,
+    seed: u64,
+    rng: StdRng,
+    date: u64,
+    event_log: EventLog,
     systems: Vec<SolarSystem>,
-    capital: Planet,
-    capital_system: SolarSystem,
-    colonies: Vec<Colony>,
+    /// Every empire of the game, the player's first
+    empires: Vec<Empire>,
+    computer_players: Vec<ComputerPlayer>,
+    diplomacy: DiplomacyManager,
     resource_tick_ratio: u32,
     resource_tick_counter: u32,
-    research_manager: ResearchManager,
     ship_module_manager: ShipModuleManager,
+    ship_designs: Vec<ShipDesign>,
+    fleets: Vec<Fleet>,
+    next_fleet_id: u32,
+    ships_built: u32,
+    combat_reports: Vec<CombatReport>,
+    event_manager: EventManager,
 }
 
-impl Default for GameState {
-    fn default() -> Self {
+impl GameState {
+    pub fn new(options: GameOptions) -> Result<Self> {
+        let assets = AssetLayers::load(&options.mods)?;
+        let namelists = Namelists::load(&assets)?;
+        let seed: u64 = options.seed.unwrap_or_else(|| thread_rng().gen());
+        let mut rng = StdRng::seed_from_u64(seed);
+
         let mut system: SolarSystem;
         let capital_planet: Planet;
         loop {
-            system = SolarSystem::generate(());
+            system = SolarSystem::generate(namelists.stars.clone(), &mut rng);
             if let Some(planet) = system.has_planets_in_habitable_zone() {
                 capital_planet = planet;
                 break
             }
         }
 
-        Self {
-            systems: vec![system.clone()],
-            capital: capital_planet.clone(),
-            capital_system: system,
-            colonies: vec![
-                Colony::new(
-                    capital_planet.get_name(),
-                    5_000,
-                )
-            ],
+        let ship_module_manager = ShipModuleManager::new(&assets)?;
+        let ship_designs = ShipDesign::load_from_assets(&assets, SHIP_DESIGNS_PATH)?;
+        let event_manager = EventManager::new(&assets)?;
+        let mut state = Self {
+            options,
+            assets,
+            namelists,
+            seed,
+            rng,
+            date: 0,
+            event_log: EventLog::default(),
+            systems: vec![system],
+            empires: Vec::new(),
+            computer_players: Vec::new(),
+            diplomacy: DiplomacyManager::default(),
             resource_tick_counter: 0,
             resource_tick_ratio: 2,
-            research_manager: ResearchManager::new(),
 
-            ship_module_manager: ShipModuleManager::new(),
+            ship_module_manager,
+            ship_designs,
+            fleets: Vec::new(),
+            next_fleet_id: 0,
+            ships_built: 0,
+            combat_reports: Vec::new(),
+            event_manager,
+        };
+
+        state.found_empire(
+            PLAYER_OWNER.to_string(),
+            String::from("Player"),
+            Color::LightGreen,
+            &capital_planet,
+        )?;
+
+        let computer_empires = state.options.computer_empires as usize;
+        for (n, (name, color)) in COMPUTER_EMPIRES.iter().take(computer_empires).enumerate() {
+            let mut free: Vec<Planet> = state.systems[0].get_satellites().into_iter()
+                .filter(|p| !state.empires.iter().any(|e| e.has_colony(&p.get_name())))
+                .collect();
+            free.sort_by_key(|p| !p.is_inside_habitable_zone());
+            let capital = match free.first() {
+                Some(planet) => planet.clone(),
+                None => break,
+            };
+
+            let id = format!("empire-{}", n + 1);
+            state.found_empire(id.clone(), name.to_string(), *color, &capital)?;
+            state.computer_players.push(ComputerPlayer {
+                empire: id,
+                strategy: Box::new(ExpansionStrategy),
+                rng: StdRng::seed_from_u64(seed.wrapping_add(n as u64 + 1)),
+            });
+        }
+        for (i, first) in state.empires.iter().enumerate() {
+            for second in state.empires.iter().skip(i + 1) {
+                state.diplomacy.sign(first.get_id(), second.get_id(), Treaty::Peace);
+            }
         }
+        state.update_sensors();
+
+        Ok(state)
     }
-}
 
-impl GameState {
-    pub fn tick(&mut self) {
+    /// Founds an empire on a planet, with a home guard, a survey ship and the planet surveyed
+    ///
+    /// # Arguments
+    /// * `id` - Identifier of the empire, its fleets use it as their owner
+    /// * `name` - Name of the empire
+    /// * `color` - Color the empire is shown in
+    /// * `capital` - The planet the capital is founded on
+    fn found_empire(
+        &mut self,
+        id: String,
+        name: String,
+        color: Color,
+        capital: &Planet,
+    ) -> Result<()> {
+        let research_manager = ResearchManager::new(&self.assets).map_err(|errors| {
+            eyre!(
+                "Research assets are invalid:\n{}",
+                errors.iter().map(|e| format!("  - {e}")).collect::<Vec<String>>().join("\n")
+            )
+        })?;
+
+        let home_guard = self.ship_designs.iter()
+            .find(|d| d.id() == "corvette")
+            .and_then(|design| {
+                ["Vanguard", "Resolute"].iter()
+                    .map(|name| self.ship_module_manager.build_ship(design, name.to_string()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(|| eyre!("Ship design `corvette` is missing or uses unknown modules"))?;
+        let surveyor = self.ship_designs.iter()
+            .find(|d| d.id() == "surveyor")
+            .and_then(|design| {
+                self.ship_module_manager.build_ship(design, String::from("Pathfinder"))
+            })
+            .ok_or_else(|| eyre!("Ship design `surveyor` is missing or uses unknown modules"))?;
+
+        let mut empire = Empire::new(
+            id.clone(),
+            name,
+            color,
+            Colony::new(
+                capital.get_name(),
+                CAPITAL_POPULATION,
+                capital.get_resource_deposit().clone(),
+                capital.is_inside_habitable_zone(),
+            ),
+            research_manager,
+        );
+        empire.get_knowledge_mut().complete_survey(capital.get_name());
+        for _ in 0..INITIAL_OFFICERS {
+            empire.get_personnel_mut().recruit(&self.namelists, &mut self.rng);
+        }
+
+        let prefix = if empire.is_player() {
+            String::new()
+        } else {
+            format!("{} ", empire.get_name())
+        };
+        for (fleet, ships) in [("Home Guard", home_guard), ("Survey Flotilla", vec![surveyor])] {
+            self.fleets.push(Fleet::new(
+                self.next_fleet_id,
+                format!("{prefix}{fleet}"),
+                id.clone(),
+                capital.get_name(),
+                ships,
+            ));
+            self.next_fleet_id += 1;
+        }
+
+        self.empires.push(empire);
+        self.reveal_surroundings(&id, &capital.get_name());
+        Ok(())
+    }
+
+    pub fn get_player(&self) -> &Empire {
+        &self.empires[0]
+    }
+
+    fn get_player_mut(&mut self) -> &mut Empire {
+        &mut self.empires[0]
+    }
+
+    fn get_empire_mut(&mut self, id: &str) -> Option<&mut Empire> {
+        self.empires.iter_mut().find(|e| e.get_id() == id)
+    }
+
+    /// Advances the game by a day
+    ///
+    /// # Returns
+    /// * `Vec<LogEntry>` - The events logged during the day, oldest first
+    pub fn tick(&mut self) -> Vec<LogEntry> {
+        let logged = self.event_log.len();
+        self.date += 1;
         self.update_research();
         self.update_colonies();
+        self.update_computer_players();
+        self.update_fleets();
+        self.diplomacy.update(self.date, &mut self.empires, &self.fleets, &self.systems[0]);
+        self.update_personnel();
+        self.update_events();
         self.update_orbits();
+        self.update_sensors();
+        self.event_log.get_since(logged)
     }
 
-    pub fn new() -> Self {
-        Self::default()
+    /// Carries out an order of the player if it is possible, impossible orders are ignored
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the order was carried out
+    pub fn give_order(&mut self, order: Order) -> bool {
+        self.carry_out(PLAYER_OWNER, order)
+    }
+
+    /// Returns every empire of the game, the player's first
+    pub fn get_empires(&self) -> &[Empire] {
+        &self.empires
+    }
+
+    pub fn get_diplomacy(&self) -> &DiplomacyManager {
+        &self.diplomacy
+    }
+
+    pub fn get_event_manager(&self) -> &EventManager {
+        &self.event_manager
     }
 
     pub fn get_starting_system(&self) -> SolarSystem {
@@ -67,46 +308,899 @@ impl GameState {
     }
 
     pub fn get_research_fields(&self) -> Vec<(String, String, Color)> {
-        self.research_manager.get_research_fields()
+        self.get_player().get_research_manager().get_research_fields()
     }
 
     pub fn get_research_info(&self, id: String) -> Vec<Vec<String>> {
-        self.research_manager.get_research_info(id)
+        self.get_player().get_research_manager().get_research_info(id)
     }
 
     pub fn get_research_dependency_info(&self, id: String) -> Vec<Vec<(String, bool)>> {
-        self.research_manager.get_dependency_info(id)
+        self.get_player().get_research_manager().get_dependency_info(id)
     }
 
     pub fn get_researches_by_field(&self, id: String) -> Vec<(String, String, Color)> {
-        self.research_manager.get_researches_with_colors_by_field(id)
+        self.get_player().get_research_manager().get_researches_with_colors_by_field(id)
     }
 
     pub fn get_research_progress_text(&self, id: String) -> String {
-        self.research_manager.get_research_text(id)
+        self.get_player().get_research_manager().get_research_text(id)
     }
 
     pub fn get_research_progress(&self, id: String) -> u32 {
-        self.research_manager.get_research_progress(id)
+        self.get_player().get_research_manager().get_research_progress(id)
+    }
+
+    pub fn get_research_graph(&self) -> ResearchGraph {
+        self.get_player().get_research_manager().get_research_graph()
     }
 
     pub fn start_research(&mut self, id: String) {
-        self.research_manager.start_research(id)
+        self.get_player_mut().get_research_manager_mut().start_research(id)
     }
 
     fn update_research(&mut self) {
-        self.research_manager.tick();
+        for empire in self.empires.iter_mut() {
+            let in_progress = empire.get_research_manager().get_researches_in_progress();
+            let mut events = Vec::new();
+            if self.options.random_research {
+                let buildings = Self::get_building_counts(empire);
+                events = empire.get_research_manager_mut()
+                    .roll_random_events(&mut self.rng, &buildings);
+            }
+
+            let leaders: Vec<(String, u32)> = empire.get_personnel().get_officers().iter()
+                .filter_map(|o| match o.get_assignment() {
+                    Some(Assignment::Research(id)) => {
+                        Some((id.clone(), o.get_research_points(self.date)))
+                    }
+                    _ => None,
+                })
+                .collect();
+            for (research, points) in leaders.into_iter().filter(|(_, p)| *p > 0) {
+                empire.get_research_manager_mut().add_progress(&research, points);
+            }
+            empire.get_research_manager_mut().tick();
+
+            events.extend(empire.get_research_manager().get_completed(&in_progress));
+            if empire.is_player() {
+                events.iter().for_each(|e| self.event_log.push(self.date, e));
+            }
+        }
+    }
+
+    /// Counts the buildings of every type across all the colonies of an empire, by building name
+    fn get_building_counts(empire: &Empire) -> HashMap<String, u32> {
+        let mut counts = HashMap::<String, u32>::new();
+        empire.get_colonies().iter().for_each(|c| {
+            c.get_buildings().into_iter().for_each(|(name, amount, _)| {
+                *counts.entry(name).or_insert(0) += amount;
+            })
+        });
+        counts
+    }
+
+    pub fn get_date(&self) -> u64 {
+        self.date
+    }
+
+    /// Returns the options the game was started with, with its seed, so the same game can be
+    /// started again
+    pub fn get_options(&self) -> GameOptions {
+        GameOptions { seed: Some(self.seed), ..self.options.clone() }
+    }
+
+    /// Returns the id and the version of every mod the game was started with, they are needed to
+    /// load the game again
+    pub fn get_mods(&self) -> Vec<String> {
+        self.assets.get_labels()
+    }
+
+    /// Returns the layers the assets of the game are loaded from
+    pub fn get_assets(&self) -> &AssetLayers {
+        &self.assets
+    }
+
+    /// Returns the research progress of every empire, by the id of the empire
+    pub fn get_research_states(&self) -> BTreeMap<String, ResearchState> {
+        self.empires.iter()
+            .map(|e| (e.get_id().clone(), e.get_research_manager().get_state().clone()))
+            .collect()
+    }
+
+    /// Replaces the research progress of the empires, e.g. with the one of a save
+    ///
+    /// # Arguments
+    /// * `states` - The research progress of the empires, by the id of the empire
+    pub fn set_research_states(&mut self, states: BTreeMap<String, ResearchState>) -> Result<()> {
+        for (id, state) in states {
+            match self.get_empire_mut(&id) {
+                Some(empire) => empire.get_research_manager_mut().set_state(state),
+                None => return Err(eyre!("There is no empire {id} in the game")),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_latest_events(&self, amount: usize) -> Vec<(String, Color)> {
+        self.event_log.get_latest(amount)
+    }
+
+    /// Returns up to `amount` of the latest logged events of the given categories, newest first
+    pub fn get_filtered_events(
+        &self,
+        amount: usize,
+        categories: &[EventCategory],
+    ) -> Vec<(String, Color)> {
+        self.event_log.get_filtered(amount, categories)
     }
 
     fn update_colonies(&mut self) {
-        self.colonies.iter_mut().for_each(|c| c.tick());
         self.resource_tick_counter += 1;
-        if self.resource_tick_ratio == self.resource_tick_counter {
+        let is_resource_tick = self.resource_tick_ratio == self.resource_tick_counter;
+        if is_resource_tick {
             self.resource_tick_counter = 0;
-            self.colonies.iter_mut().for_each(|c| c.resource_tick());
+        }
+
+        let ids: Vec<String> = self.empires.iter().map(|e| e.get_id().clone()).collect();
+        for empire in self.empires.iter_mut() {
+            let wars = ids.iter()
+                .filter(|id| self.diplomacy.are_hostile(empire.get_id(), id))
+                .count();
+            let events = empire.update_colonies(is_resource_tick, wars, &mut self.rng);
+            if empire.is_player() {
+                events.iter().for_each(|e| self.event_log.push(self.date, e));
+            }
+        }
+    }
+
+    /// Lets the officers of every empire serve for a day
+    fn update_personnel(&mut self) {
+        for empire in self.empires.iter_mut() {
+            let events = empire.update_personnel(
+                self.date,
+                &self.fleets,
+                &self.namelists,
+                &mut self.rng,
+            );
+            if empire.is_player() {
+                events.iter().for_each(|e| self.event_log.push(self.date, e));
+            }
+        }
+    }
+
+    /// Wears off the temporary modifiers of every empire, then rolls the events of the player
+    fn update_events(&mut self) {
+        self.empires.iter_mut().for_each(|e| e.update_temporary_modifiers());
+        if self.options.random_events {
+            self.event_manager.roll(self.date, &self.empires[0], &mut self.rng);
+        }
+    }
+
+    /// Lets the strategies of the computer players decide what their empires do, then carries
+    /// out their orders
+    fn update_computer_players(&mut self) {
+        let mut players = std::mem::take(&mut self.computer_players);
+
+        for player in players.iter_mut() {
+            let orders = match self.get_empire_view(&player.empire) {
+                Some(view) => player.strategy.decide(&view, &mut player.rng),
+                None => continue,
+            };
+            orders.into_iter().for_each(|order| { self.carry_out(&player.empire, order); });
+        }
+
+        self.computer_players = players;
+    }
+
+    /// Gathers everything the player can see, the same as a computer player would
+    pub fn get_player_view(&self) -> EmpireView<'_> {
+        self.get_empire_view(PLAYER_OWNER).unwrap()
+    }
+
+    /// Writes an event of the player into the log
+    pub fn log_event(&mut self, event: &impl GameEvent) -> LogEntry {
+        let logged = self.event_log.len();
+        self.event_log.push(self.date, event);
+        self.event_log.get_since(logged).remove(0)
+    }
+
+    /// Gathers everything an empire can see for its strategy
+    fn get_empire_view(&self, id: &str) -> Option<EmpireView<'_>> {
+        let empire = self.empires.iter().find(|e| e.get_id() == id)?;
+        let knowledge = empire.get_knowledge();
+        let system = &self.systems[0];
+        let home = system.get_body_position(empire.get_capital()).unwrap_or((0.0, 0.0));
+        let distance_home = |p: &Planet| {
+            let (x, y) = p.get_position();
+            (x - home.0).hypot(y - home.1)
+        };
+
+        let mut free_planets: Vec<Planet> = system.get_satellites().into_iter()
+            .filter(|p| knowledge.knows_body(&p.get_name()))
+            .filter(|p| !self.empires.iter().any(|e| e.has_colony(&p.get_name())))
+            .collect();
+        free_planets.sort_by(|a, b| distance_home(a).total_cmp(&distance_home(b)));
+
+        let mut foreign_colonies: Vec<(String, String)> = self.empires.iter()
+            .filter(|e| e.get_id() != id)
+            .flat_map(|e| e.get_colonies().iter().map(|c| (e.get_id().clone(), c.get_name())))
+            .filter(|(_, colony)| knowledge.knows_body(colony))
+            .collect();
+        foreign_colonies.sort();
+
+        let rivals: Vec<String> = self.empires.iter()
+            .filter(|e| e.get_id() != id && knowledge.has_met(e.get_id()))
+            .map(|e| e.get_id().clone())
+            .collect();
+
+        Some(EmpireView {
+            date: self.date,
+            empire,
+            fleets: self.fleets.iter().filter(|f| f.get_owner() == id).collect(),
+            free_planets: free_planets.iter().map(|p| p.get_name()).collect(),
+            foreign_colonies,
+            rivals,
+            diplomacy: &self.diplomacy,
+            researches: empire.get_research_manager().get_available_researches(),
+            designs: &self.ship_designs,
+        })
+    }
+
+    /// Carries out an order of an empire if it is possible, impossible orders are ignored
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the order was carried out
+    fn carry_out(&mut self, id: &str, order: Order) -> bool {
+        match order {
+            Order::StartResearch(research) => {
+                match self.get_empire_mut(id) {
+                    Some(empire) => empire.get_research_manager_mut().start_research(research),
+                    None => return false,
+                }
+            }
+            Order::StartConstruction { colony, building } => {
+                match self.get_empire_mut(id).and_then(|e| e.get_colony_mut(&colony)) {
+                    Some(colony) => colony.start_construction(building),
+                    None => return false,
+                }
+            }
+            Order::BuildShip { colony, design } => {
+                return self.build_ship(id, &colony, &design)
+            }
+            Order::Colonize { from, planet } => {
+                return self.colonize(id, &from, planet)
+            }
+            Order::MoveFleet { fleet, destination } => {
+                let system = &self.systems[0];
+                let fleet = self.fleets.iter_mut()
+                    .find(|f| f.get_id() == fleet && f.get_owner() == id && !f.is_in_transit());
+                let fleet = match fleet {
+                    Some(fleet) if *fleet.get_location() != destination => fleet,
+                    _ => return false,
+                };
+                let distance = match (
+                    system.get_body_position(fleet.get_location()),
+                    system.get_body_position(&destination),
+                ) {
+                    (Some(from), Some(to)) => (to.0 - from.0).hypot(to.1 - from.1),
+                    _ => return false,
+                };
+                let speed = Self::get_fleet_speed(&self.empires, fleet);
+                fleet.set_course(destination, (distance / speed).ceil() as u32);
+            }
+            Order::EmbarkTroops { fleet } => {
+                let fleet = match self.fleets.iter_mut()
+                    .find(|f| f.get_id() == fleet && f.get_owner() == id && !f.is_in_transit()) {
+                    Some(fleet) => fleet,
+                    None => return false,
+                };
+                let colony = self.empires.iter_mut()
+                    .find(|e| e.get_id() == id)
+                    .and_then(|e| e.get_colony_mut(fleet.get_location()));
+                match colony {
+                    Some(colony) => fleet.embark(colony.get_ground_forces_mut()),
+                    None => return false,
+                }
+            }
+            Order::DeclareWar(empire) => {
+                return self.diplomacy.go_to_war(
+                    id,
+                    &empire,
+                    &self.empires,
+                    &mut self.event_log,
+                    self.date,
+                )
+            }
+            Order::ProposeTreaty { empire, treaty } => {
+                return self.diplomacy.offer_treaty(
+                    id,
+                    &empire,
+                    treaty,
+                    &self.empires,
+                    &mut self.event_log,
+                    self.date,
+                )
+            }
+            Order::AcceptTreaty { empire, treaty } => {
+                return self.diplomacy.accept_offer(
+                    &empire,
+                    id,
+                    treaty,
+                    &self.empires,
+                    &mut self.event_log,
+                    self.date,
+                )
+            }
+            Order::CancelTreaty { empire, treaty } => {
+                return self.diplomacy.cancel_treaty(
+                    id,
+                    &empire,
+                    treaty,
+                    &self.empires,
+                    &mut self.event_log,
+                    self.date,
+                )
+            }
+            Order::SetPolicy { policy, is_enacted } => {
+                match self.get_empire_mut(id) {
+                    Some(empire) => return empire.set_policy(policy, is_enacted),
+                    None => return false,
+                }
+            }
+            Order::AssignOfficer { officer, assignment } => {
+                let empire = match self.empires.iter_mut().find(|e| e.get_id() == id) {
+                    Some(empire) => empire,
+                    None => return false,
+                };
+                let posts = empire.get_posts(&self.fleets);
+                if assignment.as_ref().is_some_and(|a| !posts.contains(a)) {
+                    return false;
+                }
+                return empire.get_personnel_mut().assign(officer, assignment);
+            }
+            Order::ApplyEffect { colony, effect } => {
+                match self.get_empire_mut(id) {
+                    Some(empire) => return empire.apply_effect(&colony, &effect),
+                    None => return false,
+                }
+            }
+            Order::AnswerEvent { choice } => {
+                if id != PLAYER_OWNER {
+                    return false;
+                }
+                match self.event_manager.resolve(choice, &mut self.empires[0]) {
+                    Some(outcome) => self.event_log.push(self.date, &outcome),
+                    None => return false,
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns the distance a fleet covers every day, longer with a skilled commander
+    fn get_fleet_speed(empires: &[Empire], fleet: &Fleet) -> f64 {
+        empires.iter()
+            .find(|e| e.get_id() == fleet.get_owner())
+            .and_then(|e| e.get_personnel().get_assigned(&Assignment::Fleet(fleet.get_id())))
+            .map_or(FLEET_SPEED, |o| FLEET_SPEED * o.get_speed_multiplier())
+    }
+
+    /// Builds a ship at a colony, which pays for it. The ship joins a fleet of warships of the
+    /// empire orbiting the colony, or forms a new one.
+    fn build_ship(&mut self, id: &str, colony: &str, design: &str) -> bool {
+        let design = match self.ship_designs.iter().find(|d| d.id() == design) {
+            Some(design) => design,
+            None => return false,
+        };
+        let name = format!("{} {}", design.name(), self.ships_built + 1);
+        let ship = match self.ship_module_manager.build_ship(design, name) {
+            Some(ship) => ship,
+            None => return false,
+        };
+        let empire = match self.empires.iter_mut().find(|e| e.get_id() == id) {
+            Some(empire) => empire,
+            None => return false,
+        };
+        if !empire.get_colony_mut(colony).is_some_and(|c| c.pay(get_ship_cost(design))) {
+            return false;
+        }
+        self.ships_built += 1;
+
+        let fleet = self.fleets.iter_mut().find(|f| {
+            f.get_owner() == id
+                && f.get_location() == colony
+                && !f.is_in_transit()
+                && f.get_ships().iter().all(|s| s.get_class() == ship.get_class())
+        });
+        match fleet {
+            Some(fleet) => fleet.get_ships_mut().push(ship),
+            None => {
+                self.fleets.push(Fleet::new(
+                    self.next_fleet_id,
+                    format!("{} Fleet {}", empire.get_name(), self.next_fleet_id),
+                    id.to_string(),
+                    colony.to_string(),
+                    vec![ship],
+                ));
+                self.next_fleet_id += 1;
+            }
+        }
+        true
+    }
+
+    /// Settles a free planet, paid by an existing colony of the empire
+    fn colonize(&mut self, id: &str, from: &str, planet: String) -> bool {
+        if self.empires.iter().any(|e| e.has_colony(&planet)) {
+            return false;
+        }
+        let (deposit, is_habitable) = match self.systems[0].get_satellites().into_iter()
+            .find(|p| p.get_name() == planet) {
+            Some(target) => {
+                (target.get_resource_deposit().clone(), target.is_inside_habitable_zone())
+            }
+            None => return false,
+        };
+        let empire = match self.get_empire_mut(id) {
+            Some(empire) => empire,
+            None => return false,
+        };
+        if !empire.get_colony_mut(from).is_some_and(|c| c.pay(get_colonization_cost())) {
+            return false;
+        }
+        empire.add_colony(Colony::new(planet.clone(), COLONISTS, deposit, is_habitable));
+
+        let empire = empire.get_name();
+        if self.get_player().get_knowledge().knows_body(&planet) {
+            self.event_log.push(self.date, &EmpireEvent::ColonyFounded { empire, planet });
+        }
+        true
+    }
+
+    fn update_fleets(&mut self) {
+        if self.options.pirate_raids && self.rng.gen_bool(PIRATE_RAID_CHANCE) {
+            self.spawn_pirate_raid();
+        }
+        self.fleets.iter_mut().for_each(|f| { f.advance(); });
+        let engaged = self.resolve_combat();
+        resolve_invasions(
+            &mut self.fleets,
+            &mut self.empires,
+            &mut self.diplomacy,
+            &mut self.event_log,
+            self.date,
+            &mut self.rng,
+        );
+
+        self.fleets.iter_mut()
+            .filter(|f| !engaged.contains(f.get_location()))
+            .for_each(|f| f.get_ships_mut().iter_mut().for_each(|s| s.damage_control()));
+        self.repair_fleets(&engaged);
+        self.update_surveys();
+
+        self.fleets.iter_mut().for_each(|f| {
+            f.get_ships_mut().iter_mut().for_each(|s| s.recharge_shields())
+        });
+    }
+
+    /// Repairs the ships orbiting the colonies of their empire with dry docks or spaceports,
+    /// paying for every restored hit point with the colony's metals. Missing crew members are
+    /// enlisted from the colonists, paying for their supplies with the colony's water
+    ///
+    /// # Arguments
+    /// * `engaged` - Locations where fighting happened this tick, no repairs happen there
+    fn repair_fleets(&mut self, engaged: &[String]) {
+        let fleets = self.fleets.iter_mut().filter(|f| {
+            !f.is_in_transit() && !engaged.contains(f.get_location())
+        });
+
+        for fleet in fleets {
+            let colony = self.empires.iter_mut()
+                .find(|e| e.get_id() == fleet.get_owner())
+                .and_then(|e| e.get_colony_mut(fleet.get_location()));
+            let colony = match colony {
+                Some(colony) => colony,
+                None => continue,
+            };
+            let facilities = colony.get_repair_facilities();
+            let mut capacity = facilities * REPAIR_POINTS_PER_FACILITY;
+            let mut crew_capacity = facilities * CREW_PER_FACILITY;
+            if capacity == 0 || !fleet.get_ships().iter().any(|s| s.needs_repairs()) {
+                continue;
+            }
+
+            for ship in fleet.get_ships_mut().iter_mut().filter(|s| s.needs_repairs()) {
+                let budget = capacity.min(colony.get_resource_amount(&HULL_REPAIR_RESOURCE));
+                let (hull, _) = ship.repair(budget, 0);
+                capacity -= hull;
+
+                let budget = capacity.min(colony.get_resource_amount(&MODULE_REPAIR_RESOURCE));
+                let (_, modules) = ship.repair(0, budget);
+                capacity -= modules;
+
+                let budget = crew_capacity
+                    .min(colony.get_resource_amount(&CREW_RESOURCE))
+                    .min(colony.get_population().max(0) as u32);
+                let crew = colony.enlist_crew(ship.replenish_crew(budget));
+                crew_capacity -= crew;
+
+                colony.pay(vec![
+                    ResourceTransaction::new(HULL_REPAIR_RESOURCE, -(hull as i32)),
+                    ResourceTransaction::new(MODULE_REPAIR_RESOURCE, -(modules as i32)),
+                    ResourceTransaction::new(CREW_RESOURCE, -(crew as i32)),
+                ]);
+            }
+
+            if fleet.get_owner() == PLAYER_OWNER
+                && !fleet.get_ships().iter().any(|s| s.needs_repairs()) {
+                self.event_log.push(self.date, &FleetEvent::Repaired {
+                    fleet: fleet.get_name(),
+                    location: fleet.get_location().clone(),
+                });
+            }
+        }
+    }
+
+    fn spawn_pirate_raid(&mut self) {
+        let colonies: Vec<String> = self.empires.iter()
+            .flat_map(|e| e.get_colonies().iter().map(|c| c.get_name()))
+            .collect();
+        let location = match colonies.choose(&mut self.rng) {
+            Some(colony) => colony.clone(),
+            None => return,
+        };
+        let design = match self.ship_designs.iter().find(|d| d.id() == "raider") {
+            Some(design) => design,
+            None => return,
+        };
+
+        let id = self.next_fleet_id;
+        let amount = self.rng.gen_range(1..=MAX_RAIDERS);
+        let ships: Option<Vec<_>> = (1..=amount)
+            .map(|i| self.ship_module_manager.build_ship(design, format!("Raider {id}-{i}")))
+            .collect();
+
+        if let Some(ships) = ships {
+            self.next_fleet_id += 1;
+            self.fleets.push(Fleet::new(
+                id,
+                format!("Pirate raid #{id}"),
+                PIRATE_OWNER.to_string(),
+                location.clone(),
+                ships,
+            ));
+            if self.get_player().get_knowledge().knows_body(&location) {
+                self.event_log.push(self.date, &FleetEvent::PirateRaid { location, ships: amount });
+            }
+        }
+    }
+
+    /// Runs an engagement at every location where hostile fleets meet, then removes the
+    /// destroyed ships and sends away the pirates who have nobody left to fight
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The locations where fighting happened
+    fn resolve_combat(&mut self) -> Vec<String> {
+        let mut engaged = Vec::<String>::new();
+        let modifiers = self.get_owner_modifiers();
+        let accuracy: HashMap<u32, f32> = self.empires.iter()
+            .flat_map(|e| e.get_personnel().get_officers().iter())
+            .filter_map(|o| match o.get_assignment() {
+                Some(Assignment::Fleet(fleet)) => Some((*fleet, o.get_accuracy_multiplier())),
+                _ => None,
+            })
+            .collect();
+
+        let mut locations: Vec<String> = self.fleets.iter()
+            .filter(|f| !f.is_in_transit())
+            .map(|f| f.get_location().clone())
+            .collect();
+        locations.sort();
+        locations.dedup();
+
+        for location in locations.iter() {
+            let mut fleets: Vec<&mut Fleet> = self.fleets.iter_mut()
+                .filter(|f| f.get_location() == location && !f.is_in_transit())
+                .collect();
+
+            let diplomacy = &self.diplomacy;
+            if let Some(report) = resolve_engagement(
+                location,
+                self.date,
+                &mut fleets,
+                |first, second| diplomacy.are_hostile(first, second),
+                &modifiers,
+                &accuracy,
+                &mut self.rng,
+            ) {
+                engaged.push(location.clone());
+                for first in report.sides.iter() {
+                    for second in report.sides.iter().filter(|s| s.owner != first.owner) {
+                        if self.empires.iter().any(|e| *e.get_id() == second.owner) {
+                            self.diplomacy.record_incident(
+                                &first.owner,
+                                &second.owner,
+                                Incident::Battle,
+                            );
+                        }
+                    }
+                }
+                let is_witnessed = report.sides.iter().any(|s| s.owner == PLAYER_OWNER)
+                    || self.empires[0].get_knowledge().knows_body(location);
+                if is_witnessed {
+                    self.event_log.push(self.date, &report);
+                    self.combat_reports.push(report);
+                }
+            }
+        }
+
+        self.fleets.iter_mut().for_each(|f| { f.remove_destroyed(); });
+        self.fleets.retain(|f| !f.is_empty());
+
+        for location in locations {
+            let at_location: Vec<&Fleet> = self.fleets.iter()
+                .filter(|f| *f.get_location() == location && !f.is_in_transit())
+                .collect();
+            let has_pirates = at_location.iter().any(|f| f.get_owner() == PIRATE_OWNER);
+            let is_opposed = at_location.iter().any(|f| {
+                self.diplomacy.are_hostile(PIRATE_OWNER, f.get_owner())
+            });
+
+            if has_pirates && !is_opposed {
+                self.fleets.retain(|f| {
+                    *f.get_location() != location || f.get_owner() != PIRATE_OWNER
+                });
+                if self.get_player().get_knowledge().knows_body(&location) {
+                    self.event_log.push(self.date, &FleetEvent::PiratesWithdrew { location });
+                }
+            }
+        }
+
+        engaged
+    }
+
+    /// Lets the fleets with survey sensors survey the planet they orbit. A fleet which has nothing
+    /// left to survey where it is heads for the closest known planet it can survey.
+    fn update_surveys(&mut self) {
+        let system = &self.systems[0];
+        let planets = system.get_satellites();
+        let mut completed = Vec::<(String, String, SurveyKind)>::new();
+
+        for fleet in self.fleets.iter_mut().filter(|f| !f.is_in_transit()) {
+            let kinds: Vec<SurveyKind> = [SurveyKind::Gravitational, SurveyKind::Geological]
+                .into_iter()
+                .filter(|k| fleet.get_survey_points(*k) > 0)
+                .collect();
+            if kinds.is_empty() {
+                continue;
+            }
+
+            let owner = fleet.get_owner().clone();
+            let location = fleet.get_location().clone();
+            let speed = Self::get_fleet_speed(&self.empires, fleet);
+            let knowledge = match self.empires.iter_mut().find(|e| *e.get_id() == owner) {
+                Some(empire) => empire.get_knowledge_mut(),
+                None => continue,
+            };
+            let needs_survey = |name: &str| {
+                kinds.iter().any(|k| !knowledge.get_survey(name).is_complete(*k))
+            };
+
+            if planets.iter().any(|p| p.get_name() == location) && needs_survey(&location) {
+                for kind in kinds.iter() {
+                    let points = fleet.get_survey_points(*kind);
+                    if knowledge.add_survey_progress(&location, *kind, points) {
+                        completed.push((owner.clone(), location.clone(), *kind));
+                    }
+                }
+                continue;
+            }
+
+            let here = match system.get_body_position(&location) {
+                Some(position) => position,
+                None => continue,
+            };
+            let distance_to = |p: &Planet| {
+                let (x, y) = p.get_position();
+                (x - here.0).hypot(y - here.1)
+            };
+            let target = planets.iter()
+                .filter(|p| knowledge.knows_body(&p.get_name()) && needs_survey(&p.get_name()))
+                .min_by(|a, b| distance_to(a).total_cmp(&distance_to(b)));
+
+            if let Some(planet) = target {
+                let days = (distance_to(planet) / speed).ceil() as u32;
+                fleet.set_course(planet.get_name(), days);
+            }
+        }
+
+        for (owner, body, kind) in completed {
+            let jump_points = match kind {
+                SurveyKind::Gravitational => self.reveal_surroundings(&owner, &body),
+                SurveyKind::Geological => 0,
+            };
+            if owner == PLAYER_OWNER {
+                if self.options.random_events {
+                    self.event_manager.roll_survey(&body, kind, &mut self.rng);
+                }
+                self.event_log.push(self.date, &FleetEvent::SurveyCompleted {
+                    location: body.clone(),
+                    survey: kind,
+                });
+                if jump_points > 0 {
+                    self.event_log.push(self.date, &FleetEvent::JumpPointsFound {
+                        location: body,
+                        amount: jump_points,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Reveals the planets and the jump points mapped by a gravitational survey of a planet,
+    /// which reaches the orbits up to `SURVEY_REACH` times further from the star
+    ///
+    /// # Returns
+    /// * `u32` - Amount of newly found jump points
+    fn reveal_surroundings(&mut self, owner: &str, body: &str) -> u32 {
+        let system = &self.systems[0];
+        let reach = match system.get_body_position(body) {
+            Some((x, y)) => x.hypot(y) * SURVEY_REACH,
+            None => return 0,
+        };
+        let knowledge = match self.empires.iter_mut().find(|e| e.get_id() == owner) {
+            Some(empire) => empire.get_knowledge_mut(),
+            None => return 0,
+        };
+
+        system.get_satellites().into_iter()
+            .filter(|p| {
+                let (x, y) = p.get_position();
+                x.hypot(y) <= reach
+            })
+            .for_each(|p| knowledge.learn_body(p.get_name()));
+
+        let mut found = 0;
+        for jump_point in system.get_jump_points() {
+            let (x, y) = jump_point.get_position();
+            if x.hypot(y) <= reach && !knowledge.knows_jump_point(&jump_point.get_name()) {
+                knowledge.learn_jump_point(jump_point.get_name());
+                found += 1;
+            }
+        }
+        found
+    }
+
+    /// Collects the bonuses of the researched technologies of every empire
+    fn get_owner_modifiers(&self) -> HashMap<String, TechModifiers> {
+        self.empires.iter()
+            .map(|e| (e.get_id().clone(), e.get_modifiers()))
+            .collect()
+    }
+
+    /// Lets every empire look around with the sensors of its fleets and colonies, updating what
+    /// it knows about the planets and the fleets of the system
+    fn update_sensors(&mut self) {
+        let system = &self.systems[0];
+        let modifiers = self.get_owner_modifiers();
+        let no_modifiers = TechModifiers::default();
+
+        let own_sources: Vec<Vec<SensorSource>> = self.empires.iter().map(|empire| {
+            let fleets = self.fleets.iter()
+                .filter(|f| f.get_owner() == empire.get_id())
+                .filter_map(|f| {
+                    system.get_body_position(f.get_location()).map(|position| SensorSource {
+                        position,
+                        strength: f.get_sensor_strength(),
+                    })
+                });
+            let colonies = empire.get_colonies().iter().filter_map(|c| {
+                system.get_body_position(&c.get_name()).map(|position| SensorSource {
+                    position,
+                    strength: c.get_sensor_strength(),
+                })
+            });
+            fleets.chain(colonies).collect()
+        }).collect();
+        let colonies: Vec<(String, String)> = self.empires.iter()
+            .flat_map(|e| e.get_colonies().iter().map(|c| (e.get_id().clone(), c.get_name())))
+            .collect();
+
+        let ids: Vec<String> = self.empires.iter().map(|e| e.get_id().clone()).collect();
+
+        for empire in self.empires.iter_mut() {
+            let owner = empire.get_id().clone();
+            let allies = self.diplomacy.get_allies(&owner);
+            let sources: Vec<SensorSource> = ids.iter().zip(own_sources.iter())
+                .filter(|(id, _)| **id == owner || allies.contains(id))
+                .flat_map(|(_, sources)| sources.iter().cloned())
+                .collect();
+
+            let knowledge = empire.get_knowledge_mut();
+            system.get_satellites().into_iter()
+                .filter(|p| is_detected(&sources, p.get_position(), p.get_signature()))
+                .for_each(|p| knowledge.learn_body(p.get_name()));
+            for (owner, colony) in colonies.iter() {
+                if knowledge.knows_body(colony) {
+                    knowledge.meet(owner.clone());
+                }
+            }
+
+            let detected = self.fleets.iter().filter_map(|f| {
+                let position = system.get_body_position(f.get_location())?;
+                let signature = f.get_signature(
+                    modifiers.get(f.get_owner()).unwrap_or(&no_modifiers)
+                );
+                let is_visible = *f.get_owner() == owner
+                    || allies.contains(f.get_owner())
+                    || is_detected(&sources, position, signature);
+                is_visible.then(|| Contact::new(f, position, self.date))
+            }).collect::<Vec<Contact>>();
+            self.fleets.iter()
+                .filter(|f| detected.iter().any(|c| c.get_fleet_id() == f.get_id()))
+                .for_each(|f| knowledge.meet(f.get_owner().clone()));
+            knowledge.update_contacts(detected, self.date);
         }
     }
 
+    /// Returns the player's view of the system: the star, the detected planets along with how
+    /// much they were surveyed, and the found jump points
+    pub fn get_known_system(&self) -> SolarSystem {
+        if !self.options.fog_of_war {
+            return self.systems[0].with_known_bodies(|_| Some(BodySurvey::complete()), |_| true)
+        }
+        let knowledge = self.get_player().get_knowledge();
+        self.systems[0].with_known_bodies(
+            |p| knowledge.knows_body(&p.get_name()).then(|| knowledge.get_survey(&p.get_name())),
+            |j| knowledge.knows_jump_point(&j.get_name()),
+        )
+    }
+
+    /// Returns the fleets the player knows about, including the last known positions of the
+    /// lost ones
+    pub fn get_sensor_contacts(&self) -> Vec<Contact> {
+        if !self.options.fog_of_war {
+            return self.fleets.iter().filter_map(|f| {
+                self.systems[0].get_body_position(f.get_location())
+                    .map(|position| Contact::new(f, position, self.date))
+            }).collect()
+        }
+        self.get_player().get_knowledge().get_contacts()
+    }
+
+    /// Tells whether the player can see a fleet right now
+    fn is_fleet_visible(&self, fleet: &Fleet) -> bool {
+        !self.options.fog_of_war
+            || fleet.get_owner() == PLAYER_OWNER
+            || self.get_player().get_knowledge().is_tracked(fleet.get_id())
+    }
+
+    /// Returns the fleets of every empire
+    pub fn get_all_fleets(&self) -> &[Fleet] {
+        &self.fleets
+    }
+
+    pub fn get_fleets(&self) -> Vec<(u32, String, Color)> {
+        self.fleets.iter()
+            .filter(|f| self.is_fleet_visible(f))
+            .map(|f| (f.get_id(), f.get_name(), f.get_menu_color()))
+            .collect()
+    }
+
+    pub fn get_fleet_info(&self, id: u32) -> Vec<Vec<String>> {
+        self.fleets.iter()
+            .find(|f| f.get_id() == id && self.is_fleet_visible(f))
+            .map(|f| f.get_properties())
+            .unwrap_or_default()
+    }
+
+    /// Returns the details of up to `amount` latest combat reports, newest first
+    pub fn get_combat_reports(&self, amount: usize) -> Vec<Vec<String>> {
+        self.combat_reports.iter().rev().take(amount).map(|r| {
+            r.get_properties().into_iter().flatten().collect()
+        }).collect()
+    }
+
     fn update_orbits(&mut self) {
         for system in self.systems.as_mut_slice() {
             system.update_orbits();
@@ -114,27 +1208,85 @@ impl GameState {
     }
 
     pub fn get_colonies(&self) -> Vec<Colony> {
-        self.colonies.clone()
+        self.get_player().get_colonies().clone()
     }
 
     pub fn start_construction(&mut self, colony: Colony, building: BuildingType) {
-        self.colonies.iter_mut().find(|c| c == &&colony).unwrap()
+        self.get_player_mut().get_colonies_mut().iter_mut().find(|c| c == &&colony).unwrap()
             .start_construction(building)
     }
 
     pub fn get_colony_by_name(&self, name: String) -> Option<Colony> {
-        self.colonies.iter().find(|c| c.get_name() == name).cloned()
+        self.get_player().get_colonies().iter().find(|c| c.get_name() == name).cloned()
     }
 
     pub fn get_ship_module_types(&self) -> Vec<ShipModuleType> {
         self.ship_module_manager.get_ship_module_types()
     }
 
-    // pub fn get_ship_module_type_by_name(&self, name: String) -> ShipModuleType {
-    //     self.ship_module_manager.get_ship_module_type_by_name(name)
-    // }
-    //
-    // pub fn get_ship_modules_by_type<T: ShipModule>(&self, module_type: ShipModuleType) -> Vec<T> {
-    //     self.ship_module_manager.get_ship_modules_by_type(module_type)
-    // }
+    pub fn get_ship_modules_by_type(&self, module_type: String) -> Vec<(String, Color)> {
+        self.ship_module_manager.get_ship_modules_by_type(
+            self.ship_module_manager.get_ship_module_type_by_name(module_type),
+            &self.get_player().get_modifiers(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::colony::building::BuildingType;
+    use crate::game::game_options::GameOptions;
+    use crate::game::game_state::GameState;
+
+    #[test]
+    fn test_computer_empires_play_headless() {
+        let mut state = GameState::new(GameOptions {
+            pirate_raids: false,
+            computer_empires: 2,
+            seed: Some(7),
+            ..GameOptions::default()
+        }).unwrap();
+        assert_eq!(state.empires.len(), 3);
+
+        for _ in 0..6 {
+            state.tick();
+        }
+        for empire in state.empires.iter().filter(|e| !e.is_player()) {
+            assert!(empire.get_research_manager().is_researching());
+            assert!(empire.get_colonies().iter().all(|c| !c.get_construction().is_empty()));
+        }
+    }
+
+    #[test]
+    fn test_seeded_games_play_the_same() {
+        let play = || {
+            let mut state = GameState::new(GameOptions {
+                computer_empires: 2,
+                seed: Some(11),
+                ..GameOptions::default()
+            }).unwrap();
+            for _ in 0..3 {
+                let capital = state.get_colonies()[0].clone();
+                state.start_construction(capital, BuildingType::Mine);
+            }
+            for _ in 0..60 {
+                state.tick();
+            }
+            state
+        };
+        let first = play();
+        let second = play();
+
+        assert_eq!(first.systems, second.systems);
+        let mined: u32 = first.empires.iter()
+            .flat_map(|e| e.get_colonies().iter())
+            .flat_map(|c| c.get_resources().into_iter().map(|(_, amount)| amount))
+            .sum();
+        assert!(mined > 0);
+        for (first, second) in first.empires.iter().zip(second.empires.iter()) {
+            assert_eq!(first.get_colonies(), second.get_colonies());
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use rand::Rng;
+
+use crate::game::ground::ground_forces::GroundForces;
+
+/// Maximum amount of rounds of a ground battle, the invaders who haven't won by then surrender
+const MAX_ROUNDS: u32 = 10;
+/// Share of the landing troops every planetary defense battery shoots down
+const LANDING_LOSSES_PER_BATTERY: f32 = 0.1;
+/// Planetary defenses can not shoot down more than this share of the landing troops
+const MAX_LANDING_LOSSES: f32 = 0.5;
+/// How many times the defenders hit harder than the invaders, as they fight from prepared
+/// positions
+const DEFENDER_BONUS: f32 = 1.25;
+
+/// `GroundBattle` is the outcome of an invasion
+///
+/// **Fields**
+/// - is_won (`bool`) - `true` if the invaders took the colony
+/// - landed (`u32`) - amount of invading units that made it to the surface
+/// - attackers_lost (`u32`) - amount of invading units destroyed, including the ones shot
+///   down while landing
+/// - defenders_lost (`u32`) - amount of defending units destroyed
+pub struct GroundBattle {
+    pub is_won: bool,
+    pub landed: u32,
+    pub attackers_lost: u32,
+    pub defenders_lost: u32,
+}
+
+/// Resolves an invasion of a colony. The planetary defenses shoot down a share of the landing
+/// troops, then both sides exchange fire until one of them is destroyed or the time runs out.
+///
+/// # Arguments
+/// * `attackers` - The landing troops, what is left of them after the battle
+/// * `defenders` - The troops of the colony, what is left of them after the battle
+/// * `batteries` - Amount of planetary defense batteries of the colony
+/// * `rng` - The random number generator of the game
+///
+/// # Returns
+/// * `GroundBattle` - The outcome of the battle
+pub fn resolve_invasion(
+    attackers: &mut GroundForces,
+    defenders: &mut GroundForces,
+    batteries: u32,
+    rng: &mut impl Rng,
+) -> GroundBattle {
+    let sent = attackers.get_total();
+    let defending = defenders.get_total();
+    attackers.take_losses((batteries as f32 * LANDING_LOSSES_PER_BATTERY).min(MAX_LANDING_LOSSES));
+    let landed = attackers.get_total();
+
+    for _ in 0..MAX_ROUNDS {
+        if attackers.is_empty() || defenders.is_empty() {
+            break;
+        }
+        let attack = attackers.get_attack() as f32 * rng.gen_range(0.5..1.5);
+        let defense = defenders.get_attack() as f32 * DEFENDER_BONUS * rng.gen_range(0.5..1.5);
+        defenders.take_damage(attack.round() as u32);
+        attackers.take_damage(defense.round() as u32);
+    }
+
+    let is_won = defenders.is_empty() && !attackers.is_empty();
+    if !is_won {
+        *attackers = GroundForces::default();
+    }
+    GroundBattle {
+        is_won,
+        landed,
+        attackers_lost: sent - attackers.get_total(),
+        defenders_lost: defending - defenders.get_total(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::game::ground::ground_combat::resolve_invasion;
+    use crate::game::ground::ground_forces::GroundForces;
+    use crate::game::ground::ground_unit::GroundUnitType;
+
+    #[test]
+    fn test_defenses_and_garrisons_hold_off_small_invasions() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut attackers = GroundForces::default();
+        attackers.add(GroundUnitType::Armor, 6);
+        attackers.add(GroundUnitType::Infantry, 10);
+        let mut defenders = GroundForces::default();
+        defenders.add(GroundUnitType::Garrison, 4);
+        let battle = resolve_invasion(&mut attackers, &mut defenders, 0, &mut rng);
+        assert!(battle.is_won);
+        assert_eq!(battle.landed, 16);
+        assert!(defenders.is_empty());
+        assert!(!attackers.is_empty());
+
+        let mut attackers = GroundForces::default();
+        attackers.add(GroundUnitType::Infantry, 4);
+        let mut defenders = GroundForces::default();
+        defenders.add(GroundUnitType::Garrison, 10);
+        let battle = resolve_invasion(&mut attackers, &mut defenders, 5, &mut rng);
+        assert!(!battle.is_won);
+        assert_eq!(battle.landed, 2);
+        assert_eq!(battle.attackers_lost, 4);
+        assert!(attackers.is_empty());
+    }
+}
\ No newline at end of file
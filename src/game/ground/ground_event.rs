@@ -0,0 +1,38 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// `GroundEvent` is the outcome of a fight on the surface of a planet
+pub enum GroundEvent {
+    /// An empire took a colony from another one
+    ColonyCaptured { colony: String, by: String, from: String },
+    /// The troops of a colony threw the invaders back
+    InvasionRepelled { colony: String, attacker: String, defender: String },
+}
+
+impl Displayable for GroundEvent {
+    fn get_name(&self) -> String {
+        match self {
+            GroundEvent::ColonyCaptured { colony, by, from } => {
+                format!("{by} captured {colony} from {from}")
+            }
+            GroundEvent::InvasionRepelled { colony, attacker, defender } => {
+                format!("{defender} repelled the invasion of {colony} by {attacker}")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            GroundEvent::ColonyCaptured { .. } => Color::LightRed,
+            GroundEvent::InvasionRepelled { .. } => Color::LightYellow,
+        }
+    }
+}
+
+impl GameEvent for GroundEvent {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Combat
+    }
+}
\ No newline at end of file
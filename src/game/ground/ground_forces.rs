@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::ground::ground_unit::GroundUnitType;
+
+/// `GroundForces` are the troops stationed in a colony or carried by a ship
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GroundForces {
+    units: BTreeMap<GroundUnitType, u32>,
+}
+
+impl GroundForces {
+    pub fn get_amount(&self, unit: GroundUnitType) -> u32 {
+        *self.units.get(&unit).unwrap_or(&0)
+    }
+
+    pub fn add(&mut self, unit: GroundUnitType, amount: u32) {
+        if amount > 0 {
+            *self.units.entry(unit).or_insert(0) += amount;
+        }
+    }
+
+    /// Removes units of a type, as many as there are
+    ///
+    /// # Returns
+    /// * `u32` - Amount of units actually removed
+    pub fn remove(&mut self, unit: GroundUnitType, amount: u32) -> u32 {
+        let removed = amount.min(self.get_amount(unit));
+        if removed == self.get_amount(unit) {
+            self.units.remove(&unit);
+        } else {
+            *self.units.get_mut(&unit).unwrap() -= removed;
+        }
+        removed
+    }
+
+    /// Moves all the units of other forces into these
+    pub fn merge(&mut self, other: GroundForces) {
+        other.units.into_iter().for_each(|(unit, amount)| self.add(unit, amount));
+    }
+
+    /// Takes out the units able to board ships, up to a limit
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum amount of units to take out
+    pub fn split_embarking(&mut self, limit: u32) -> GroundForces {
+        let mut embarking = GroundForces::default();
+        let mut left = limit;
+        for unit in GroundUnitType::get_variants().into_iter().rev().filter(|u| u.can_embark()) {
+            let amount = self.remove(unit, left);
+            embarking.add(unit, amount);
+            left -= amount;
+        }
+        embarking
+    }
+
+    /// Destroys units, the weakest first, until there are no more than a limit left
+    pub fn truncate(&mut self, limit: u32) {
+        for unit in GroundUnitType::get_variants() {
+            let excess = self.get_total().saturating_sub(limit);
+            self.remove(unit, excess);
+        }
+    }
+
+    pub fn get_total(&self) -> u32 {
+        self.units.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.get_total() == 0
+    }
+
+    pub fn get_attack(&self) -> u32 {
+        self.units.iter().map(|(unit, amount)| unit.get_attack() * amount).sum()
+    }
+
+    /// Destroys units, the weakest first, as long as the damage is enough to destroy them
+    ///
+    /// # Returns
+    /// * `u32` - Amount of destroyed units
+    pub fn take_damage(&mut self, damage: u32) -> u32 {
+        let mut damage = damage;
+        let mut destroyed = 0;
+        for unit in GroundUnitType::get_variants() {
+            let amount = (damage / unit.get_defense()).min(self.get_amount(unit));
+            self.remove(unit, amount);
+            damage -= amount * unit.get_defense();
+            destroyed += amount;
+        }
+        destroyed
+    }
+
+    /// Destroys a share of every type of units, rounded down
+    pub fn take_losses(&mut self, share: f32) {
+        for unit in GroundUnitType::get_variants() {
+            let amount = (self.get_amount(unit) as f32 * share.clamp(0.0, 1.0)) as u32;
+            self.remove(unit, amount);
+        }
+    }
+
+    /// Describes the forces in a single line, e.g. "3 Infantry, 1 Armor"
+    pub fn get_description(&self) -> String {
+        if self.is_empty() {
+            return String::from("none");
+        }
+        self.units.iter()
+            .map(|(unit, amount)| format!("{} {}", amount, unit.get_name()))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
\ No newline at end of file
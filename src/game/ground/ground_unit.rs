@@ -0,0 +1,105 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::resource::resource::{ResourceTransaction, ResourceType};
+
+/// `GroundUnitType` is a kind of troops fighting on the surface of a planet
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum GroundUnitType {
+    /// Militia trained to hold its own colony, it never boards a ship
+    Garrison,
+    /// Regular soldiers, cheap and quick to train
+    Infantry,
+    /// Tanks and fighting vehicles
+    Armor,
+}
+
+impl GroundUnitType {
+    /// Returns every unit type, in the order the losses are taken
+    pub fn get_variants() -> Vec<GroundUnitType> {
+        vec![GroundUnitType::Garrison, GroundUnitType::Infantry, GroundUnitType::Armor]
+    }
+
+    /// Returns the damage the unit deals every round of a ground battle
+    pub fn get_attack(&self) -> u32 {
+        match self {
+            GroundUnitType::Garrison => 1,
+            GroundUnitType::Infantry => 2,
+            GroundUnitType::Armor => 5,
+        }
+    }
+
+    /// Returns the damage needed to destroy the unit
+    pub fn get_defense(&self) -> u32 {
+        match self {
+            GroundUnitType::Garrison => 4,
+            GroundUnitType::Infantry => 2,
+            GroundUnitType::Armor => 4,
+        }
+    }
+
+    /// Returns `true` if the unit can board troop transports
+    pub fn can_embark(&self) -> bool {
+        *self != GroundUnitType::Garrison
+    }
+
+    /// Returns the building training the unit
+    pub fn get_training_building(&self) -> BuildingType {
+        match self {
+            GroundUnitType::Garrison => BuildingType::Barracks,
+            GroundUnitType::Infantry => BuildingType::Barracks,
+            GroundUnitType::Armor => BuildingType::VehicleDepot,
+        }
+    }
+
+    /// Returns the amount of days a single training building needs to train the unit
+    pub fn get_training_time(&self) -> u32 {
+        match self {
+            GroundUnitType::Garrison => 10,
+            GroundUnitType::Infantry => 15,
+            GroundUnitType::Armor => 30,
+        }
+    }
+
+    /// Returns the resources the colony pays when the unit finishes its training
+    pub fn get_cost(&self) -> Vec<ResourceTransaction> {
+        match self {
+            GroundUnitType::Garrison => vec![
+                ResourceTransaction::new(ResourceType::PRLightMetals, -5),
+            ],
+            GroundUnitType::Infantry => vec![
+                ResourceTransaction::new(ResourceType::PRLightMetals, -10),
+            ],
+            GroundUnitType::Armor => vec![
+                ResourceTransaction::new(ResourceType::PRHeavyMetals, -30),
+                ResourceTransaction::new(ResourceType::SRElectronics, -2),
+            ],
+        }
+    }
+}
+
+impl From<GroundUnitType> for String {
+    fn from(value: GroundUnitType) -> Self {
+        match value {
+            GroundUnitType::Garrison => String::from("Garrison"),
+            GroundUnitType::Infantry => String::from("Infantry"),
+            GroundUnitType::Armor => String::from("Armor"),
+        }
+    }
+}
+
+impl Displayable for GroundUnitType {
+    fn get_name(&self) -> String {
+        String::from(*self)
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            GroundUnitType::Garrison => Color::Gray,
+            GroundUnitType::Infantry => Color::LightGreen,
+            GroundUnitType::Armor => Color::LightYellow,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,96 @@
+use rand::Rng;
+
+use crate::game::diplomacy::diplomacy_manager::DiplomacyManager;
+use crate::game::diplomacy::incident::Incident;
+use crate::game::empires::empire::{Empire, get_empire_name};
+use crate::game::event_log::EventLog;
+use crate::game::fleets::fleet::{Fleet, PLAYER_OWNER};
+use crate::game::ground::ground_combat::resolve_invasion;
+use crate::game::ground::ground_event::GroundEvent;
+
+/// Lands the troops of the fleets orbiting the colonies of their enemies, once no armed enemy
+/// ship is left in orbit. The invaders who win take the colony over.
+///
+/// # Arguments
+/// * `fleets` - Every fleet of the game, the fleets which land their troops are emptied
+/// * `empires` - Every empire of the game, the captured colonies change hands
+/// * `diplomacy` - Relations of the empires, only enemies are invaded
+/// * `event_log` - Where the player hears about the invasions witnessed
+/// * `date` - The current date
+/// * `rng` - Random number generator of the game
+pub fn resolve_invasions(
+    fleets: &mut [Fleet],
+    empires: &mut [Empire],
+    diplomacy: &mut DiplomacyManager,
+    event_log: &mut EventLog,
+    date: u64,
+    rng: &mut impl Rng,
+) {
+    let landings: Vec<(u32, String, String)> = fleets.iter()
+        .filter(|f| !f.is_in_transit() && f.get_troop_count() > 0)
+        .map(|f| (f.get_id(), f.get_owner().clone(), f.get_location().clone()))
+        .collect();
+
+    for (fleet, attacker, location) in landings {
+        let defender = match empires.iter().find(|e| e.has_colony(&location)) {
+            Some(empire) => empire.get_id().clone(),
+            None => continue,
+        };
+        let is_contested = fleets.iter().any(|f| {
+            *f.get_location() == location
+                && !f.is_in_transit()
+                && diplomacy.are_hostile(&attacker, f.get_owner())
+                && f.get_ships().iter().any(|s| !s.get_weapons().is_empty())
+        });
+        if !diplomacy.are_hostile(&attacker, &defender) || is_contested {
+            continue;
+        }
+
+        let mut invaders = match fleets.iter_mut().find(|f| f.get_id() == fleet) {
+            Some(fleet) => fleet.disembark(),
+            None => continue,
+        };
+        let colony = match empires.iter_mut()
+            .find(|e| *e.get_id() == defender)
+            .and_then(|e| e.get_colony_mut(&location)) {
+            Some(colony) => colony,
+            None => continue,
+        };
+        let batteries = colony.get_defense_batteries();
+        let battle = resolve_invasion(
+            &mut invaders,
+            colony.get_ground_forces_mut(),
+            batteries,
+            rng,
+        );
+        diplomacy.record_incident(&defender, &attacker, Incident::Battle);
+
+        let is_witnessed = attacker == PLAYER_OWNER
+            || defender == PLAYER_OWNER
+            || empires.iter().any(|e| e.is_player() && e.get_knowledge().knows_body(&location));
+        let event = if battle.is_won {
+            let mut colony = empires.iter_mut()
+                .find(|e| *e.get_id() == defender)
+                .and_then(|e| e.remove_colony(&location))
+                .unwrap();
+            colony.capture(invaders, rng);
+            if let Some(empire) = empires.iter_mut().find(|e| *e.get_id() == attacker) {
+                empire.add_colony(colony);
+            }
+            GroundEvent::ColonyCaptured {
+                colony: location,
+                by: get_empire_name(empires, &attacker),
+                from: get_empire_name(empires, &defender),
+            }
+        } else {
+            GroundEvent::InvasionRepelled {
+                colony: location,
+                attacker: get_empire_name(empires, &attacker),
+                defender: get_empire_name(empires, &defender),
+            }
+        };
+        if is_witnessed {
+            event_log.push(date, &event);
+        }
+    }
+}
\ No newline at end of file
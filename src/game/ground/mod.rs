@@ -0,0 +1,5 @@
+pub mod ground_unit;
+pub mod ground_forces;
+pub mod ground_combat;
+pub mod ground_event;
+pub mod invasion;
\ No newline at end of file
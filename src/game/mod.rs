@@ -5,3 +5,18 @@ pub mod game_state;
 pub mod colony;
 mod resource;
 pub mod shipbuilding;
+pub mod game_options;
+pub mod event_log;
+pub mod fleets;
+pub mod combat;
+
+pub mod sensors;
+pub mod exploration;
+pub mod empires;
+pub mod diplomacy;
+pub mod ground;
+pub mod personnel;
+pub mod events;
+pub mod simulation;
+pub mod scripting;
+pub mod mods;
\ No newline at end of file
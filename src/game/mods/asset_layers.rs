@@ -0,0 +1,219 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use color_eyre::eyre::Result;
+use serde_json::Value;
+
+use crate::game::mods::game_mod::{get_mods_dir, GameMod, resolve_mods};
+
+/// Directory of the assets of the base game, relative to the working directory
+pub const BASE_ASSETS_PATH: &str = "assets";
+
+/// `AssetError` describes why an asset file could not be loaded
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssetError {
+    /// The file of a layer could not be read
+    Io { path: String, message: String },
+    /// The file of a layer is not valid JSON
+    Parse { path: String, message: String },
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::Io { path, message } => {
+                write!(f, "{path}: unable to read file: {message}")
+            }
+            AssetError::Parse { path, message } => {
+                write!(f, "{path}: unable to parse file: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+/// `AssetLayers` stacks the assets of the enabled mods on top of the ones of the base game. A file
+/// of a mod does not replace the file of the base game, its records are merged into it instead
+///
+/// **Fields**
+/// - mods (`Vec<GameMod>`) - the enabled mods, every one overrides the ones before it
+#[derive(Clone, Debug, Default)]
+pub struct AssetLayers {
+    mods: Vec<GameMod>,
+}
+
+/// Finds the key a record is merged by, its `id` or, for records without one, its `name`
+fn get_record_key(record: &Value) -> Option<&str> {
+    record.get("id").or_else(|| record.get("name")).and_then(|k| k.as_str())
+}
+
+/// Merges the contents of an asset file of a mod into the ones of the layers below it. Records of
+/// a list replace the records with the same key and the others are appended, keys of an object
+/// replace the same keys. Anything else replaces the contents below
+pub fn merge_records(base: Value, layer: Value) -> Value {
+    match (base, layer) {
+        (Value::Array(mut records), Value::Array(layer_records)) => {
+            for record in layer_records {
+                let position = get_record_key(&record).and_then(|key| {
+                    records.iter().position(|r| get_record_key(r) == Some(key))
+                });
+                match position {
+                    Some(index) => records[index] = record,
+                    None => records.push(record),
+                }
+            }
+            Value::Array(records)
+        }
+        (Value::Object(mut entries), Value::Object(layer_entries)) => {
+            entries.extend(layer_entries);
+            Value::Object(entries)
+        }
+        (_, layer) => layer,
+    }
+}
+
+impl AssetLayers {
+    pub fn new(mods: Vec<GameMod>) -> Self {
+        Self { mods }
+    }
+
+    /// Layers the assets of the installed mods over the ones of the base game
+    ///
+    /// # Arguments
+    /// * `ids` - Ids of the enabled mods, in the order their assets are layered
+    ///
+    /// # Returns
+    /// * `Result<Self>` - The layers, or an error if one of the mods is not installed
+    pub fn load(ids: &[String]) -> Result<Self> {
+        Ok(Self::new(resolve_mods(&get_mods_dir(), ids)?))
+    }
+
+    pub fn get_mods(&self) -> &[GameMod] {
+        &self.mods
+    }
+
+    /// Returns the id and the version of every enabled mod, e.g. `more-stars 1.2.0`
+    pub fn get_labels(&self) -> Vec<String> {
+        self.mods.iter().map(|m| m.get_label()).collect()
+    }
+
+    /// Returns the paths of an asset in every mod that has it, in the order of the mods
+    fn get_mod_paths(&self, asset: &str) -> Vec<PathBuf> {
+        self.mods.iter()
+            .map(|m| m.get_assets_dir().join(asset))
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    /// Reads a JSON asset of the base game and merges the same file of every mod into it
+    ///
+    /// # Arguments
+    /// * `asset` - Path of the file, relative to the assets directory
+    ///
+    /// # Returns
+    /// * `Result<String, AssetError>` - The merged contents, or the first file that failed to load
+    pub fn read_records(&self, asset: &str) -> Result<String, AssetError> {
+        let base = PathBuf::from(BASE_ASSETS_PATH).join(asset);
+        let mut records = read_json(base)?;
+        for path in self.get_mod_paths(asset) {
+            records = merge_records(records, read_json(path)?);
+        }
+        Ok(records.to_string())
+    }
+
+    /// Reads a namelist of the base game and adds the names of every mod to it
+    ///
+    /// # Arguments
+    /// * `asset` - Path of the file, relative to the assets directory
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, AssetError>` - Every name once, the ones of the base game first
+    pub fn read_lines(&self, asset: &str) -> Result<Vec<String>, AssetError> {
+        let base = PathBuf::from(BASE_ASSETS_PATH).join(asset);
+        let mut names: Vec<String> = Vec::new();
+        for path in std::iter::once(base).chain(self.get_mod_paths(asset)) {
+            let contents = read_file(&path)?;
+            for name in contents.lines().map(|s| s.trim()).filter(|s| !s.is_empty()) {
+                if !names.iter().any(|n| n == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+}
+
+fn read_file(path: &PathBuf) -> Result<String, AssetError> {
+    std::fs::read_to_string(path).map_err(|e| AssetError::Io {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })
+}
+
+fn read_json(path: PathBuf) -> Result<Value, AssetError> {
+    serde_json::from_str(&read_file(&path)?).map_err(|e| AssetError::Parse {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use crate::game::mods::asset_layers::{AssetLayers, merge_records};
+    use crate::game::mods::game_mod::GameMod;
+
+    #[test]
+    fn test_records_are_merged_by_id() {
+        let base = json!([
+            { "id": "lasers", "cost": 10 },
+            { "id": "shields", "cost": 20 },
+            { "name": "Mass driver", "damage": 10 },
+        ]);
+        let layer = json!([
+            { "id": "shields", "cost": 5 },
+            { "name": "Mass driver", "damage": 12 },
+            { "id": "warp", "cost": 50 },
+        ]);
+
+        assert_eq!(merge_records(base, layer), json!([
+            { "id": "lasers", "cost": 10 },
+            { "id": "shields", "cost": 5 },
+            { "name": "Mass driver", "damage": 12 },
+            { "id": "warp", "cost": 50 },
+        ]));
+        assert_eq!(
+            merge_records(json!({ "a": 1, "b": 2 }), json!({ "b": 3 })),
+            json!({ "a": 1, "b": 3 }),
+        );
+    }
+
+    #[test]
+    fn test_mods_are_layered_over_the_base_game() {
+        let dir = std::env::temp_dir().join(format!("astray-mods-{}", std::process::id()));
+        let mod_dir = dir.join("more-stars");
+        std::fs::create_dir_all(mod_dir.join("assets/namelists")).unwrap();
+        std::fs::write(mod_dir.join("mod.json5"), r#"{ "name": "More stars" }"#).unwrap();
+        std::fs::write(mod_dir.join("assets/namelists/star_namelist.txt"), "Sol\nAlgol\n").unwrap();
+        std::fs::write(
+            mod_dir.join("assets/events.json5"),
+            r#"[{ "id": "more-stars-supernova" }]"#,
+        ).unwrap();
+
+        let base = AssetLayers::default();
+        let layers = AssetLayers::new(vec![GameMod::load_from_dir(&mod_dir).unwrap()]);
+        let base_names = base.read_lines("namelists/star_namelist.txt").unwrap();
+        let names = layers.read_lines("namelists/star_namelist.txt").unwrap();
+        let events = layers.read_records("events.json5").unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names.len(), base_names.len() + 2);
+        assert_eq!(names[base_names.len()..], [String::from("Sol"), String::from("Algol")]);
+        assert!(events.contains("more-stars-supernova"));
+        assert!(layers.read_records("no_such_asset.json5").is_err());
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::game::game_options::GameOptions;
+
+/// Name of the file describing a mod, in the directory of the mod
+pub const MANIFEST_FILE: &str = "mod.json5";
+/// Name of the file listing the mods enabled in the mod manager, in the mods directory
+pub const ENABLED_MODS_FILE: &str = "enabled.json5";
+
+/// Returns the directory the mods are installed to, a subdirectory of the data directory
+pub fn get_mods_dir() -> PathBuf {
+    crate::utils::get_data_dir().join("mods")
+}
+
+/// `ModManifest` is what a mod says about itself in its `mod.json5`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ModManifest {
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    description: String,
+}
+
+/// `GameMod` is a mod installed in the mods directory. Its `assets` directory mirrors the one of
+/// the game and its `scripts` directory holds scripts run along with the ones of the player
+///
+/// **Fields**
+/// - id (`String`) - name of the directory of the mod, the configuration refers to the mod by it
+/// - manifest (`ModManifest`) - name, version and description of the mod
+/// - path (`PathBuf`) - the directory of the mod
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameMod {
+    id: String,
+    manifest: ModManifest,
+    path: PathBuf,
+}
+
+impl GameMod {
+    /// Reads the manifest of the mod installed in a directory
+    pub fn load_from_dir(dir: &Path) -> Result<Self> {
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let contents = std::fs::read_to_string(&manifest_path)
+            .wrap_err_with(|| format!("{}: unable to read file", manifest_path.display()))?;
+        let manifest = serde_json::from_str(&contents)
+            .wrap_err_with(|| format!("{}: unable to parse file", manifest_path.display()))?;
+
+        Ok(Self {
+            id: dir.file_name().unwrap_or_default().to_string_lossy().to_string(),
+            manifest,
+            path: dir.to_path_buf(),
+        })
+    }
+
+    pub fn get_id(&self) -> String { self.id.clone() }
+    pub fn get_name(&self) -> String { self.manifest.name.clone() }
+    pub fn get_version(&self) -> String { self.manifest.version.clone() }
+    pub fn get_description(&self) -> String { self.manifest.description.clone() }
+
+    pub fn get_assets_dir(&self) -> PathBuf {
+        self.path.join("assets")
+    }
+
+    pub fn get_scripts_dir(&self) -> PathBuf {
+        self.path.join("scripts")
+    }
+
+    /// Returns the id and the version of the mod, as recorded in the game
+    pub fn get_label(&self) -> String {
+        if self.manifest.version.is_empty() {
+            self.id.clone()
+        } else {
+            format!("{} {}", self.id, self.manifest.version)
+        }
+    }
+}
+
+/// Lists the mods installed in a directory, ordered by their ids. Subdirectories without a
+/// manifest are skipped, and nothing is installed if the directory does not exist
+pub fn get_available_mods(dir: &Path) -> Result<Vec<GameMod>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new())
+    }
+
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .wrap_err_with(|| format!("{}: unable to read directory", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.join(MANIFEST_FILE).is_file())
+        .collect();
+    paths.sort();
+
+    paths.iter().map(|path| GameMod::load_from_dir(path)).collect()
+}
+
+/// Finds the enabled mods among the installed ones
+///
+/// # Arguments
+/// * `dir` - The mods directory
+/// * `ids` - Ids of the enabled mods, in the order their assets are layered
+///
+/// # Returns
+/// * `Result<Vec<GameMod>>` - The enabled mods in the same order, or an error if one of them is
+///   not installed
+pub fn resolve_mods(dir: &Path, ids: &[String]) -> Result<Vec<GameMod>> {
+    ids.iter()
+        .map(|id| {
+            let path = dir.join(id);
+            if !path.join(MANIFEST_FILE).is_file() {
+                return Err(eyre!("mod `{id}` is enabled but not installed in {}", dir.display()))
+            }
+            GameMod::load_from_dir(&path)
+        })
+        .collect()
+}
+
+/// Returns the ids of the mods a game is started with, the ones of the options or, if they name
+/// none, the ones enabled in the mod manager
+pub fn get_enabled_mods(options: &GameOptions) -> Result<Vec<String>> {
+    if options.mods.is_empty() {
+        load_enabled_mods(&get_mods_dir())
+    } else {
+        Ok(options.mods.clone())
+    }
+}
+
+/// Reads the ids of the mods enabled in the mod manager, none are enabled if the file is missing
+pub fn load_enabled_mods(dir: &Path) -> Result<Vec<String>> {
+    let path = dir.join(ENABLED_MODS_FILE);
+    if !path.is_file() {
+        return Ok(Vec::new())
+    }
+    let contents = std::fs::read_to_string(&path)
+        .wrap_err_with(|| format!("{}: unable to read file", path.display()))?;
+    serde_json::from_str(&contents)
+        .wrap_err_with(|| format!("{}: unable to parse file", path.display()))
+}
+
+/// Writes the ids of the mods enabled in the mod manager
+pub fn save_enabled_mods(dir: &Path, ids: &[String]) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .wrap_err_with(|| format!("{}: unable to create directory", dir.display()))?;
+    let path = dir.join(ENABLED_MODS_FILE);
+    std::fs::write(&path, serde_json::to_string_pretty(ids)?)
+        .wrap_err_with(|| format!("{}: unable to write file", path.display()))
+}
\ No newline at end of file
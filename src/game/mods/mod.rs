@@ -0,0 +1,3 @@
+pub mod game_mod;
+pub mod asset_layers;
+pub mod namelists;
\ No newline at end of file
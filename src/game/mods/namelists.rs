@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::game::mods::asset_layers::{AssetError, AssetLayers};
+
+mod constants {
+    /// Names of the stars, relative to the assets directory
+    pub const STARS: &str = "namelists/star_namelist.txt";
+    /// First names of the officers, relative to the assets directory
+    pub const OFFICER_FIRST_NAMES: &str = "namelists/officer_first_names.txt";
+    /// Last names of the officers, relative to the assets directory
+    pub const OFFICER_LAST_NAMES: &str = "namelists/officer_last_names.txt";
+}
+
+/// `Namelists` holds the names the game picks from, read once when the game starts
+///
+/// **Fields**
+/// - stars (`Arc<Vec<String>>`) - names of the stars
+/// - officer_first_names (`Arc<Vec<String>>`) - first names of the officers
+/// - officer_last_names (`Arc<Vec<String>>`) - last names of the officers
+#[derive(Clone, Debug, Default)]
+pub struct Namelists {
+    pub stars: Arc<Vec<String>>,
+    pub officer_first_names: Arc<Vec<String>>,
+    pub officer_last_names: Arc<Vec<String>>,
+}
+
+impl Namelists {
+    /// Reads every namelist of the base game and the enabled mods
+    pub fn load(assets: &AssetLayers) -> Result<Self, AssetError> {
+        Ok(Self {
+            stars: Arc::new(assets.read_lines(constants::STARS)?),
+            officer_first_names: Arc::new(assets.read_lines(constants::OFFICER_FIRST_NAMES)?),
+            officer_last_names: Arc::new(assets.read_lines(constants::OFFICER_LAST_NAMES)?),
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::personnel::officer::Skill;
+
+/// `Assignment` is a post an officer can serve at. Every post takes a single officer.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Assignment {
+    /// Governs a colony, by its name
+    Colony(String),
+    /// Leads a research in progress, by its id
+    Research(String),
+    /// Commands a fleet, by its id
+    Fleet(u32),
+}
+
+impl Assignment {
+    /// Returns the skills the officer uses at the post, which improve when the officer is promoted
+    pub fn get_skills(&self) -> Vec<Skill> {
+        match self {
+            Assignment::Colony(_) => vec![Skill::Governance, Skill::Logistics],
+            Assignment::Research(_) => vec![Skill::Research],
+            Assignment::Fleet(_) => vec![Skill::Command, Skill::Logistics],
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod officer;
+pub mod assignment;
+pub mod personnel_manager;
+pub mod personnel_event;
\ No newline at end of file
@@ -0,0 +1,253 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::mods::namelists::Namelists;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
+
+/// Highest level of a skill
+pub const MAX_SKILL: u32 = 5;
+/// Ranks of the officers, from the lowest
+const RANKS: [&str; 5] = ["Lieutenant", "Commander", "Captain", "Commodore", "Admiral"];
+/// Days of service needed for the first promotion, every next rank takes this much longer
+const DAYS_PER_RANK: u32 = 180;
+/// Stability a governor adds to the colony for every level of governance
+const STABILITY_PER_GOVERNANCE: i32 = 3;
+/// Output of mines and factories a governor adds for every level of logistics, in percent
+const OUTPUT_PER_LOGISTICS: i32 = 5;
+/// Speed a commander adds to the fleet for every level of logistics, in percent
+const SPEED_PER_LOGISTICS: f64 = 10.0;
+/// Accuracy a commander adds to the weapons of the fleet for every level of command, in percent
+const ACCURACY_PER_COMMAND: f32 = 5.0;
+/// A research leader adds a research point on as many days out of this many as the level of
+/// research, so the best leaders double the speed of a research
+const RESEARCH_CYCLE: u64 = MAX_SKILL as u64;
+
+/// `Skill` is something an officer can be good at
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum Skill {
+    /// Speeds up the research the officer leads
+    Research,
+    /// Keeps the colony the officer governs stable
+    Governance,
+    /// Raises the output of a governed colony and the speed of a commanded fleet
+    Logistics,
+    /// Makes the weapons of a commanded fleet more accurate
+    Command,
+}
+
+impl Skill {
+    pub fn get_variants() -> Vec<Skill> {
+        vec![Skill::Research, Skill::Governance, Skill::Logistics, Skill::Command]
+    }
+}
+
+impl From<Skill> for String {
+    fn from(value: Skill) -> Self {
+        match value {
+            Skill::Research => String::from("Research"),
+            Skill::Governance => String::from("Governance"),
+            Skill::Logistics => String::from("Logistics"),
+            Skill::Command => String::from("Command"),
+        }
+    }
+}
+
+/// `Officer` is a named character of an empire, who serves at a post and grows better at the
+/// skills the post needs with every promotion
+///
+/// **Fields**
+/// - id (`u32`) - identifies the officer within the empire
+/// - name (`String`) - full name of the officer
+/// - age (`u32`) - age of the officer, in years
+/// - rank (`usize`) - index of the rank of the officer in `RANKS`
+/// - experience (`u32`) - days served since the last promotion
+/// - skills (`BTreeMap<Skill, u32>`) - level of every skill, from 0 to `MAX_SKILL`
+/// - assignment (`Option<Assignment>`) - the post the officer serves at, `None` if idle
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Officer {
+    id: u32,
+    name: String,
+    age: u32,
+    rank: usize,
+    experience: u32,
+    skills: BTreeMap<Skill, u32>,
+    assignment: Option<Assignment>,
+}
+
+impl Officer {
+    pub fn new(id: u32, name: String, age: u32, skills: BTreeMap<Skill, u32>) -> Self {
+        Self { id, name, age, rank: 0, experience: 0, skills, assignment: None }
+    }
+
+    /// Generates a young officer with a random name, good at one skill and poor at the others
+    pub fn generate(id: u32, namelists: &Namelists, rng: &mut impl Rng) -> Self {
+        let name = format!(
+            "{} {}",
+            namelists.officer_first_names.choose(rng).cloned().unwrap_or_default(),
+            namelists.officer_last_names.choose(rng).cloned().unwrap_or_default(),
+        );
+        let mut skills: BTreeMap<Skill, u32> = Skill::get_variants().into_iter()
+            .map(|s| (s, rng.gen_range(0..=1)))
+            .collect();
+        let talent = *Skill::get_variants().choose(rng).unwrap();
+        skills.insert(talent, rng.gen_range(2..=3));
+
+        Self::new(id, name, rng.gen_range(25..=40), skills)
+    }
+
+    pub fn get_id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn get_age(&self) -> u32 {
+        self.age
+    }
+
+    pub fn get_rank(&self) -> String {
+        RANKS[self.rank].to_string()
+    }
+
+    pub fn get_skill(&self, skill: Skill) -> u32 {
+        *self.skills.get(&skill).unwrap_or(&0)
+    }
+
+    pub fn get_assignment(&self) -> Option<&Assignment> {
+        self.assignment.as_ref()
+    }
+
+    pub fn set_assignment(&mut self, assignment: Option<Assignment>) {
+        self.assignment = assignment;
+    }
+
+    /// Serves a day at the post. Once the officer has served long enough, they get promoted and
+    /// improve the best of the skills the post needs, which is not at its highest level yet.
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the officer got promoted
+    pub fn serve(&mut self) -> bool {
+        let assignment = match self.assignment.as_ref() {
+            Some(assignment) if self.rank + 1 < RANKS.len() => assignment,
+            _ => return false,
+        };
+        self.experience += 1;
+        if self.experience < DAYS_PER_RANK * (self.rank as u32 + 1) {
+            return false;
+        }
+
+        let skill = assignment.get_skills().into_iter()
+            .filter(|s| self.get_skill(*s) < MAX_SKILL)
+            .fold(None, |best: Option<Skill>, s| match best {
+                Some(b) if self.get_skill(b) >= self.get_skill(s) => Some(b),
+                _ => Some(s),
+            });
+        if let Some(skill) = skill {
+            *self.skills.entry(skill).or_insert(0) += 1;
+        }
+        self.rank += 1;
+        self.experience = 0;
+        true
+    }
+
+    pub fn grow_older(&mut self) {
+        self.age += 1;
+    }
+
+    /// Returns the stability the officer adds to the colony they govern
+    pub fn get_stability_bonus(&self) -> i32 {
+        self.get_skill(Skill::Governance) as i32 * STABILITY_PER_GOVERNANCE
+    }
+
+    /// Applies the bonus of the officer to the output of the colony they govern
+    pub fn apply(&self, modifiers: &mut TechModifiers) {
+        let bonus = self.get_skill(Skill::Logistics) as i32 * OUTPUT_PER_LOGISTICS;
+        modifiers.add(ModifierType::MiningEfficiency, bonus);
+        modifiers.add(ModifierType::FactoryYield, bonus);
+    }
+
+    /// Returns the factor the speed of the fleet the officer commands is multiplied by
+    pub fn get_speed_multiplier(&self) -> f64 {
+        1.0 + self.get_skill(Skill::Logistics) as f64 * SPEED_PER_LOGISTICS / 100.0
+    }
+
+    /// Returns the factor the hit chance of the fleet the officer commands is multiplied by
+    pub fn get_accuracy_multiplier(&self) -> f32 {
+        1.0 + self.get_skill(Skill::Command) as f32 * ACCURACY_PER_COMMAND / 100.0
+    }
+
+    /// Returns the research points the officer adds to the research they lead on a day
+    ///
+    /// # Arguments
+    /// * `date` - The current in-game date
+    pub fn get_research_points(&self, date: u64) -> u32 {
+        (date % RESEARCH_CYCLE < self.get_skill(Skill::Research) as u64) as u32
+    }
+
+    /// Describes the skills of the officer, one line per skill
+    pub fn get_skill_description(&self) -> Vec<String> {
+        Skill::get_variants().into_iter().map(|s| {
+            let level = self.get_skill(s) as usize;
+            format!(
+                "{}: {}{}",
+                String::from(s),
+                "*".repeat(level),
+                "-".repeat(MAX_SKILL as usize - level.min(MAX_SKILL as usize)),
+            )
+        }).collect()
+    }
+}
+
+impl Displayable for Officer {
+    fn get_name(&self) -> String {
+        format!("{} {}", self.get_rank(), self.name)
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![
+                format!("Name: {}", self.name),
+                format!("Rank: {}", self.get_rank()),
+                format!("Age: {}", self.age),
+            ],
+            self.get_skill_description(),
+        ]
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self.assignment {
+            Some(_) => Color::White,
+            None => Color::LightYellow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::game::personnel::assignment::Assignment;
+    use crate::game::personnel::officer::{DAYS_PER_RANK, Officer, Skill};
+
+    #[test]
+    fn test_promotions_improve_the_skills_the_post_needs() {
+        let skills = BTreeMap::from([(Skill::Governance, 1), (Skill::Logistics, 2)]);
+        let mut officer = Officer::new(0, String::from("Ada Voss"), 30, skills);
+        assert!(!officer.serve());
+
+        officer.set_assignment(Some(Assignment::Colony(String::from("Earth"))));
+        let promotions = (0..DAYS_PER_RANK * 3).filter(|_| officer.serve()).count();
+
+        assert_eq!(promotions, 2);
+        assert_eq!(officer.get_rank(), "Captain");
+        assert_eq!(officer.get_skill(Skill::Logistics), 4);
+        assert_eq!(officer.get_skill(Skill::Governance), 1);
+        assert_eq!(officer.get_skill(Skill::Command), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// `PersonnelEvent` is a change among the officers of an empire
+pub enum PersonnelEvent {
+    /// A new officer joined the empire
+    Recruited { officer: String },
+    /// An officer got promoted, the name is the one with the new rank
+    Promoted { officer: String },
+    /// An officer retired from the service
+    Retired { officer: String },
+    /// The post of an officer does not exist anymore
+    Relieved { officer: String },
+}
+
+impl Displayable for PersonnelEvent {
+    fn get_name(&self) -> String {
+        match self {
+            PersonnelEvent::Recruited { officer } => {
+                format!("{officer} joined the service")
+            }
+            PersonnelEvent::Promoted { officer } => {
+                format!("{officer} got promoted")
+            }
+            PersonnelEvent::Retired { officer } => {
+                format!("{officer} retired")
+            }
+            PersonnelEvent::Relieved { officer } => {
+                format!("{officer} is waiting for a new assignment")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            PersonnelEvent::Recruited { .. } => Color::LightCyan,
+            PersonnelEvent::Promoted { .. } => Color::LightGreen,
+            PersonnelEvent::Retired { .. } => Color::Gray,
+            PersonnelEvent::Relieved { .. } => Color::LightYellow,
+        }
+    }
+}
+
+impl GameEvent for PersonnelEvent {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Personnel
+    }
+}
\ No newline at end of file
@@ -0,0 +1,142 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::TICKS_PER_YEAR;
+use crate::game::mods::namelists::Namelists;
+use crate::game::personnel::assignment::Assignment;
+use crate::game::personnel::officer::Officer;
+use crate::game::personnel::personnel_event::PersonnelEvent;
+
+/// Amount of officers every empire starts with
+pub const INITIAL_OFFICERS: u32 = 3;
+/// Amount of days between two recruitments
+const RECRUITMENT_INTERVAL: u64 = 90;
+/// Age the officers may retire at
+const RETIREMENT_AGE: u32 = 60;
+/// Chance of retiring every year for every year past the retirement age
+const RETIREMENT_CHANCE_PER_YEAR: f64 = 0.1;
+
+/// `PersonnelManager` holds the officers of an empire
+///
+/// **Fields**
+/// - officers (`Vec<Officer>`) - every officer in the service, the longest serving first
+/// - next_id (`u32`) - id of the next recruited officer
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PersonnelManager {
+    officers: Vec<Officer>,
+    next_id: u32,
+}
+
+impl PersonnelManager {
+    pub fn get_officers(&self) -> &Vec<Officer> {
+        &self.officers
+    }
+
+    pub fn get_officer(&self, id: u32) -> Option<&Officer> {
+        self.officers.iter().find(|o| o.get_id() == id)
+    }
+
+    /// Returns the officer serving at a post
+    pub fn get_assigned(&self, assignment: &Assignment) -> Option<&Officer> {
+        self.officers.iter().find(|o| o.get_assignment() == Some(assignment))
+    }
+
+    /// Recruits a new officer
+    ///
+    /// # Arguments
+    /// * `namelists` - Names the officer is named from
+    /// * `rng` - Random number generator of the game
+    ///
+    /// # Returns
+    /// * `&Officer` - The recruited officer
+    pub fn recruit(&mut self, namelists: &Namelists, rng: &mut impl Rng) -> &Officer {
+        self.officers.push(Officer::generate(self.next_id, namelists, rng));
+        self.next_id += 1;
+        self.officers.last().unwrap()
+    }
+
+    /// Sends an officer to a post, replacing the officer who served there, or calls the officer
+    /// back if the post is `None`
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the assignment was changed
+    pub fn assign(&mut self, id: u32, assignment: Option<Assignment>) -> bool {
+        let current = match self.get_officer(id) {
+            Some(officer) => officer.get_assignment().cloned(),
+            None => return false,
+        };
+        if current == assignment {
+            return false;
+        }
+
+        for officer in self.officers.iter_mut() {
+            if officer.get_id() == id {
+                officer.set_assignment(assignment.clone());
+            } else if assignment.is_some() && officer.get_assignment() == assignment.as_ref() {
+                officer.set_assignment(None);
+            }
+        }
+        true
+    }
+
+    /// Calls back the officers serving at the posts that do not exist anymore
+    ///
+    /// # Arguments
+    /// * `exists` - Returns `true` if the post still exists
+    pub fn release_invalid(&mut self, exists: impl Fn(&Assignment) -> bool) -> Vec<PersonnelEvent> {
+        let mut events = Vec::<PersonnelEvent>::new();
+        for officer in self.officers.iter_mut() {
+            if officer.get_assignment().is_some_and(|a| !exists(a)) {
+                officer.set_assignment(None);
+                events.push(PersonnelEvent::Relieved { officer: officer.get_name() });
+            }
+        }
+        events
+    }
+
+    /// Lets the officers serve for a day. Once a year every officer grows older and the old ones
+    /// may retire, and a new officer is recruited regularly while the empire has room for them.
+    ///
+    /// # Arguments
+    /// * `date` - The current in-game date
+    /// * `max_officers` - Amount of officers the empire can have
+    /// * `namelists` - Names the recruited officers are named from
+    /// * `rng` - The random number generator of the game
+    ///
+    /// # Returns
+    /// * `Vec<PersonnelEvent>` - What happened to the officers
+    pub fn tick(
+        &mut self,
+        date: u64,
+        max_officers: usize,
+        namelists: &Namelists,
+        rng: &mut impl Rng,
+    ) -> Vec<PersonnelEvent> {
+        let mut events = Vec::<PersonnelEvent>::new();
+        for officer in self.officers.iter_mut() {
+            if officer.serve() {
+                events.push(PersonnelEvent::Promoted { officer: officer.get_name() });
+            }
+        }
+
+        if date.is_multiple_of(TICKS_PER_YEAR) {
+            self.officers.iter_mut().for_each(|o| o.grow_older());
+            self.officers.retain(|o| {
+                let years = o.get_age().saturating_sub(RETIREMENT_AGE);
+                let chance = (years as f64 * RETIREMENT_CHANCE_PER_YEAR).min(1.0);
+                if years > 0 && rng.gen_bool(chance) {
+                    events.push(PersonnelEvent::Retired { officer: o.get_name() });
+                    return false;
+                }
+                true
+            });
+        }
+
+        if date.is_multiple_of(RECRUITMENT_INTERVAL) && self.officers.len() < max_officers {
+            let officer = self.recruit(namelists, rng).get_name();
+            events.push(PersonnelEvent::Recruited { officer });
+        }
+        events
+    }
+}
\ No newline at end of file
@@ -1,2 +1,7 @@
 pub mod research;
 pub mod research_manager;
+pub mod research_graph;
+pub mod research_event;
+pub mod research_progress;
+pub mod validation;
+pub mod tech_modifiers;
@@ -1,41 +1,97 @@
-use std::cmp::min;
-
 use derive_getters::Getters;
 use log::info;
-use ratatui::prelude::Color;
-use ratatui::style::Color::{LightCyan, LightGreen, LightRed, LightYellow};
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 
 use crate::game::celestial_bodies::Displayable;
+use crate::game::mods::asset_layers::{AssetError, AssetLayers};
+use crate::game::research::tech_modifiers::ResearchEffect;
+use crate::game::research::validation::{ResearchError, validate_research};
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
 pub struct ResearchField {
     name: String,
     id: String,
     researches: Vec<Research>,
+    /// Names of the buildings that can inspire a eureka moment in this field
+    #[serde(default)]
+    related_buildings: Vec<String>,
 }
 
+/// `Research` is the static definition of a technology. It is loaded once from the game assets
+/// and never changes during the game, the progress is stored separately in `ResearchState`
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters)]
 pub struct Research {
     name: String,
     id: String,
     cost: u32,
-    progress: u32,
     speed: u32,
-    is_going: bool,
     field: String,
     required_any: Vec<String>,
     required_all: Vec<String>,
     description: String,
+    /// Amount of times the technology can be researched, every level costs more than the last
+    #[serde(default = "default_max_level")]
+    max_level: u32,
+    /// How much more expensive every next level is, in percent of the previous level's cost
+    #[serde(default)]
+    level_cost_growth: u32,
+    /// Bonuses granted by every researched level
+    #[serde(default)]
+    effects: Vec<ResearchEffect>,
+}
+
+fn default_max_level() -> u32 {
+    1
+}
+
+/// Reads a research asset merged with the same asset of every enabled mod
+pub(crate) fn load_json<T: DeserializeOwned>(
+    assets: &AssetLayers,
+    asset: &str,
+) -> Result<T, ResearchError> {
+    let contents = assets.read_records(asset).map_err(|e| match e {
+        AssetError::Io { path, message } => ResearchError::Io { path, message },
+        AssetError::Parse { path, message } => ResearchError::Parse { path, message },
+    })?;
+
+    serde_json::from_str(&contents).map_err(|e| ResearchError::Parse {
+        path: asset.to_string(),
+        message: e.to_string(),
+    })
 }
 
 impl ResearchField {
-    pub fn load_from_file(filepath: &str, research_path: &str) -> Vec<Self> {
-        let mut objects: Vec<Self> = serde_json::from_str(
-            &std::fs::read_to_string(filepath).unwrap()
-        ).unwrap();
+    /// Loads the research fields and fills them with the researches that belong to them
+    ///
+    /// # Arguments
+    /// * `assets` - Layers the files are read from
+    /// * `fields_asset` - Path to the research fields file, relative to the assets directory
+    /// * `research_asset` - Path to the researches file, relative to the assets directory
+    ///
+    /// # Returns
+    /// * `Result<Vec<Self>, Vec<ResearchError>>` - The fields or every problem found in the files
+    pub fn load_from_assets(
+        assets: &AssetLayers,
+        fields_asset: &str,
+        research_asset: &str,
+    ) -> Result<Vec<Self>, Vec<ResearchError>> {
+        let fields = load_json::<Vec<Self>>(assets, fields_asset);
+        let researches = Research::load_from_assets(assets, research_asset);
+
+        let (mut objects, researches) = match (fields, researches) {
+            (Ok(fields), Ok(researches)) => (fields, researches),
+            (fields, researches) => {
+                return Err(
+                    fields.err().into_iter().chain(researches.err()).collect()
+                )
+            }
+        };
 
-        let researches = Research::load_from_file(research_path);
+        let errors = validate_research(&objects, &researches);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
 
         info!("Loaded research fields: {:?}", objects);
         info!("Loaded research objects: {:?}", researches);
@@ -51,53 +107,38 @@ impl ResearchField {
             }
         );
 
-        objects
+        Ok(objects)
     }
 }
 
 impl Research {
-    pub fn load_from_file(filepath: &str) -> Vec<Self> {
-        serde_json::from_str(&std::fs::read_to_string(filepath).unwrap()).unwrap()
-    }
-
-    pub fn is_finished(&self) -> bool {
-        self.progress == self.cost
+    pub fn load_from_assets(assets: &AssetLayers, asset: &str) -> Result<Vec<Self>, ResearchError> {
+        load_json(assets, asset)
     }
 
-    pub fn is_in_progress(&self) -> bool {
-        self.is_going
+    pub fn is_leveled(&self) -> bool {
+        self.max_level > 1
     }
 
-    pub fn percent_complete(&self) -> f32 {
-        (self.progress as f32 * 100f32) / (self.cost as f32)
+    /// Calculates the cost of a level of the technology
+    ///
+    /// # Arguments
+    /// * `level` - The amount of already researched levels
+    ///
+    /// # Returns
+    /// * `u32` - Amount of points required to research the next level
+    pub fn get_cost_for_level(&self, level: u32) -> u32 {
+        let growth = 1.0 + self.level_cost_growth as f64 / 100.0;
+        (self.cost as f64 * growth.powi(level as i32)).round() as u32
     }
 
-    pub fn get_progress_text(&self) -> String {
-        if !self.is_in_progress() && !self.is_finished() {
-            return String::from("Research available")
-        }
-
-        match self.percent_complete() as i32 {
-            100 => String::from("Research complete"),
-            percent => format!(
-                "Research in progress: {}% ({}/{})",
-                percent,
-                self.progress,
-                self.cost,
-            )
-        }
-    }
-
-    pub fn start(&mut self) {
-        self.is_going = true;
+    /// Calculates how many points are needed to research the technology up to the level
+    pub fn get_cost_until_level(&self, level: u32) -> u32 {
+        (0..level.min(self.max_level)).map(|l| self.get_cost_for_level(l)).sum()
     }
 }
 
 impl ResearchField {
-    pub fn finished_research(&self) -> Vec<Research> {
-        self.researches.iter().filter(|r| { r.is_finished() }).cloned().collect()
-    }
-
     pub fn get_id(&self) -> String { self.id.clone() }
     pub fn get_researches(&self) -> Vec<Research> { self.researches.clone() }
 
@@ -114,45 +155,12 @@ impl ResearchField {
             }
         ).unwrap()
     }
-
-    pub fn get_mut_research_by_id(&mut self, id: String) -> &mut Research {
-        self.researches.iter_mut().find(
-            |r| r.id == id
-        ).unwrap()
-    }
-
-    pub fn tick(&mut self) {
-        for r in self.researches.as_mut_slice() {
-            if r.is_in_progress() {
-                r.progress = min(r.cost, r.progress + r.speed)
-            }
-            if r.progress == r.cost {
-                r.is_going = false
-            }
-        }
-    }
 }
 
 impl Displayable for ResearchField {
     fn get_name(&self) -> String {
         self.name.clone()
     }
-
-    fn get_menu_color(&self) -> Color {
-        let total_research = self.researches.len() as f32;
-        let finished_research = self.finished_research().len() as f32;
-
-        if total_research == 0.0 {
-            return Color::Gray;
-        } else {
-            match (finished_research * 100f32 / total_research) as i32 {
-                0..=10 => LightRed,
-                11..=50 => LightYellow,
-                51..=90 => LightGreen,
-                _ => LightCyan,
-            }
-        }
-    }
 }
 
 impl Displayable for Research {
@@ -161,7 +169,7 @@ impl Displayable for Research {
     }
 
     fn get_properties(&self) -> Vec<Vec<String>> {
-        vec![
+        let mut properties = vec![
             vec![
                 self.name.clone()
             ],
@@ -175,15 +183,23 @@ impl Displayable for Research {
                     "Description: {}", self.description.clone()
                 )
             ],
-        ]
-    }
+        ];
 
-    fn get_menu_color(&self) -> Color {
-        match self.percent_complete() as i32 {
-            0..=25 => LightRed,
-            25..=75 => LightYellow,
-            76..=99 => LightGreen,
-            _ => LightCyan,
+        if self.is_leveled() {
+            properties.push(vec![
+                format!(
+                    "Levels: {}, each {}% more expensive than the last",
+                    self.max_level,
+                    self.level_cost_growth,
+                )
+            ]);
         }
+
+        self.effects.iter().for_each(|e| {
+            let modifier: String = e.modifier.into();
+            properties.push(vec![format!("{}: {:+}% per level", modifier, e.per_level)]);
+        });
+
+        properties
     }
-}
\ No newline at end of file
+}
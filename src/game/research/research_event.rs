@@ -0,0 +1,79 @@
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// Chance for a research in progress to get a breakthrough on any tick
+pub const BREAKTHROUGH_CHANCE: f64 = 0.02;
+/// Chance for a research in progress to fail an experiment on any tick
+pub const SETBACK_CHANCE: f64 = 0.015;
+/// Chance for a research in progress to unlock another available technology of its field
+pub const FREE_TECHNOLOGY_CHANCE: f64 = 0.002;
+/// Eureka chance added by every building related to the field of the research
+pub const EUREKA_CHANCE_PER_BUILDING: f64 = 0.001;
+/// Eureka chance added by every finished research of the same field
+pub const EUREKA_CHANCE_PER_DISCOVERY: f64 = 0.002;
+/// Upper limit for the eureka chance
+pub const MAX_EUREKA_CHANCE: f64 = 0.05;
+
+/// `ResearchEvent` is a random event that happened to a research in progress
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ResearchEvent {
+    /// Bonus progress
+    Breakthrough { research: String, bonus: u32 },
+    /// A failed experiment, which cost some progress
+    Setback { research: String, loss: u32 },
+    /// Bonus progress inspired by the colonies' industry or the previous discoveries
+    Eureka { research: String, bonus: u32, cause: String },
+    /// Another technology of the same field got researched for free
+    FreeTechnology { source: String, research: String },
+    /// A research got finished, or one more level of it got researched
+    Completed { research: String, level: Option<u32> },
+}
+
+impl Displayable for ResearchEvent {
+    fn get_name(&self) -> String {
+        match self {
+            ResearchEvent::Breakthrough { research, bonus } => {
+                format!("Breakthrough in {research}: +{bonus} points")
+            }
+            ResearchEvent::Setback { research, loss } => {
+                format!("Failed experiment in {research}: -{loss} points")
+            }
+            ResearchEvent::Eureka { research, bonus, cause } => {
+                format!("Eureka! {cause} inspired {research}: +{bonus} points")
+            }
+            ResearchEvent::FreeTechnology { source, research } => {
+                format!("Research of {source} also uncovered {research}")
+            }
+            ResearchEvent::Completed { research, level: Some(level) } => {
+                format!("{research} reached level {level}")
+            }
+            ResearchEvent::Completed { research, level: None } => {
+                format!("Research of {research} complete")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            ResearchEvent::Breakthrough { .. } => Color::LightGreen,
+            ResearchEvent::Setback { .. } => Color::LightRed,
+            ResearchEvent::Eureka { .. } => Color::LightYellow,
+            ResearchEvent::FreeTechnology { .. } => Color::LightCyan,
+            ResearchEvent::Completed { .. } => Color::LightCyan,
+        }
+    }
+}
+
+impl GameEvent for ResearchEvent {
+    fn get_category(&self) -> EventCategory {
+        match self {
+            ResearchEvent::FreeTechnology { .. } | ResearchEvent::Completed { .. } => {
+                EventCategory::Technology
+            }
+            _ => EventCategory::Research,
+        }
+    }
+}
\ No newline at end of file
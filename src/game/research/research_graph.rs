@@ -0,0 +1,400 @@
+use std::collections::{HashMap, HashSet};
+
+use ratatui::prelude::{Color, Line as TextLine, Span, Style};
+use ratatui::style::Modifier;
+use ratatui::widgets::canvas::{Context, Line};
+use serde::{Deserialize, Serialize};
+
+use crate::game::research::research::Research;
+use crate::game::research::research_progress::ResearchState;
+
+/// Horizontal distance between two neighbouring layers of the graph, in canvas units
+pub const LAYER_SPACING: f64 = 30.0;
+/// Vertical distance between two neighbouring nodes of a layer, in canvas units
+pub const ROW_SPACING: f64 = 2.0;
+/// Maximum amount of characters of a technology name drawn on the canvas
+const LABEL_WIDTH: usize = 24;
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum DependencyKind {
+    /// The dependency comes from `required_all`
+    All,
+    /// The dependency comes from `required_any`
+    Any,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResearchNode {
+    pub id: String,
+    pub name: String,
+    pub field: String,
+    /// Cost of the level being researched, or of the last level once all of them are researched
+    pub cost: u32,
+    /// Points put into the level being researched
+    pub progress: u32,
+    /// Whether the technology is researched enough to unlock the ones requiring it
+    pub is_finished: bool,
+    pub color: Color,
+    pub layer: usize,
+    pub row: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResearchEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: DependencyKind,
+}
+
+/// `ResearchGraph` is a layered representation of the whole technology tree, where every
+/// technology is placed one layer to the right of its deepest prerequisite. Edges point from a
+/// prerequisite to the technology that requires it, regardless of their research fields.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResearchGraph {
+    nodes: Vec<ResearchNode>,
+    edges: Vec<ResearchEdge>,
+}
+
+impl ResearchGraph {
+    /// Builds the graph from a list of researches
+    ///
+    /// # Arguments
+    /// * `researches` - All the researches of the game, in the order of their fields
+    /// * `state` - The research progress of the current game
+    /// * `color_of` - A function returning the color a research should be drawn with
+    ///
+    /// # Returns
+    /// * `ResearchGraph` - The laid out graph. Dependencies on unknown ids are skipped
+    pub fn build(
+        researches: &[Research],
+        state: &ResearchState,
+        color_of: impl Fn(&Research) -> Color,
+    ) -> Self {
+        let known: HashSet<&String> = researches.iter().map(|r| r.id()).collect();
+
+        let mut edges = Vec::<ResearchEdge>::new();
+        researches.iter().for_each(|r| {
+            r.required_all().iter().filter(|id| known.contains(id)).for_each(|id| {
+                edges.push(ResearchEdge {
+                    from: id.clone(),
+                    to: r.id().clone(),
+                    kind: DependencyKind::All,
+                })
+            });
+            r.required_any().iter().filter(|id| known.contains(id)).for_each(|id| {
+                edges.push(ResearchEdge {
+                    from: id.clone(),
+                    to: r.id().clone(),
+                    kind: DependencyKind::Any,
+                })
+            });
+        });
+
+        let mut layers = HashMap::<String, usize>::new();
+        researches.iter().for_each(|r| {
+            Self::calculate_layer(r.id(), &edges, &mut layers, &mut HashSet::new());
+        });
+
+        let mut rows_taken = HashMap::<usize, usize>::new();
+        let nodes = researches.iter().map(|r| {
+            let layer = *layers.get(r.id()).unwrap_or(&0);
+            let row = rows_taken.entry(layer).or_insert(0);
+            let (progress, cost) = state.get(r.id()).get_level_progress(r);
+            let node = ResearchNode {
+                id: r.id().clone(),
+                name: r.name().clone(),
+                field: r.field().clone(),
+                cost,
+                progress,
+                is_finished: state.is_researched(r),
+                color: color_of(r),
+                layer,
+                row: *row,
+            };
+            *row += 1;
+            node
+        }).collect();
+
+        Self { nodes, edges }
+    }
+
+    /// Finds the length of the longest prerequisite chain leading to the research. Cyclic
+    /// dependencies are cut at the point where the cycle closes.
+    fn calculate_layer(
+        id: &String,
+        edges: &[ResearchEdge],
+        layers: &mut HashMap<String, usize>,
+        visiting: &mut HashSet<String>,
+    ) -> usize {
+        if let Some(layer) = layers.get(id) {
+            return *layer;
+        }
+        if !visiting.insert(id.clone()) {
+            return 0;
+        }
+
+        let layer = edges.iter()
+            .filter(|e| &e.to == id)
+            .map(|e| Self::calculate_layer(&e.from, edges, layers, visiting) + 1)
+            .max()
+            .unwrap_or(0);
+
+        visiting.remove(id);
+        layers.insert(id.clone(), layer);
+        layer
+    }
+
+    pub fn nodes(&self) -> &Vec<ResearchNode> {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &Vec<ResearchEdge> {
+        &self.edges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn get_node(&self, id: &str) -> Option<&ResearchNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+
+    pub fn get_node_at(&self, layer: usize, row: usize) -> Option<&ResearchNode> {
+        self.nodes.iter().find(|n| n.layer == layer && n.row == row)
+    }
+
+    pub fn get_layer_count(&self) -> usize {
+        self.nodes.iter().map(|n| n.layer + 1).max().unwrap_or(0)
+    }
+
+    pub fn get_layer_size(&self, layer: usize) -> usize {
+        self.nodes.iter().filter(|n| n.layer == layer).count()
+    }
+
+    fn get_prerequisites(&self, id: &str, kind: DependencyKind) -> Vec<&ResearchNode> {
+        self.edges.iter()
+            .filter(|e| e.to == id && e.kind == kind)
+            .filter_map(|e| self.get_node(&e.from))
+            .collect()
+    }
+
+    /// Finds the unfinished technologies that have to be researched to unlock the goal
+    ///
+    /// # Arguments
+    /// * `goal` - The id of the goal technology
+    ///
+    /// # Returns
+    /// * `Vec<String>` - Ids of the technologies in an order they can be researched in, ending
+    ///   with the goal itself. For every `required_any` group which is not yet satisfied the
+    ///   cheapest option is picked.
+    pub fn get_path_to(&self, goal: &str) -> Vec<String> {
+        let mut path = Vec::<String>::new();
+        self.collect_path(goal, &mut path, &mut HashSet::new());
+        path
+    }
+
+    fn collect_path(&self, id: &str, path: &mut Vec<String>, visiting: &mut HashSet<String>) {
+        let node = match self.get_node(id) {
+            Some(node) => node,
+            None => return,
+        };
+        if node.is_finished || path.contains(&node.id) || !visiting.insert(node.id.clone()) {
+            return;
+        }
+
+        for required in self.get_prerequisites(id, DependencyKind::All) {
+            self.collect_path(&required.id, path, visiting);
+        }
+
+        let any_of = self.get_prerequisites(id, DependencyKind::Any);
+        if !any_of.is_empty() && !any_of.iter().any(|n| n.is_finished) {
+            let cheapest = any_of.iter().map(|option| {
+                let mut option_path = path.clone();
+                self.collect_path(&option.id, &mut option_path, &mut visiting.clone());
+                let added = option_path[path.len()..].to_vec();
+                (self.get_remaining_cost(&added), added)
+            }).min_by_key(|(cost, _)| *cost);
+
+            if let Some((_, added)) = cheapest {
+                path.extend(added);
+            }
+        }
+
+        visiting.remove(&node.id);
+        path.push(node.id.clone());
+    }
+
+    /// Calculates the total cost of the technologies
+    ///
+    /// # Returns
+    /// * `u32` - The sum of the full costs of the levels being researched
+    pub fn get_total_cost(&self, ids: &[String]) -> u32 {
+        ids.iter().filter_map(|id| self.get_node(id)).map(|n| n.cost).sum()
+    }
+
+    /// Calculates how many research points are still needed to finish the technologies
+    pub fn get_remaining_cost(&self, ids: &[String]) -> u32 {
+        ids.iter()
+            .filter_map(|id| self.get_node(id))
+            .map(|n| n.cost.saturating_sub(n.progress))
+            .sum()
+    }
+
+    /// Returns the position of the node's label on the canvas
+    pub fn get_node_position(node: &ResearchNode) -> (f64, f64) {
+        (node.layer as f64 * LAYER_SPACING, -(node.row as f64) * ROW_SPACING)
+    }
+
+    pub fn draw_image(
+        &self,
+        ctx: &mut Context,
+        selected: Option<&String>,
+        goal_path: &[String],
+    ) {
+        for edge in self.edges.iter() {
+            let (from, to) = match (self.get_node(&edge.from), self.get_node(&edge.to)) {
+                (Some(from), Some(to)) => (from, to),
+                _ => continue,
+            };
+            let (x1, y1) = Self::get_node_position(from);
+            let (x2, y2) = Self::get_node_position(to);
+
+            let color = if goal_path.contains(&from.id) && goal_path.contains(&to.id) {
+                Color::LightMagenta
+            } else if from.field != to.field {
+                Color::Blue
+            } else {
+                match edge.kind {
+                    DependencyKind::All => Color::Gray,
+                    DependencyKind::Any => Color::DarkGray,
+                }
+            };
+
+            ctx.draw(&Line {
+                x1: x1 + (LABEL_WIDTH + 1) as f64,
+                y1,
+                x2: x2 - 1.0,
+                y2,
+                color,
+            });
+        }
+
+        ctx.layer();
+
+        for node in self.nodes.iter() {
+            let (x, y) = Self::get_node_position(node);
+            let mut style = Style::default().fg(node.color);
+            if goal_path.contains(&node.id) {
+                style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+            }
+            if Some(&node.id) == selected {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+
+            let label: String = node.name.chars().take(LABEL_WIDTH).collect();
+            ctx.print(x, y, TextLine::from(Span::styled(label, style)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui::prelude::Color;
+
+    use crate::game::research::research::Research;
+    use crate::game::research::research_graph::ResearchGraph;
+    use crate::game::research::research_progress::ResearchState;
+
+    fn research(id: &str, cost: u32, all: &[&str], any: &[&str]) -> Research {
+        serde_json::from_value(serde_json::json!({
+            "name": id,
+            "id": id,
+            "cost": cost,
+            "speed": 1,
+            "field": "test",
+            "required_any": any,
+            "required_all": all,
+            "description": "",
+        })).unwrap()
+    }
+
+    fn leveled_research(id: &str, cost: u32, max_level: u32, growth: u32) -> Research {
+        let mut research = serde_json::to_value(research(id, cost, &[], &[])).unwrap();
+        research["max_level"] = max_level.into();
+        research["level_cost_growth"] = growth.into();
+        serde_json::from_value(research).unwrap()
+    }
+
+    fn graph() -> ResearchGraph {
+        ResearchGraph::build(
+            &[
+                research("a", 10, &[], &[]),
+                research("b", 20, &["a"], &[]),
+                research("c", 50, &[], &[]),
+                research("d", 5, &[], &[]),
+                research("e", 30, &["b"], &["c", "a"]),
+                research("f", 15, &[], &["c", "d"]),
+            ],
+            &serde_json::from_value::<ResearchState>(serde_json::json!({
+                "d": { "progress": 5, "is_going": false },
+            })).unwrap(),
+            |_| Color::White,
+        )
+    }
+
+    #[test]
+    fn test_layers_follow_longest_chain() {
+        let graph = graph();
+        let layers: Vec<usize> = graph.nodes().iter().map(|n| n.layer).collect();
+        assert_eq!(layers, vec![0, 1, 0, 0, 2, 1]);
+        assert_eq!(graph.get_layer_count(), 3);
+        assert_eq!(graph.get_node_at(1, 1).unwrap().id, "f");
+    }
+
+    #[test]
+    fn test_path_picks_cheapest_option() {
+        let graph = graph();
+        let path = graph.get_path_to("e");
+        assert_eq!(path, vec!["a", "b", "e"]);
+        assert_eq!(graph.get_total_cost(&path), 60);
+    }
+
+    #[test]
+    fn test_path_skips_satisfied_options() {
+        let graph = graph();
+        assert_eq!(graph.get_path_to("f"), vec!["f"]);
+        assert!(graph.get_path_to("d").is_empty());
+    }
+
+    #[test]
+    fn test_leveled_costs_follow_the_level_being_researched() {
+        let graph = ResearchGraph::build(
+            &[leveled_research("a", 10, 3, 50), research("b", 20, &["a"], &[])],
+            &serde_json::from_value::<ResearchState>(serde_json::json!({
+                "a": { "progress": 14, "is_going": true },
+            })).unwrap(),
+            |_| Color::White,
+        );
+        let a = graph.get_node("a").unwrap();
+        assert_eq!((a.cost, a.progress), (15, 4));
+
+        let path = vec![String::from("a"), String::from("b")];
+        assert_eq!(graph.get_total_cost(&path), 35);
+        assert_eq!(graph.get_remaining_cost(&path), 31);
+    }
+
+    #[test]
+    fn test_cycles_do_not_hang() {
+        let graph = ResearchGraph::build(
+            &[
+                research("a", 10, &["b"], &[]),
+                research("b", 10, &["a"], &[]),
+            ],
+            &ResearchState::default(),
+            |_| Color::White,
+        );
+        assert_eq!(graph.get_path_to("a"), vec!["b", "a"]);
+    }
+}
@@ -1,31 +1,71 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
 use ratatui::style::Color;
 use ratatui::style::Color::{DarkGray, LightCyan};
 
 use crate::game::celestial_bodies::Displayable;
+use crate::game::mods::asset_layers::AssetLayers;
 use crate::game::research::research::{Research, ResearchField};
-
+use crate::game::research::research_event::{
+    BREAKTHROUGH_CHANCE, EUREKA_CHANCE_PER_BUILDING, EUREKA_CHANCE_PER_DISCOVERY,
+    FREE_TECHNOLOGY_CHANCE, MAX_EUREKA_CHANCE, ResearchEvent, SETBACK_CHANCE,
+};
+use crate::game::research::research_graph::ResearchGraph;
+use crate::game::research::research_progress::ResearchState;
+use crate::game::research::tech_modifiers::TechModifiers;
+use crate::game::research::validation::ResearchError;
+
+/// `ResearchManager` combines the research definitions, which are shared and never change, with
+/// the research progress of the current game
+#[derive(Clone)]
 pub struct ResearchManager {
-    research_fields: Vec<ResearchField>,
+    research_fields: Arc<Vec<ResearchField>>,
+    state: ResearchState,
 }
 
-impl Default for ResearchManager {
-    fn default() -> Self {
-        Self {
-            research_fields: ResearchField::load_from_file(
-                "assets/research_fields.json5",
-                "assets/research.json5",
-            ),
-        }
-    }
-}
+const RESEARCH_FIELDS_PATH: &str = "research_fields.json5";
+const RESEARCH_PATH: &str = "research.json5";
+const RESEARCH_PROGRESS_PATH: &str = "research_progress.json5";
 
 impl ResearchManager {
-    pub fn new() -> Self { Self::default() }
+    pub fn new(assets: &AssetLayers) -> Result<Self, Vec<ResearchError>> {
+        Ok(Self::from_fields(
+            ResearchField::load_from_assets(assets, RESEARCH_FIELDS_PATH, RESEARCH_PATH)?,
+            ResearchState::load_from_assets(assets, RESEARCH_PROGRESS_PATH).map_err(|e| vec![e])?,
+        ))
+    }
+
+    pub fn from_fields(research_fields: Vec<ResearchField>, state: ResearchState) -> Self {
+        Self { research_fields: Arc::new(research_fields), state }
+    }
+
+    /// Loads the research assets without keeping them, reporting every problem found
+    pub fn validate_assets(assets: &AssetLayers) -> Vec<ResearchError> {
+        let mut errors = ResearchField::load_from_assets(
+            assets,
+            RESEARCH_FIELDS_PATH,
+            RESEARCH_PATH,
+        ).err().unwrap_or_default();
+        errors.extend(ResearchState::load_from_assets(assets, RESEARCH_PROGRESS_PATH).err());
+        errors
+    }
+
+    pub fn get_state(&self) -> &ResearchState {
+        &self.state
+    }
+
+    /// Replaces the progress of the current game, e.g. with one restored from a save
+    pub fn set_state(&mut self, state: ResearchState) {
+        self.state = state;
+    }
 
     pub fn get_research_fields(&self) -> Vec<(String, String, Color)> {
         self.research_fields.iter().map(
             |r| {
-                (r.id().clone(), r.get_name(), r.get_menu_color())
+                (r.id().clone(), r.get_name(), self.state.get_field_color(r))
             }
         ).collect()
     }
@@ -36,12 +76,6 @@ impl ResearchManager {
         ).unwrap()
     }
 
-    fn get_mut_field_by_id(&mut self, id: String) -> &mut ResearchField {
-        self.research_fields.iter_mut().find(
-            |rf| rf.get_id() == id
-        ).unwrap()
-    }
-
     fn get_researches_by_field(&self, id: String) -> Vec<Research> {
         let field = self.get_field_by_id(id);
         field.get_researches()
@@ -56,6 +90,9 @@ impl ResearchManager {
         field.get_research_by_id(id)
     }
 
+    fn is_research_researched(&self, id: String) -> bool {
+        self.state.is_researched(self.get_research_by_id(id))
+    }
 
     fn are_research_requirements_satisfied(&self, id: String) -> bool {
         let all_of: Vec<bool> = self.get_research_by_id(id.clone())
@@ -63,7 +100,7 @@ impl ResearchManager {
             .iter()
             .map(
                 |id| {
-                    self.get_research_by_id(id.clone()).is_finished()
+                    self.is_research_researched(id.clone())
                 }
             )
             .collect();
@@ -73,7 +110,7 @@ impl ResearchManager {
             .iter()
             .map(
                 |id| {
-                    self.get_research_by_id(id.clone()).is_finished()
+                    self.is_research_researched(id.clone())
                 }
             )
             .collect();
@@ -85,7 +122,7 @@ impl ResearchManager {
     pub fn get_research_text(&self, id: String) -> String {
         let research = self.get_research_by_id(id);
         if self.are_research_requirements_satisfied(research.id().clone()) {
-            research.get_progress_text()
+            self.state.get(research.id()).get_progress_text(research)
         } else {
             String::from("Research locked")
         }
@@ -111,9 +148,8 @@ impl ResearchManager {
             .filter(|r| {
                 research.required_all().contains(r.id())
             })
-            .cloned()
             .map(|r| {
-                (r.name().clone(), r.is_finished())
+                (r.name().clone(), self.state.is_researched(r))
             })
             .collect();
 
@@ -126,9 +162,8 @@ impl ResearchManager {
             .filter(|r| {
                 research.required_any().contains(r.id())
             })
-            .cloned()
             .map(|r| {
-                (r.name().clone(), r.is_finished())
+                (r.name().clone(), self.state.is_researched(r))
             })
             .collect();
 
@@ -142,31 +177,213 @@ impl ResearchManager {
 
     pub fn get_research_color(&self, id: String) -> Color {
         let research = self.get_research_by_id(id.clone());
-        if research.is_finished() {
+        if self.state.is_finished(research) {
             LightCyan
         } else if !self.are_research_requirements_satisfied(id) {
             DarkGray
         } else {
-            research.get_menu_color()
+            self.state.get(research.id()).get_menu_color(research)
         }
     }
 
-    fn get_mut_research_by_id(&mut self, id: String) -> &mut Research {
-        let research = self.get_research_by_id(id.clone());
-        let field = self.get_mut_field_by_id(research.field().clone());
-        field.get_mut_research_by_id(id)
+    /// Lists the ids of the researches which can be started right now, in the order of the
+    /// research fields
+    pub fn get_available_researches(&self) -> Vec<String> {
+        self.get_all_researches().iter()
+            .filter(|r| !self.state.is_finished(r) && !self.state.get(r.id()).is_in_progress())
+            .filter(|r| self.are_research_requirements_satisfied(r.id().clone()))
+            .map(|r| r.id().clone())
+            .collect()
+    }
+
+    pub fn is_researching(&self) -> bool {
+        self.get_all_researches().iter().any(|r| self.state.get(r.id()).is_in_progress())
+    }
+
+    /// Advances the researches in progress the partner has already researched further
+    ///
+    /// # Arguments
+    /// * `partner` - Research progress of the partner sharing its findings
+    /// * `points` - Research points added to every shared research
+    pub fn learn_from(&mut self, partner: &ResearchState, points: u32) {
+        for research in self.get_all_researches() {
+            let is_shared = self.state.get(research.id()).is_in_progress()
+                && partner.get_level(&research) > self.state.get_level(&research);
+            if is_shared {
+                self.state.add_progress(&research, points as i64);
+            }
+        }
+    }
+
+    /// Returns `true` if the first level of a research is researched, `false` for unknown ids
+    pub fn is_researched(&self, id: &str) -> bool {
+        self.get_all_researches().iter().any(|r| r.id() == id && self.state.is_researched(r))
+    }
+
+    /// Returns the id and the name of every research which can be started right now
+    pub fn get_available_research_names(&self) -> Vec<(String, String)> {
+        let available = self.get_available_researches();
+        self.get_all_researches().into_iter()
+            .filter(|r| available.contains(r.id()))
+            .map(|r| (r.id().clone(), r.name().clone()))
+            .collect()
+    }
+
+    /// Returns `true` if a research with the id is defined in the assets
+    pub fn has_research(&self, id: &str) -> bool {
+        self.get_all_researches().iter().any(|r| r.id() == id)
+    }
+
+    /// Returns the ids of the researches with at least a level researched
+    pub fn get_researched(&self) -> Vec<String> {
+        self.get_all_researches().iter()
+            .filter(|r| self.state.is_researched(r))
+            .map(|r| r.id().clone())
+            .collect()
+    }
+
+    /// Returns the ids of the researches in progress
+    pub fn get_researches_in_progress(&self) -> Vec<String> {
+        self.get_all_researches().iter()
+            .filter(|r| self.state.get(r.id()).is_in_progress())
+            .map(|r| r.id().clone())
+            .collect()
+    }
+
+    /// Adds research points to a research in progress
+    pub fn add_progress(&mut self, id: &str, points: u32) {
+        if let Some(research) = self.get_all_researches().into_iter()
+            .find(|r| r.id() == id && self.state.get(id).is_in_progress()) {
+            self.state.add_progress(&research, points as i64);
+        }
+    }
+
+    /// Reports the researches which got a level researched since they were in progress
+    ///
+    /// # Arguments
+    /// * `previous` - Ids of the researches that were in progress before
+    ///
+    /// # Returns
+    /// * `Vec<ResearchEvent>` - An event for every one of them which is not in progress anymore
+    pub fn get_completed(&self, previous: &[String]) -> Vec<ResearchEvent> {
+        self.get_all_researches().into_iter()
+            .filter(|r| previous.contains(r.id()) && !self.state.get(r.id()).is_in_progress())
+            .map(|r| ResearchEvent::Completed {
+                level: r.is_leveled().then(|| self.state.get_level(&r)),
+                research: r.get_name(),
+            })
+            .collect()
     }
 
     pub fn start_research(&mut self, id: String) {
-        self.get_mut_research_by_id(id.clone()).start();
+        self.state.start(id);
     }
 
     pub fn tick(&mut self) {
-        for rf in self.research_fields.as_mut_slice() {
-            rf.tick();
+        for rf in self.research_fields.iter() {
+            self.state.tick(rf.researches());
         }
     }
 
+    /// Adds progress to a research, which neither drops below zero nor goes past its cost
+    ///
+    /// # Returns
+    /// * `u32` - How much the progress actually changed
+    fn change_progress(&mut self, research: &Research, amount: i64) -> u32 {
+        let before = self.state.get(research.id()).get_progress();
+        self.state.add_progress(research, amount);
+        before.abs_diff(self.state.get(research.id()).get_progress())
+    }
+
+    /// Rolls the random events for every research in progress and applies them
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator of the game
+    /// * `buildings` - Amount of buildings of every type across all the colonies, by name
+    ///
+    /// # Returns
+    /// * `Vec<ResearchEvent>` - The events that happened during this tick
+    pub fn roll_random_events(
+        &mut self,
+        rng: &mut impl Rng,
+        buildings: &HashMap<String, u32>,
+    ) -> Vec<ResearchEvent> {
+        let mut events = Vec::<ResearchEvent>::new();
+        let fields = Arc::clone(&self.research_fields);
+
+        let in_progress: Vec<Research> = self.get_all_researches().into_iter()
+            .filter(|r| self.state.get(r.id()).is_in_progress() && !self.state.is_finished(r))
+            .collect();
+
+        for research in in_progress {
+            let cost = research.get_cost_for_level(self.state.get_level(&research));
+
+            if rng.gen_bool(BREAKTHROUGH_CHANCE) {
+                let bonus = rng.gen_range(cost / 10..=cost / 4).max(1);
+                let bonus = self.change_progress(&research, bonus as i64);
+                if bonus > 0 {
+                    events.push(ResearchEvent::Breakthrough {
+                        research: research.get_name(),
+                        bonus,
+                    });
+                }
+            }
+
+            if rng.gen_bool(SETBACK_CHANCE) {
+                let loss = rng.gen_range(cost / 20..=cost / 8).max(1);
+                let loss = self.change_progress(&research, -(loss as i64));
+                if loss > 0 {
+                    events.push(ResearchEvent::Setback { research: research.get_name(), loss });
+                }
+            }
+
+            let field = fields.iter().find(|f| f.id() == research.field()).unwrap();
+            let inspiring_building = field.related_buildings().iter()
+                .map(|b| (b.clone(), *buildings.get(b).unwrap_or(&0)))
+                .max_by_key(|(_, amount)| *amount);
+            let building_count: u32 = field.related_buildings().iter()
+                .map(|b| *buildings.get(b).unwrap_or(&0))
+                .sum();
+            let discoveries = self.state.finished_research(field).len();
+
+            let eureka_chance = (building_count as f64 * EUREKA_CHANCE_PER_BUILDING
+                + discoveries as f64 * EUREKA_CHANCE_PER_DISCOVERY).min(MAX_EUREKA_CHANCE);
+            if rng.gen_bool(eureka_chance) {
+                let cause = match inspiring_building {
+                    Some((building, amount)) if amount > 0 => format!("Work at the {building}s"),
+                    _ => String::from("Previous discoveries"),
+                };
+                let bonus = rng.gen_range(cost / 4..=cost / 2).max(1);
+                let bonus = self.change_progress(&research, bonus as i64);
+                if bonus > 0 {
+                    events.push(ResearchEvent::Eureka {
+                        research: research.get_name(),
+                        bonus,
+                        cause,
+                    });
+                }
+            }
+
+            if rng.gen_bool(FREE_TECHNOLOGY_CHANCE) {
+                let candidates: Vec<Research> = field.researches().iter()
+                    .filter(|r| r.id() != research.id() && !self.state.is_finished(r))
+                    .filter(|r| self.are_research_requirements_satisfied(r.id().clone()))
+                    .cloned()
+                    .collect();
+
+                if let Some(free) = candidates.choose(rng) {
+                    self.state.complete_level(free);
+                    events.push(ResearchEvent::FreeTechnology {
+                        source: research.get_name(),
+                        research: free.get_name(),
+                    });
+                }
+            }
+        }
+
+        events
+    }
+
     pub fn get_research_info(&self, id: String) -> Vec<Vec<String>> {
         let research = self.get_research_by_id(id);
         research.get_properties()
@@ -174,15 +391,135 @@ impl ResearchManager {
 
     pub fn get_researches_with_colors_by_field(&self, id: String) -> Vec<(String, String, Color)> {
         let field = self.get_field_by_id(id);
-        let res: Vec<(String, String, Color)> = field.researches().clone().iter()
+        let res: Vec<(String, String, Color)> = field.researches().iter()
             .map(|x| {
-                (x.id().clone(), x.get_name(), self.get_research_color(x.id().clone()))
+                (x.id().clone(), self.get_leveled_name(x), self.get_research_color(x.id().clone()))
             }).collect();
 
         res
     }
 
+    /// Returns the name of the research, followed by the researched levels if it has any
+    fn get_leveled_name(&self, research: &Research) -> String {
+        if research.is_leveled() {
+            format!(
+                "{} ({}/{})",
+                research.get_name(),
+                self.state.get_level(research),
+                research.max_level(),
+            )
+        } else {
+            research.get_name()
+        }
+    }
+
+    /// Combines the effects of every researched level of every technology
+    pub fn get_modifiers(&self) -> TechModifiers {
+        let mut modifiers = TechModifiers::default();
+        self.get_all_researches().iter().for_each(|r| {
+            let level = self.state.get_level(r) as i32;
+            r.effects().iter().for_each(|e| modifiers.add(e.modifier, e.per_level * level));
+        });
+        modifiers
+    }
+
     pub fn get_research_progress(&self, id: String) -> u32 {
-        self.get_research_by_id(id).percent_complete() as u32
+        let research = self.get_research_by_id(id);
+        self.state.get(research.id()).percent_complete(research) as u32
+    }
+
+    pub fn get_research_graph(&self) -> ResearchGraph {
+        ResearchGraph::build(
+            &self.get_all_researches(),
+            &self.state,
+            |r| self.get_research_color(r.id().clone()),
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use serde_json::json;
+
+    use crate::game::research::research::{Research, ResearchField};
+    use crate::game::research::research_event::ResearchEvent;
+    use crate::game::research::research_manager::ResearchManager;
+    use crate::game::research::research_progress::ResearchState;
+
+    fn research(id: &str, cost: u32, required_all: &[&str]) -> Research {
+        serde_json::from_value(json!({
+            "name": id,
+            "id": id,
+            "cost": cost,
+            "speed": 1,
+            "field": "test",
+            "required_any": [],
+            "required_all": required_all,
+            "description": "",
+        })).unwrap()
+    }
+
+    fn manager() -> ResearchManager {
+        let field: ResearchField = serde_json::from_value(json!({
+            "name": "test",
+            "id": "test",
+            "researches": [
+                research("lasers", 1_000, &[]),
+                research("optics", 10, &[]),
+                research("sensors", 10, &["optics"]),
+            ],
+            "related_buildings": ["Mine"],
+        })).unwrap();
+        ResearchManager::from_fields(vec![field], ResearchState::default())
+    }
+
+    #[test]
+    fn test_random_events_report_what_they_applied() {
+        let mut manager = manager();
+        let lasers = research("lasers", 1_000, &[]);
+        let buildings = HashMap::from([(String::from("Mine"), 50)]);
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut happened = Vec::<&str>::new();
+
+        for _ in 0..1_000 {
+            manager.set_state(ResearchState::default());
+            manager.start_research(String::from("lasers"));
+            manager.state.add_progress(&lasers, 900);
+            let mut expected = 900;
+            for event in manager.roll_random_events(&mut rng, &buildings) {
+                match event {
+                    ResearchEvent::Breakthrough { bonus, .. } => {
+                        expected += bonus;
+                        happened.push("breakthrough");
+                    }
+                    ResearchEvent::Setback { loss, .. } => {
+                        expected -= loss;
+                        happened.push("setback");
+                    }
+                    ResearchEvent::Eureka { bonus, cause, .. } => {
+                        assert_eq!(cause, "Work at the Mines");
+                        expected += bonus;
+                        happened.push("eureka");
+                    }
+                    ResearchEvent::FreeTechnology { source, research } => {
+                        assert_eq!(source, "lasers");
+                        assert_eq!(manager.get_research_progress(research), 100);
+                        happened.push("free technology");
+                    }
+                    ResearchEvent::Completed { .. } => unreachable!(),
+                }
+            }
+            assert_eq!(manager.get_state().get("lasers").get_progress(), expected);
+        }
+
+        for kind in ["breakthrough", "setback", "eureka", "free technology"] {
+            assert!(happened.contains(&kind), "no {kind} happened");
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,301 @@
+use std::cmp::min;
+use std::collections::HashMap;
+
+use ratatui::prelude::Color;
+use ratatui::style::Color::{LightCyan, LightGreen, LightRed, LightYellow};
+use serde::{Deserialize, Serialize};
+
+use crate::game::mods::asset_layers::AssetLayers;
+use crate::game::research::research::{load_json, Research, ResearchField};
+use crate::game::research::validation::ResearchError;
+
+/// `ResearchProgress` holds how far a single technology has been researched. The progress is the
+/// total amount of points put into the technology, so it also defines the researched level
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResearchProgress {
+    progress: u32,
+    is_going: bool,
+}
+
+impl ResearchProgress {
+    pub fn get_progress(&self) -> u32 {
+        self.progress
+    }
+
+    /// Counts the researched levels. A level counts as researched once the progress reaches its
+    /// cost, so a definition that got cheaper after the game was saved keeps its levels
+    pub fn get_level(&self, research: &Research) -> u32 {
+        (1..=*research.max_level())
+            .take_while(|l| self.progress >= research.get_cost_until_level(*l))
+            .count() as u32
+    }
+
+    /// A research is finished once all of its levels are researched
+    pub fn is_finished(&self, research: &Research) -> bool {
+        self.get_level(research) >= *research.max_level()
+    }
+
+    /// A research unlocks the technologies that require it once its first level is researched
+    pub fn is_researched(&self, research: &Research) -> bool {
+        self.get_level(research) >= 1
+    }
+
+    /// Returns the points put into the level being researched and the cost of that level
+    pub fn get_level_progress(&self, research: &Research) -> (u32, u32) {
+        let level = self.get_level(research).min(research.max_level().saturating_sub(1));
+        let start = research.get_cost_until_level(level);
+        let cost = research.get_cost_for_level(level);
+        (min(self.progress.saturating_sub(start), cost), cost)
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.is_going
+    }
+
+    /// Calculates how much of the level being researched is complete
+    pub fn percent_complete(&self, research: &Research) -> f32 {
+        let (progress, cost) = self.get_level_progress(research);
+        (progress as f32 * 100f32) / (cost as f32)
+    }
+
+    pub fn get_progress_text(&self, research: &Research) -> String {
+        let level = if research.is_leveled() {
+            format!(" [level {}/{}]", self.get_level(research), research.max_level())
+        } else {
+            String::new()
+        };
+
+        if self.is_finished(research) {
+            return format!("Research complete{level}")
+        }
+        if !self.is_in_progress() {
+            return format!("Research available{level}")
+        }
+
+        let (progress, cost) = self.get_level_progress(research);
+        format!(
+            "Research in progress: {}% ({}/{}){}",
+            self.percent_complete(research) as i32,
+            progress,
+            cost,
+            level,
+        )
+    }
+
+    pub fn get_menu_color(&self, research: &Research) -> Color {
+        match self.percent_complete(research) as i32 {
+            0..=25 => LightRed,
+            26..=75 => LightYellow,
+            76..=99 => LightGreen,
+            _ => LightCyan,
+        }
+    }
+}
+
+/// `ResearchState` is the per-game research progress, keyed by research id. It is the only
+/// research data that belongs to a save, the definitions always come from the assets. Entries
+/// for ids that are not defined anymore are kept, but ignored.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ResearchState {
+    researches: HashMap<String, ResearchProgress>,
+}
+
+impl ResearchState {
+    /// Loads the research progress a new game starts with
+    pub fn load_from_assets(assets: &AssetLayers, asset: &str) -> Result<Self, ResearchError> {
+        load_json(assets, asset)
+    }
+
+    pub fn get(&self, id: &str) -> ResearchProgress {
+        self.researches.get(id).cloned().unwrap_or_default()
+    }
+
+    pub fn is_finished(&self, research: &Research) -> bool {
+        self.get(research.id()).is_finished(research)
+    }
+
+    pub fn is_researched(&self, research: &Research) -> bool {
+        self.get(research.id()).is_researched(research)
+    }
+
+    pub fn get_level(&self, research: &Research) -> u32 {
+        self.get(research.id()).get_level(research)
+    }
+
+    pub fn start(&mut self, id: String) {
+        self.researches.entry(id).or_default().is_going = true;
+    }
+
+    /// Adds progress to the level being researched, never gaining or losing a whole level. The
+    /// research stops once the level is complete, so the next one has to be started again
+    pub fn add_progress(&mut self, research: &Research, amount: i64) {
+        let state = self.researches.entry(research.id().clone()).or_default();
+        let level = state.get_level(research);
+        let start = research.get_cost_until_level(level) as i64;
+        let end = research.get_cost_until_level(level + 1) as i64;
+
+        state.progress = (state.progress as i64 + amount).clamp(start, end) as u32;
+        if state.get_level(research) > level || state.is_finished(research) {
+            state.is_going = false
+        }
+    }
+
+    /// Researches the current level of the technology at once
+    pub fn complete_level(&mut self, research: &Research) {
+        let state = self.researches.entry(research.id().clone()).or_default();
+        state.progress = research.get_cost_until_level(state.get_level(research) + 1);
+        state.is_going = false;
+    }
+
+    /// Advances every research in progress by its speed
+    ///
+    /// # Arguments
+    /// * `researches` - Definitions of the researches that can be advanced
+    pub fn tick(&mut self, researches: &[Research]) {
+        for r in researches {
+            if self.get(r.id()).is_in_progress() {
+                self.add_progress(r, *r.speed() as i64);
+            }
+        }
+    }
+
+    pub fn finished_research(&self, field: &ResearchField) -> Vec<Research> {
+        field.researches().iter().filter(|r| self.is_finished(r)).cloned().collect()
+    }
+
+    pub fn get_field_color(&self, field: &ResearchField) -> Color {
+        let total_research = field.researches().len() as f32;
+        let finished_research = self.finished_research(field).len() as f32;
+
+        if total_research == 0.0 {
+            Color::Gray
+        } else {
+            match (finished_research * 100f32 / total_research) as i32 {
+                0..=10 => LightRed,
+                11..=50 => LightYellow,
+                51..=90 => LightGreen,
+                _ => LightCyan,
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use pretty_assertions::assert_eq;
+
+    use crate::game::mods::asset_layers::AssetLayers;
+    use crate::game::mods::game_mod::GameMod;
+    use crate::game::research::research::Research;
+    use crate::game::research::research_progress::ResearchState;
+
+    fn research(id: &str, cost: u32, speed: u32) -> Research {
+        serde_json::from_value(serde_json::json!({
+            "name": id,
+            "id": id,
+            "cost": cost,
+            "speed": speed,
+            "field": "test",
+            "required_any": [],
+            "required_all": [],
+            "description": "",
+        })).unwrap()
+    }
+
+    fn leveled_research() -> Research {
+        serde_json::from_value(serde_json::json!({
+            "name": "a",
+            "id": "a",
+            "cost": 10,
+            "speed": 4,
+            "field": "test",
+            "required_any": [],
+            "required_all": [],
+            "description": "",
+            "max_level": 3,
+            "level_cost_growth": 50,
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_progress_is_loaded_by_id() {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let dir = std::env::temp_dir()
+            .join(format!("astray-progress-loaded-{}-{nanos}", std::process::id()));
+        let path = dir.join("assets/research_progress.json5");
+        std::fs::create_dir_all(dir.join("assets")).unwrap();
+        std::fs::write(dir.join("mod.json5"), r#"{ "name": "Head start" }"#).unwrap();
+        std::fs::write(&path, r#"{
+            "a": { "progress": 5, "is_going": true },
+            "removed": { "progress": 3, "is_going": false }
+        }"#).unwrap();
+        let assets = AssetLayers::new(vec![GameMod::load_from_dir(&dir).unwrap()]);
+        let state = ResearchState::load_from_assets(&assets, "research_progress.json5");
+        std::fs::write(&path, "{ \"a\": 5 }").unwrap();
+        let invalid = ResearchState::load_from_assets(&assets, "research_progress.json5");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let state = state.unwrap();
+        assert_eq!(state.get("a").get_progress(), 5);
+        assert!(state.get("a").is_in_progress());
+        assert_eq!(state.get("removed").get_progress(), 3);
+        assert_eq!(state.get("b").get_progress(), 0);
+        assert!(!state.get("b").is_in_progress());
+        assert!(invalid.is_err());
+    }
+
+    #[test]
+    fn test_progress_follows_the_definitions() {
+        let a = research("a", 10, 4);
+        let b = research("b", 10, 4);
+        let mut state = ResearchState::default();
+        state.start(String::from("a"));
+
+        for _ in 0..3 {
+            state.tick(&[a.clone(), b.clone()]);
+        }
+        assert_eq!(state.get("a").get_progress(), 10);
+        assert!(state.is_finished(&a) && !state.get("a").is_in_progress());
+        assert!(!state.is_finished(&b) && state.get("b").get_progress() == 0);
+
+        // A definition that got more expensive is not finished anymore, a cheaper one stays so
+        assert!(!state.is_finished(&research("a", 20, 4)));
+        assert!(state.is_finished(&research("a", 5, 4)));
+    }
+
+    #[test]
+    fn test_level_costs_grow() {
+        let research = leveled_research();
+        assert_eq!(research.get_cost_for_level(0), 10);
+        assert_eq!(research.get_cost_for_level(1), 15);
+        assert_eq!(research.get_cost_until_level(3), 48);
+    }
+
+    #[test]
+    fn test_research_stops_after_every_level() {
+        let researches = vec![leveled_research()];
+        let research = &researches[0];
+        let mut state = ResearchState::default();
+        state.start(research.id().clone());
+
+        for _ in 0..5 {
+            state.tick(&researches);
+        }
+        assert_eq!(state.get_level(research), 1);
+        assert!(state.is_researched(research));
+        assert!(!state.get(research.id()).is_in_progress());
+
+        state.add_progress(research, -100);
+        assert_eq!(state.get_level(research), 1);
+
+        state.complete_level(research);
+        state.complete_level(research);
+        assert!(state.is_finished(research));
+        assert_eq!(state.get(research.id()).get_progress_text(research),
+                   "Research complete [level 3/3]");
+    }
+}
\ No newline at end of file
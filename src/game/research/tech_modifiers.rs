@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// `ModifierType` is a game statistic that can be improved by researching technologies
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ModifierType {
+    /// Amount of resources extracted by mines
+    MiningEfficiency,
+    /// Amount of products made by factories
+    FactoryYield,
+    /// Thrust of sublight engines
+    SublightThrust,
+}
+
+impl From<ModifierType> for String {
+    fn from(value: ModifierType) -> Self {
+        match value {
+            ModifierType::MiningEfficiency => String::from("Mining efficiency"),
+            ModifierType::FactoryYield => String::from("Factory yield"),
+            ModifierType::SublightThrust => String::from("Sublight thrust"),
+        }
+    }
+}
+
+/// `ResearchEffect` describes how much every level of a technology improves a statistic
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ResearchEffect {
+    pub modifier: ModifierType,
+    /// Bonus granted by every researched level, in percent
+    pub per_level: i32,
+}
+
+/// `TemporaryModifier` is a bonus or a malus of an empire, which wears off after some days
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TemporaryModifier {
+    pub modifier: ModifierType,
+    pub percent: i32,
+    pub days_left: u32,
+}
+
+/// `TechModifiers` holds the combined bonuses of all the researched technologies
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TechModifiers {
+    percents: HashMap<ModifierType, i32>,
+}
+
+impl TechModifiers {
+    pub fn add(&mut self, modifier: ModifierType, percent: i32) {
+        *self.percents.entry(modifier).or_insert(0) += percent;
+    }
+
+    /// Returns the factor the statistic should be multiplied by, never below zero
+    pub fn get_multiplier(&self, modifier: ModifierType) -> f32 {
+        (1.0 + *self.percents.get(&modifier).unwrap_or(&0) as f32 / 100.0).max(0.0)
+    }
+
+    /// Returns the statistic in percent of its base value, never below zero
+    pub fn get_percent(&self, modifier: ModifierType) -> u32 {
+        (100 + *self.percents.get(&modifier).unwrap_or(&0)).max(0) as u32
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::research::research::{Research, ResearchField};
+
+/// `ResearchError` describes a single problem found while loading or validating research assets
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResearchError {
+    /// The file could not be read
+    Io { path: String, message: String },
+    /// The file contents are not valid research data
+    Parse { path: String, message: String },
+    /// Two research fields share the same id
+    DuplicateFieldId { id: String },
+    /// Two researches share the same id
+    DuplicateResearchId { id: String },
+    /// A research field is related to a building that does not exist
+    UnknownBuilding { field: String, building: String },
+    /// A research belongs to a field that does not exist
+    UnknownField { research: String, field: String },
+    /// A research requires a technology that does not exist
+    DanglingPrerequisite { research: String, prerequisite: String },
+    /// A chain of researches require each other, so none of them can ever be researched
+    CyclicPrerequisites { cycle: Vec<String> },
+    /// A research costs no points at all
+    NonPositiveCost { research: String },
+    /// A research has no levels, so it can never be researched
+    NoLevels { research: String },
+}
+
+impl fmt::Display for ResearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResearchError::Io { path, message } => {
+                write!(f, "{path}: unable to read file: {message}")
+            }
+            ResearchError::Parse { path, message } => {
+                write!(f, "{path}: unable to parse file: {message}")
+            }
+            ResearchError::DuplicateFieldId { id } => {
+                write!(f, "research field `{id}` is defined more than once")
+            }
+            ResearchError::DuplicateResearchId { id } => {
+                write!(f, "research `{id}` is defined more than once")
+            }
+            ResearchError::UnknownBuilding { field, building } => {
+                write!(f, "research field `{field}` is related to unknown building `{building}`")
+            }
+            ResearchError::UnknownField { research, field } => {
+                write!(f, "research `{research}` belongs to unknown field `{field}`")
+            }
+            ResearchError::DanglingPrerequisite { research, prerequisite } => {
+                write!(f, "research `{research}` requires unknown research `{prerequisite}`")
+            }
+            ResearchError::CyclicPrerequisites { cycle } => {
+                write!(f, "researches require each other: {}", cycle.join(" -> "))
+            }
+            ResearchError::NonPositiveCost { research } => {
+                write!(f, "research `{research}` must cost at least one point")
+            }
+            ResearchError::NoLevels { research } => {
+                write!(f, "research `{research}` must have at least one level")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResearchError {}
+
+/// Checks the loaded research fields and researches for inconsistencies
+///
+/// # Arguments
+/// * `fields` - The research fields, as loaded from the fields file
+/// * `researches` - All the researches, as loaded from the research file
+///
+/// # Returns
+/// * `Vec<ResearchError>` - All the problems found, empty if the data is valid
+pub fn validate_research(fields: &[ResearchField], researches: &[Research]) -> Vec<ResearchError> {
+    let mut errors = Vec::<ResearchError>::new();
+
+    let mut field_ids = HashSet::<&String>::new();
+    for field in fields {
+        if !field_ids.insert(field.id()) {
+            errors.push(ResearchError::DuplicateFieldId { id: field.id().clone() });
+        }
+    }
+
+    let building_names: Vec<String> = BuildingType::get_variants().iter()
+        .map(|(b, _)| b.get_name())
+        .collect();
+    for field in fields {
+        field.related_buildings().iter()
+            .filter(|b| !building_names.contains(b))
+            .for_each(|b| {
+                errors.push(ResearchError::UnknownBuilding {
+                    field: field.id().clone(),
+                    building: b.clone(),
+                })
+            });
+    }
+
+    let mut research_ids = HashSet::<&String>::new();
+    for research in researches {
+        if !research_ids.insert(research.id()) {
+            errors.push(ResearchError::DuplicateResearchId { id: research.id().clone() });
+        }
+    }
+
+    for research in researches {
+        if !field_ids.contains(research.field()) {
+            errors.push(ResearchError::UnknownField {
+                research: research.id().clone(),
+                field: research.field().clone(),
+            });
+        }
+
+        research.required_all().iter()
+            .chain(research.required_any().iter())
+            .filter(|id| !research_ids.contains(id))
+            .for_each(|id| {
+                errors.push(ResearchError::DanglingPrerequisite {
+                    research: research.id().clone(),
+                    prerequisite: id.clone(),
+                })
+            });
+
+        if *research.cost() == 0 {
+            errors.push(ResearchError::NonPositiveCost { research: research.id().clone() });
+        }
+
+        if *research.max_level() == 0 {
+            errors.push(ResearchError::NoLevels { research: research.id().clone() });
+        }
+    }
+
+    errors.extend(find_cycles(researches).into_iter().map(
+        |cycle| ResearchError::CyclicPrerequisites { cycle }
+    ));
+
+    errors
+}
+
+/// Finds every dependency cycle, reporting each one once, starting and ending with the same id
+fn find_cycles(researches: &[Research]) -> Vec<Vec<String>> {
+    let prerequisites: HashMap<&String, Vec<&String>> = researches.iter().map(|r| {
+        (r.id(), r.required_all().iter().chain(r.required_any().iter()).collect())
+    }).collect();
+
+    let mut cycles = Vec::<Vec<String>>::new();
+    let mut done = HashSet::<&String>::new();
+
+    fn visit<'a>(
+        id: &'a String,
+        prerequisites: &HashMap<&'a String, Vec<&'a String>>,
+        stack: &mut Vec<&'a String>,
+        done: &mut HashSet<&'a String>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        if let Some(start) = stack.iter().position(|s| *s == id) {
+            let mut cycle: Vec<String> = stack[start..].iter().map(|s| s.to_string()).collect();
+            cycle.push(id.clone());
+            cycles.push(cycle);
+            return;
+        }
+        if done.contains(id) {
+            return;
+        }
+
+        stack.push(id);
+        for next in prerequisites.get(id).into_iter().flatten() {
+            visit(next, prerequisites, stack, done, cycles);
+        }
+        stack.pop();
+        done.insert(id);
+    }
+
+    for research in researches {
+        visit(research.id(), &prerequisites, &mut Vec::new(), &mut done, &mut cycles);
+    }
+
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::research::research::{Research, ResearchField};
+    use crate::game::research::validation::{ResearchError, validate_research};
+
+    fn field(id: &str) -> ResearchField {
+        serde_json::from_value(serde_json::json!({
+            "name": id,
+            "id": id,
+            "researches": [],
+        })).unwrap()
+    }
+
+    fn research(id: &str, field: &str, cost: u32, all: &[&str], any: &[&str]) -> Research {
+        serde_json::from_value(serde_json::json!({
+            "name": id,
+            "id": id,
+            "cost": cost,
+            "speed": 1,
+            "field": field,
+            "required_any": any,
+            "required_all": all,
+            "description": "",
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_valid_data() {
+        let errors = validate_research(
+            &[field("f")],
+            &[research("a", "f", 10, &[], &[]), research("b", "f", 10, &["a"], &[])],
+        );
+        assert_eq!(errors, vec![]);
+    }
+
+    #[test]
+    fn test_reports_every_problem() {
+        let errors = validate_research(
+            &[field("f"), field("f")],
+            &[
+                research("a", "f", 0, &[], &["missing"]),
+                research("a", "g", 10, &[], &[]),
+            ],
+        );
+        assert_eq!(errors, vec![
+            ResearchError::DuplicateFieldId { id: "f".into() },
+            ResearchError::DuplicateResearchId { id: "a".into() },
+            ResearchError::DanglingPrerequisite { research: "a".into(), prerequisite: "missing".into() },
+            ResearchError::NonPositiveCost { research: "a".into() },
+            ResearchError::UnknownField { research: "a".into(), field: "g".into() },
+        ]);
+    }
+
+    #[test]
+    fn test_reports_cycles_once() {
+        let errors = validate_research(
+            &[field("f")],
+            &[
+                research("a", "f", 10, &["c"], &[]),
+                research("b", "f", 10, &["a"], &[]),
+                research("c", "f", 10, &[], &["b"]),
+                research("d", "f", 10, &["a"], &[]),
+            ],
+        );
+        assert_eq!(errors, vec![
+            ResearchError::CyclicPrerequisites {
+                cycle: vec!["a".into(), "c".into(), "b".into(), "a".into()],
+            },
+        ]);
+    }
+}
@@ -1,5 +1,5 @@
 use derive_getters::Getters;
-use rand::{prelude::*, Rng, thread_rng};
+use rand::{prelude::*, Rng};
 use rand::distributions::WeightedIndex;
 use ratatui::prelude::Color;
 use serde::{Deserialize, Serialize};
@@ -40,6 +40,30 @@ pub enum ResourceType {
     CFuelRods,
 }
 
+impl ResourceType {
+    pub fn get_variants() -> Vec<ResourceType> {
+        vec![
+            ResourceType::PRLightMetals,
+            ResourceType::PRHeavyMetals,
+            ResourceType::PRPreciousMetals,
+            ResourceType::PRWater,
+            ResourceType::PRCrudeOil,
+            ResourceType::PRSilicon,
+            ResourceType::SRKerosene,
+            ResourceType::SRElectronics,
+            ResourceType::SRPlastic,
+            ResourceType::SRSuperconductors,
+            ResourceType::SRHeatResistantAlloys,
+            ResourceType::SRComposites,
+            ResourceType::SRRadioactivePellets,
+            ResourceType::CEngineNozzles,
+            ResourceType::CMicroprocessors,
+            ResourceType::CSensors,
+            ResourceType::CFuelRods,
+        ]
+    }
+}
+
 impl Into<ResourceGrade> for ResourceType {
     fn into(self) -> ResourceGrade {
         match self {
@@ -100,6 +124,24 @@ impl ResourceTransaction {
     pub fn new(resource_type: ResourceType, amount: i32) -> Self {
         Self { resource_type, amount }
     }
+
+    /// Scales the transaction to a number of buildings, the produced amount also gets the yield
+    /// bonus while consumption stays the same
+    ///
+    /// # Arguments
+    /// * `count` - Number of buildings making the transaction
+    /// * `yield_percent` - Produced amount in percent of the base one
+    /// * `remainder` - Hundredths of a unit left over from earlier production, updated in place
+    pub fn scaled(self, count: u32, yield_percent: u32, remainder: &mut u32) -> Self {
+        let amount = self.amount * count as i32;
+        if amount > 0 {
+            let hundredths = amount as u32 * yield_percent + *remainder;
+            *remainder = hundredths % 100;
+            Self { amount: (hundredths / 100) as i32, ..self }
+        } else {
+            Self { amount, ..self }
+        }
+    }
 }
 
 impl From<BuildingType> for Option<Vec<ResourceTransaction>> {
@@ -203,7 +245,7 @@ pub struct ResourceDeposit {
 }
 
 impl ResourceDeposit {
-    pub fn generate_for_planet() -> Self {
+    pub fn generate_for_planet(rng: &mut impl Rng) -> Self {
         let mut values = [
             (ResourceType::PRLightMetals, 1),
             (ResourceType::PRHeavyMetals, 1),
@@ -213,8 +255,6 @@ impl ResourceDeposit {
             (ResourceType::PRSilicon, 1),
         ];
 
-        let mut rng = thread_rng();
-
         for _ in 0..(100 - values.len()) {
             let index = match rng.gen_range(1..=100) {
                 1..=20 => 0,
@@ -235,9 +275,12 @@ impl ResourceDeposit {
         }
     }
 
-    pub fn sample(&self) -> ResourceType {
-        let mut rng = thread_rng();
+    /// Returns the share of every raw resource in the deposit, in percent
+    pub fn get_amounts(&self) -> &Vec<(ResourceType, i32)> {
+        &self.amounts
+    }
 
+    pub fn sample(&self, rng: &mut impl Rng) -> ResourceType {
         let weights: Vec<i32> = self.amounts.iter().map(
             |(rt, w)| { w.clone() }
         ).collect();
@@ -250,7 +293,7 @@ impl ResourceDeposit {
             weights
         ).unwrap();
 
-        choices[dist.sample(&mut rng)].clone()
+        choices[dist.sample(rng)].clone()
     }
 }
 
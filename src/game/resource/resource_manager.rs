@@ -65,6 +65,10 @@ impl ResourceManager {
         }
     }
 
+    pub fn get_amount(&self, resource_type: &ResourceType) -> u32 {
+        *self.resources.get(resource_type).unwrap_or(&0)
+    }
+
     pub fn apply_many(&mut self, transactions: Vec<ResourceTransaction>) {
         if !transactions.iter().all(|rt| self.is_applicable(rt)) {
             return
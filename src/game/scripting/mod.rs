@@ -0,0 +1,3 @@
+pub mod script_api;
+pub mod script_event;
+pub mod script_manager;
\ No newline at end of file
@@ -0,0 +1,189 @@
+use std::sync::{Arc, Mutex};
+
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::colony::colony::Colony;
+use crate::game::empires::strategy::Order;
+use crate::game::event_log::LogEntry;
+use crate::game::events::event_effect::EventEffect;
+use crate::game::game_state::GameState;
+
+/// `ScriptCommand` is something a script asked for. The commands are carried out once the hook
+/// of the script returns
+#[derive(Clone, Debug)]
+pub enum ScriptCommand {
+    /// An order of the player, the same as the interface gives
+    Order(Order),
+    /// A custom rule, applied to a colony of the player the same way as an event option is
+    Effect { colony: String, effect: EventEffect },
+    /// A message to the event log of the player
+    Message(String),
+}
+
+/// `ScriptGame` is the `game` object the hooks of the scripts get. It holds a snapshot of what
+/// the player can see and collects the commands of the script
+///
+/// **Fields**
+/// - date (`i64`) - amount of days passed since the start of the game
+/// - capital (`String`) - name of the capital of the player
+/// - colonies (`Array`) - a map of the name, population, stability, resources, buildings and
+///   construction queue of every colony of the player
+/// - fleets (`Array`) - a map of the id, name, location and course of every fleet of the player
+/// - free_planets (`Array`) - known planets nobody settled yet, the closest to the capital
+///   first
+/// - researches (`Array`) - ids of the researches which can be started
+/// - researching (`Array`) - ids of the researches in progress
+/// - researched (`Array`) - ids of the researches with at least a level researched
+/// - commands (`Arc<Mutex<Vec<ScriptCommand>>>`) - the commands of the script, shared by every
+///   copy of the object the script makes
+#[derive(Clone)]
+pub struct ScriptGame {
+    date: i64,
+    capital: String,
+    colonies: Array,
+    fleets: Array,
+    free_planets: Array,
+    researches: Array,
+    researching: Array,
+    researched: Array,
+    commands: Arc<Mutex<Vec<ScriptCommand>>>,
+}
+
+fn to_array(values: Vec<String>) -> Array {
+    values.into_iter().map(Dynamic::from).collect()
+}
+
+fn get_colony_map(colony: &Colony) -> Map {
+    let mut resources = Map::new();
+    for (resource, amount) in colony.get_resources() {
+        resources.insert(format!("{resource:?}").into(), Dynamic::from(amount as i64));
+    }
+    let mut buildings = Map::new();
+    for (name, amount, _) in colony.get_buildings() {
+        buildings.insert(name.into(), Dynamic::from(amount as i64));
+    }
+    let construction: Array = colony.get_construction().into_iter()
+        .map(|(building, progress)| {
+            let mut entry = Map::new();
+            entry.insert("building".into(), Dynamic::from(building));
+            entry.insert("progress".into(), Dynamic::from(progress as i64));
+            Dynamic::from_map(entry)
+        })
+        .collect();
+
+    let mut map = Map::new();
+    map.insert("name".into(), Dynamic::from(colony.get_name()));
+    map.insert("population".into(), Dynamic::from(colony.get_population() as i64));
+    map.insert("stability".into(), Dynamic::from(colony.get_stability().get_value() as i64));
+    map.insert("resources".into(), Dynamic::from_map(resources));
+    map.insert("buildings".into(), Dynamic::from_map(buildings));
+    map.insert("construction".into(), Dynamic::from_array(construction));
+    map
+}
+
+/// Turns a log entry into the `event` object of the `on_event` hook
+pub fn get_event_map(entry: &LogEntry) -> Map {
+    let mut map = Map::new();
+    map.insert("date".into(), Dynamic::from(entry.get_date() as i64));
+    map.insert("category".into(), Dynamic::from(entry.get_category().get_name()));
+    map.insert("text".into(), Dynamic::from(entry.get_text().clone()));
+    map
+}
+
+impl ScriptGame {
+    pub fn new(state: &GameState) -> Self {
+        let view = state.get_player_view();
+        let research_manager = view.empire.get_research_manager();
+        Self {
+            date: view.date as i64,
+            capital: view.empire.get_capital().clone(),
+            colonies: view.empire.get_colonies().iter()
+                .map(|c| Dynamic::from_map(get_colony_map(c)))
+                .collect(),
+            fleets: view.fleets.iter()
+                .map(|f| {
+                    let mut map = Map::new();
+                    map.insert("id".into(), Dynamic::from(f.get_id() as i64));
+                    map.insert("name".into(), Dynamic::from(f.get_name()));
+                    map.insert("location".into(), Dynamic::from(f.get_location().clone()));
+                    map.insert("in_transit".into(), Dynamic::from(f.is_in_transit()));
+                    Dynamic::from_map(map)
+                })
+                .collect(),
+            free_planets: to_array(view.free_planets.clone()),
+            researches: to_array(view.researches.clone()),
+            researching: to_array(research_manager.get_researches_in_progress()),
+            researched: to_array(research_manager.get_researched()),
+            commands: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn push(&mut self, command: ScriptCommand) {
+        self.commands.lock().unwrap().push(command);
+    }
+
+    /// Takes the commands the script gave so far
+    pub fn take_commands(&self) -> Vec<ScriptCommand> {
+        std::mem::take(&mut *self.commands.lock().unwrap())
+    }
+}
+
+/// Makes the `game` object available to the scripts run by the engine
+pub fn register(engine: &mut Engine) {
+    engine.register_type_with_name::<ScriptGame>("Game")
+        .register_get("date", |g: &mut ScriptGame| g.date)
+        .register_get("capital", |g: &mut ScriptGame| g.capital.clone())
+        .register_get("colonies", |g: &mut ScriptGame| g.colonies.clone())
+        .register_get("fleets", |g: &mut ScriptGame| g.fleets.clone())
+        .register_get("free_planets", |g: &mut ScriptGame| g.free_planets.clone())
+        .register_get("researches", |g: &mut ScriptGame| g.researches.clone())
+        .register_get("researching", |g: &mut ScriptGame| g.researching.clone())
+        .register_get("researched", |g: &mut ScriptGame| g.researched.clone());
+
+    engine.register_fn("start_research", |g: &mut ScriptGame, research: &str| {
+        g.push(ScriptCommand::Order(Order::StartResearch(research.to_string())))
+    });
+    engine.register_fn(
+        "build",
+        |g: &mut ScriptGame, colony: &str, building: &str| -> Result<(), Box<EvalAltResult>> {
+            let building = BuildingType::from_name(building)
+                .ok_or_else(|| format!("unknown building `{building}`"))?;
+            g.push(ScriptCommand::Order(Order::StartConstruction {
+                colony: colony.to_string(),
+                building,
+            }));
+            Ok(())
+        },
+    );
+    engine.register_fn("build_ship", |g: &mut ScriptGame, colony: &str, design: &str| {
+        g.push(ScriptCommand::Order(Order::BuildShip {
+            colony: colony.to_string(),
+            design: design.to_string(),
+        }))
+    });
+    engine.register_fn("colonize", |g: &mut ScriptGame, from: &str, planet: &str| {
+        g.push(ScriptCommand::Order(Order::Colonize {
+            from: from.to_string(),
+            planet: planet.to_string(),
+        }))
+    });
+    engine.register_fn("move_fleet", |g: &mut ScriptGame, fleet: i64, destination: &str| {
+        g.push(ScriptCommand::Order(Order::MoveFleet {
+            fleet: fleet as u32,
+            destination: destination.to_string(),
+        }))
+    });
+    engine.register_fn(
+        "apply_effect",
+        |g: &mut ScriptGame, colony: &str, effect: Map| -> Result<(), Box<EvalAltResult>> {
+            let effect: EventEffect = rhai::serde::from_dynamic(&Dynamic::from_map(effect))?;
+            g.push(ScriptCommand::Effect { colony: colony.to_string(), effect });
+            Ok(())
+        },
+    );
+    engine.register_fn("notify", |g: &mut ScriptGame, text: &str| {
+        g.push(ScriptCommand::Message(text.to_string()))
+    });
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use ratatui::style::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::{EventCategory, GameEvent};
+
+/// `ScriptEvent` is something a script of the player has to say
+pub enum ScriptEvent {
+    /// A script called `notify`
+    Message { script: String, text: String },
+    /// A script ran into an error and is not run anymore
+    Failed { script: String, error: String },
+}
+
+impl Displayable for ScriptEvent {
+    fn get_name(&self) -> String {
+        match self {
+            ScriptEvent::Message { script, text } => format!("[{script}] {text}"),
+            ScriptEvent::Failed { script, error } => {
+                format!("Script {script} failed and was stopped: {error}")
+            }
+        }
+    }
+
+    fn get_menu_color(&self) -> Color {
+        match self {
+            ScriptEvent::Message { .. } => Color::White,
+            ScriptEvent::Failed { .. } => Color::LightRed,
+        }
+    }
+}
+
+impl GameEvent for ScriptEvent {
+    fn get_category(&self) -> EventCategory {
+        EventCategory::Script
+    }
+}
\ No newline at end of file
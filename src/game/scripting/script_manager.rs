@@ -0,0 +1,289 @@
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use rhai::{AST, CallFnOptions, Dynamic, Engine, Scope};
+
+use crate::game::empires::strategy::Order;
+use crate::game::event_log::LogEntry;
+use crate::game::game_options::GameOptions;
+use crate::game::game_state::GameState;
+use crate::game::mods::asset_layers::AssetLayers;
+use crate::game::scripting::script_api::{get_event_map, register, ScriptCommand, ScriptGame};
+use crate::game::scripting::script_event::ScriptEvent;
+
+pub const SCRIPTS_PATH: &str = "scripts";
+/// Amount of operations a hook may take, so a script stuck in a loop does not freeze the game
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// `Script` is a compiled script of the player
+///
+/// **Fields**
+/// - name (`String`) - name of the script file, without the extension
+/// - ast (`AST`) - the compiled script
+/// - scope (`Scope`) - the variables defined at the top level of the script
+/// - is_failed (`bool`) - `true` if the script ran into an error and is not run anymore
+struct Script {
+    name: String,
+    ast: AST,
+    scope: Scope<'static>,
+    is_failed: bool,
+}
+
+impl Script {
+    fn has_hook(&self, name: &str, params: usize) -> bool {
+        self.ast.iter_functions().any(|f| f.name == name && f.params.len() == params)
+    }
+}
+
+/// Reads the name and the source code of every `.rhai` file of a directory, in alphabetical order
+fn read_sources(dir: &Path) -> Result<Vec<(String, String)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new())
+    }
+
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .wrap_err_with(|| format!("{}: unable to read directory", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|e| e == "rhai"))
+        .collect();
+    paths.sort();
+
+    let mut sources = Vec::new();
+    for path in paths {
+        let source = std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("{}: unable to read file", path.display()))?;
+        let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        sources.push((name, source));
+    }
+    Ok(sources)
+}
+
+/// `ScriptManager` runs the scripts which play along with the player. A script is a Rhai file
+/// defining the hooks `on_tick(game)` and `on_event(game, event)`, both are optional
+///
+/// **Fields**
+/// - engine (`Engine`) - runs the scripts
+/// - scripts (`Vec<Script>`) - the loaded scripts, in the alphabetical order of their files, the
+///   ones of the mods last
+pub struct ScriptManager {
+    engine: Engine,
+    scripts: Vec<Script>,
+}
+
+impl Default for ScriptManager {
+    fn default() -> Self {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        engine.on_print(|text| log::info!("{text}"));
+        register(&mut engine);
+        Self { engine, scripts: Vec::new() }
+    }
+}
+
+impl ScriptManager {
+    /// Loads the scripts of the player and the ones of the mods the assets are loaded from. The
+    /// scripts of a mod are named after the mod, e.g. `more-pirates/raids`
+    pub fn new(assets: &AssetLayers) -> Result<Self> {
+        let mut sources = read_sources(Path::new(SCRIPTS_PATH))?;
+        for game_mod in assets.get_mods() {
+            let prefix = game_mod.get_id();
+            sources.extend(
+                read_sources(&game_mod.get_scripts_dir())?.into_iter()
+                    .map(|(name, source)| (format!("{prefix}/{name}"), source))
+            );
+        }
+        Self::from_sources(sources)
+    }
+
+    /// Loads the scripts, unless the options of the game turn them off
+    pub fn for_options(options: &GameOptions, assets: &AssetLayers) -> Result<Self> {
+        if options.scripts {
+            Self::new(assets)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Loads every `.rhai` file of a directory, the subdirectories are skipped. No scripts are
+    /// loaded if the directory does not exist
+    pub fn load_from_dir(dir: &Path) -> Result<Self> {
+        Self::from_sources(read_sources(dir)?)
+    }
+
+    /// Compiles the scripts and runs their top level statements
+    ///
+    /// # Arguments
+    /// * `sources` - The name and the source code of every script
+    pub fn from_sources(sources: Vec<(String, String)>) -> Result<Self> {
+        let mut manager = Self::default();
+        for (name, source) in sources {
+            let ast = manager.engine.compile(source)
+                .map_err(|e| eyre!("script `{name}` is invalid: {e}"))?;
+            let mut scope = Scope::new();
+            manager.engine.run_ast_with_scope(&mut scope, &ast)
+                .map_err(|e| eyre!("script `{name}` failed: {e}"))?;
+            manager.scripts.push(Script { name, ast, scope, is_failed: false });
+        }
+        Ok(manager)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Runs the hooks of every script for the day and carries out the commands of the scripts
+    ///
+    /// # Arguments
+    /// * `state` - The game, after the day has passed
+    /// * `entries` - The events logged during the day, every one is passed to `on_event`
+    ///
+    /// # Returns
+    /// * `Vec<LogEntry>` - The messages and the failures of the scripts
+    pub fn run(&mut self, state: &mut GameState, entries: &[LogEntry]) -> Vec<LogEntry> {
+        let mut logged = Vec::new();
+        for index in 0..self.scripts.len() {
+            let mut calls: Vec<(&str, Option<&LogEntry>)> = Vec::new();
+            if self.scripts[index].has_hook("on_tick", 1) {
+                calls.push(("on_tick", None));
+            }
+            if self.scripts[index].has_hook("on_event", 2) {
+                calls.extend(entries.iter().map(|e| ("on_event", Some(e))));
+            }
+
+            for (hook, entry) in calls {
+                if self.scripts[index].is_failed {
+                    break
+                }
+                let game = ScriptGame::new(state);
+                let mut args = vec![Dynamic::from(game.clone())];
+                if let Some(entry) = entry {
+                    args.push(Dynamic::from_map(get_event_map(entry)));
+                }
+
+                let script = &mut self.scripts[index];
+                let result = self.engine.call_fn_with_options::<Dynamic>(
+                    CallFnOptions::new().eval_ast(false),
+                    &mut script.scope,
+                    &script.ast,
+                    hook,
+                    args,
+                );
+                if let Err(error) = result {
+                    script.is_failed = true;
+                    logged.push(state.log_event(&ScriptEvent::Failed {
+                        script: script.name.clone(),
+                        error: error.to_string(),
+                    }));
+                    break
+                }
+
+                for command in game.take_commands() {
+                    match command {
+                        ScriptCommand::Order(order) => {
+                            state.give_order(order);
+                        }
+                        ScriptCommand::Effect { colony, effect } => {
+                            state.give_order(Order::ApplyEffect { colony, effect });
+                        }
+                        ScriptCommand::Message(text) => {
+                            logged.push(state.log_event(&ScriptEvent::Message {
+                                script: script.name.clone(),
+                                text,
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+        logged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::game::game_options::GameOptions;
+    use crate::game::game_state::GameState;
+    use crate::game::resource::resource::ResourceType;
+    use crate::game::scripting::script_manager::ScriptManager;
+
+    fn get_state() -> GameState {
+        GameState::new(GameOptions {
+            pirate_raids: false,
+            random_events: false,
+            computer_empires: 0,
+            seed: Some(3),
+            ..GameOptions::default()
+        }).unwrap()
+    }
+
+    #[test]
+    fn test_scripts_give_orders_and_apply_effects() {
+        let mut manager = ScriptManager::from_sources(vec![(
+            String::from("governor"),
+            String::from(r#"
+                const WATER = 250;
+
+                fn on_tick(game) {
+                    if game.date == 1 {
+                        game.build(game.capital, "Mine");
+                        game.apply_effect(game.capital, #{
+                            type: "Resource", resource: "PRWater", amount: WATER
+                        });
+                        game.notify("Day one");
+                    }
+                }
+            "#),
+        )]).unwrap();
+        let mut state = get_state();
+        let capital = state.get_colonies()[0].clone();
+
+        let entries = state.tick();
+        let logged = manager.run(&mut state, &entries);
+
+        let colony = state.get_colonies()[0].clone();
+        assert_eq!(colony.get_construction().len(), capital.get_construction().len() + 1);
+        assert_eq!(
+            colony.get_resource_amount(&ResourceType::PRWater),
+            capital.get_resource_amount(&ResourceType::PRWater) + 250,
+        );
+        assert_eq!(logged.len(), 1);
+        assert_eq!(logged[0].get_text(), "[governor] Day one");
+    }
+
+    #[test]
+    fn test_failing_scripts_are_stopped() {
+        let mut manager = ScriptManager::from_sources(vec![(
+            String::from("broken"),
+            String::from(r#"fn on_tick(game) { game.build(game.capital, "Moon base"); }"#),
+        )]).unwrap();
+        let mut state = get_state();
+
+        assert_eq!(manager.run(&mut state, &[]).len(), 1);
+        assert!(manager.run(&mut state, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_example_scripts_run() {
+        let mut manager = ScriptManager::load_from_dir(Path::new("scripts/examples")).unwrap();
+        let mut state = get_state();
+        for _ in 0..5 {
+            let entries = state.tick();
+            manager.run(&mut state, &entries);
+        }
+
+        assert_eq!(manager.scripts.len(), 2);
+        assert!(manager.scripts.iter().all(|s| !s.is_failed));
+        let research_manager = state.get_player().get_research_manager();
+        assert!(!research_manager.get_researches_in_progress().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_scripts_are_rejected() {
+        let sources = vec![(String::from("typo"), String::from("fn on_tick(game) {"))];
+        assert!(ScriptManager::from_sources(sources).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+/// `SensorSource` is anything looking around for other objects, like a fleet or a colony
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensorSource {
+    /// Position of the source relative to the star of the system, in AU
+    pub position: (f64, f64),
+    pub strength: f32,
+}
+
+/// Calculates how far a sensor can see an object. The range grows with the square root of the
+/// signature, so an object has to be four times as large to be seen twice as far.
+///
+/// # Arguments
+/// * `strength` - Strength of the sensor, which is its range against a signature of 1
+/// * `signature` - Signature of the object looked for
+///
+/// # Returns
+/// * `f64` - The detection range, in AU
+pub fn get_detection_range(strength: f32, signature: f32) -> f64 {
+    (strength.max(0.0) * signature.max(0.0).sqrt()) as f64
+}
+
+/// Tells whether any of the sources detects an object. Objects sharing a position with a source
+/// are always detected.
+///
+/// # Arguments
+/// * `sources` - The sensors looking for the object
+/// * `position` - Position of the object, in AU
+/// * `signature` - Signature of the object
+pub fn is_detected(sources: &[SensorSource], position: (f64, f64), signature: f32) -> bool {
+    sources.iter().any(|source| {
+        let distance = (source.position.0 - position.0).hypot(source.position.1 - position.1);
+        distance <= get_detection_range(source.strength, signature)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::sensors::detection::{is_detected, SensorSource};
+
+    #[test]
+    fn test_larger_signature_is_detected_further() {
+        let sources = vec![SensorSource { position: (0.0, 0.0), strength: 2.0 }];
+
+        assert!(is_detected(&sources, (3.0, 4.0), 9.0));
+        assert!(!is_detected(&sources, (3.0, 4.0), 4.0));
+    }
+
+    #[test]
+    fn test_blind_source_sees_its_own_position() {
+        let sources = vec![SensorSource { position: (1.0, 1.0), strength: 0.0 }];
+
+        assert!(is_detected(&sources, (1.0, 1.0), 1.0));
+        assert!(!is_detected(&sources, (1.0, 1.1), 100.0));
+    }
+}
\ No newline at end of file
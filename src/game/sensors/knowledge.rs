@@ -0,0 +1,194 @@
+use std::collections::{HashMap, HashSet};
+
+use ordered_float::OrderedFloat;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::event_log::format_date;
+use crate::game::exploration::body_survey::{BodySurvey, SurveyKind};
+use crate::game::fleets::fleet::{Fleet, PLAYER_OWNER};
+
+/// Amount of days a lost contact is remembered for
+const CONTACT_MEMORY: u64 = 90;
+
+/// `Contact` is a fleet someone has detected, along with where it was seen last
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    fleet_id: u32,
+    name: String,
+    owner: String,
+    /// Name of the celestial body the fleet was orbiting
+    location: String,
+    /// Position the fleet was seen at, in AU
+    position: (OrderedFloat<f64>, OrderedFloat<f64>),
+    last_seen: u64,
+    /// `true` if the fleet is detected right now, `false` if it was lost
+    is_tracked: bool,
+}
+
+impl Contact {
+    pub fn new(fleet: &Fleet, position: (f64, f64), date: u64) -> Self {
+        Self {
+            fleet_id: fleet.get_id(),
+            name: fleet.get_name(),
+            owner: fleet.get_owner().clone(),
+            location: fleet.get_location().clone(),
+            position: (OrderedFloat(position.0), OrderedFloat(position.1)),
+            last_seen: date,
+            is_tracked: true,
+        }
+    }
+
+    pub fn get_fleet_id(&self) -> u32 {
+        self.fleet_id
+    }
+
+    pub fn get_position(&self) -> (f64, f64) {
+        (self.position.0.into_inner(), self.position.1.into_inner())
+    }
+
+    pub fn is_tracked(&self) -> bool {
+        self.is_tracked
+    }
+}
+
+impl Displayable for Contact {
+    fn get_name(&self) -> String {
+        if self.is_tracked {
+            self.name.clone()
+        } else {
+            format!("{}?", self.name)
+        }
+    }
+
+    fn get_properties(&self) -> Vec<Vec<String>> {
+        vec![
+            vec![format!("Owner: {}", self.owner)],
+            vec![format!("Location: {}", self.location)],
+            vec![format!("Last seen: {}", format_date(self.last_seen))],
+        ]
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if !self.is_tracked {
+            Color::DarkGray
+        } else if self.owner == PLAYER_OWNER {
+            Color::LightGreen
+        } else {
+            Color::LightRed
+        }
+    }
+}
+
+/// `Knowledge` holds everything a single owner has learned with its sensors
+///
+/// **Fields**
+/// - known_bodies (`HashSet<String>`) - names of the planets that have ever been detected
+/// - known_jump_points (`HashSet<String>`) - names of the jump points found by surveys
+/// - surveys (`HashMap<String, BodySurvey>`) - progress of the surveys, by planet name
+/// - contacts (`HashMap<u32, Contact>`) - fleets detected recently, by fleet id
+/// - met_owners (`HashSet<String>`) - owners whose fleets or colonies have ever been detected
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Knowledge {
+    known_bodies: HashSet<String>,
+    known_jump_points: HashSet<String>,
+    surveys: HashMap<String, BodySurvey>,
+    contacts: HashMap<u32, Contact>,
+    #[serde(default)]
+    met_owners: HashSet<String>,
+}
+
+impl Knowledge {
+    pub fn knows_body(&self, name: &str) -> bool {
+        self.known_bodies.contains(name)
+    }
+
+    /// Remembers a planet. Orbits are predictable, so a planet stays on the map once detected.
+    pub fn learn_body(&mut self, name: String) {
+        self.known_bodies.insert(name);
+    }
+
+    pub fn has_met(&self, owner: &str) -> bool {
+        self.met_owners.contains(owner)
+    }
+
+    pub fn meet(&mut self, owner: String) {
+        self.met_owners.insert(owner);
+    }
+
+    pub fn knows_jump_point(&self, name: &str) -> bool {
+        self.known_jump_points.contains(name)
+    }
+
+    pub fn learn_jump_point(&mut self, name: String) {
+        self.known_jump_points.insert(name);
+    }
+
+    pub fn get_survey(&self, body: &str) -> BodySurvey {
+        self.surveys.get(body).cloned().unwrap_or_default()
+    }
+
+    /// Advances a survey of a planet
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the survey was completed by this progress
+    pub fn add_survey_progress(&mut self, body: &str, kind: SurveyKind, points: u32) -> bool {
+        self.surveys.entry(body.to_string()).or_default().add_progress(kind, points)
+    }
+
+    /// Marks a planet as known and completely surveyed
+    pub fn complete_survey(&mut self, body: String) {
+        self.surveys.insert(body.clone(), BodySurvey::complete());
+        self.known_bodies.insert(body);
+    }
+
+    pub fn is_tracked(&self, fleet_id: u32) -> bool {
+        self.contacts.get(&fleet_id).is_some_and(|c| c.is_tracked)
+    }
+
+    /// Replaces the contacts with the ones detected now. The fleets which were not detected
+    /// again keep their last known position, until they are forgotten.
+    ///
+    /// # Arguments
+    /// * `detected` - Contacts with the fleets detected this tick
+    /// * `date` - The current in-game date
+    pub fn update_contacts(&mut self, detected: Vec<Contact>, date: u64) {
+        self.contacts.values_mut().for_each(|c| c.is_tracked = false);
+        self.contacts.extend(detected.into_iter().map(|c| (c.fleet_id, c)));
+        self.contacts.retain(|_, c| c.is_tracked || date - c.last_seen <= CONTACT_MEMORY);
+    }
+
+    /// Returns every contact, ordered by fleet id
+    pub fn get_contacts(&self) -> Vec<Contact> {
+        let mut contacts: Vec<Contact> = self.contacts.values().cloned().collect();
+        contacts.sort_by_key(|c| c.fleet_id);
+        contacts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::fleets::fleet::Fleet;
+    use crate::game::sensors::knowledge::{Contact, Knowledge};
+
+    #[test]
+    fn test_lost_contacts_are_kept_then_forgotten() {
+        let fleet = Fleet::new(
+            7, String::from("Raid"), String::from("pirates"), String::from("Earth"), vec![],
+        );
+        let mut knowledge = Knowledge::default();
+
+        knowledge.update_contacts(vec![Contact::new(&fleet, (1.0, 0.0), 10)], 10);
+        assert!(knowledge.is_tracked(7));
+
+        knowledge.update_contacts(vec![], 11);
+        assert!(!knowledge.is_tracked(7));
+        assert_eq!(knowledge.get_contacts()[0].get_position(), (1.0, 0.0));
+
+        knowledge.update_contacts(vec![], 200);
+        assert_eq!(knowledge.get_contacts(), vec![]);
+    }
+}
\ No newline at end of file
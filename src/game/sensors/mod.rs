@@ -0,0 +1,2 @@
+pub mod detection;
+pub mod knowledge;
\ No newline at end of file
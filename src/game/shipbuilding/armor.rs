@@ -0,0 +1,44 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `Armor` is the outer layer of a ship. It takes hits before any other module does, and only
+/// lets through the part of the damage it could not stop
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct Armor {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    /// Damage of every hit which is stopped completely while the plating holds
+    reduction: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for Armor {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for Armor {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::armor::Armor;
+use crate::game::shipbuilding::point_defense::PointDefense;
+use crate::game::shipbuilding::reactor::Reactor;
+use crate::game::shipbuilding::sensor::Sensor;
+use crate::game::shipbuilding::ship_module::{ShipModule, ShipModuleType};
+use crate::game::shipbuilding::shield::Shield;
+use crate::game::shipbuilding::sublight_engine::SublightEngine;
+use crate::game::shipbuilding::survey_sensor::SurveySensor;
+use crate::game::shipbuilding::troop_bay::TroopBay;
+use crate::game::shipbuilding::weapon::Weapon;
+
+/// `ModuleKind` is a module definition of any module family
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ModuleKind {
+    SublightEngine(SublightEngine),
+    Weapon(Weapon),
+    Armor(Armor),
+    Shield(Shield),
+    PointDefense(PointDefense),
+    Reactor(Reactor),
+    Sensor(Sensor),
+    SurveySensor(SurveySensor),
+    TroopBay(TroopBay),
+}
+
+impl ModuleKind {
+    pub fn get_type(&self) -> ShipModuleType {
+        match self {
+            ModuleKind::SublightEngine(_) => ShipModuleType::SublightThruster,
+            ModuleKind::Weapon(_) => ShipModuleType::Weapon,
+            ModuleKind::Armor(_) => ShipModuleType::Armor,
+            ModuleKind::Shield(_) => ShipModuleType::Shield,
+            ModuleKind::PointDefense(_) => ShipModuleType::PointDefense,
+            ModuleKind::Reactor(_) => ShipModuleType::Reactor,
+            ModuleKind::Sensor(_) => ShipModuleType::Sensor,
+            ModuleKind::SurveySensor(_) => ShipModuleType::SurveySensor,
+            ModuleKind::TroopBay(_) => ShipModuleType::TroopBay,
+        }
+    }
+
+    fn get_max_hit_points(&self) -> u32 {
+        match self {
+            ModuleKind::SublightEngine(m) => m.get_hit_points(),
+            ModuleKind::Weapon(m) => m.get_hit_points(),
+            ModuleKind::Armor(m) => m.get_hit_points(),
+            ModuleKind::Shield(m) => m.get_hit_points(),
+            ModuleKind::PointDefense(m) => m.get_hit_points(),
+            ModuleKind::Reactor(m) => m.get_hit_points(),
+            ModuleKind::Sensor(m) => m.get_hit_points(),
+            ModuleKind::SurveySensor(m) => m.get_hit_points(),
+            ModuleKind::TroopBay(m) => m.get_hit_points(),
+        }
+    }
+}
+
+impl Displayable for ModuleKind {
+    fn get_name(&self) -> String {
+        match self {
+            ModuleKind::SublightEngine(m) => m.get_name(),
+            ModuleKind::Weapon(m) => m.get_name(),
+            ModuleKind::Armor(m) => m.get_name(),
+            ModuleKind::Shield(m) => m.get_name(),
+            ModuleKind::PointDefense(m) => m.get_name(),
+            ModuleKind::Reactor(m) => m.get_name(),
+            ModuleKind::Sensor(m) => m.get_name(),
+            ModuleKind::SurveySensor(m) => m.get_name(),
+            ModuleKind::TroopBay(m) => m.get_name(),
+        }
+    }
+}
+
+/// `InstalledModule` is a module built into a ship, which can be damaged and destroyed
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InstalledModule {
+    module: ModuleKind,
+    hit_points: u32,
+}
+
+impl InstalledModule {
+    pub fn new(module: ModuleKind) -> Self {
+        Self {
+            hit_points: module.get_max_hit_points(),
+            module,
+        }
+    }
+
+    pub fn get_module(&self) -> &ModuleKind {
+        &self.module
+    }
+
+    pub fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+
+    pub fn get_max_hit_points(&self) -> u32 {
+        self.module.get_max_hit_points()
+    }
+
+    /// A destroyed module stays on the ship, but does not work anymore
+    pub fn is_destroyed(&self) -> bool {
+        self.hit_points == 0
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.hit_points < self.get_max_hit_points()
+    }
+
+    /// A damaged module works worse the more damaged it is
+    ///
+    /// # Returns
+    /// * `f32` - Share of the module's statistics it still provides, between 0 and 1
+    pub fn get_efficiency(&self) -> f32 {
+        self.hit_points as f32 / self.get_max_hit_points().max(1) as f32
+    }
+
+    /// Restores hit points of the module, up to its maximum
+    ///
+    /// # Returns
+    /// * `u32` - Amount of hit points actually restored
+    pub fn repair(&mut self, points: u32) -> u32 {
+        let repaired = points.min(self.get_max_hit_points() - self.hit_points);
+        self.hit_points += repaired;
+        repaired
+    }
+
+    /// Destroys the module at once
+    pub fn destroy(&mut self) {
+        self.hit_points = 0;
+    }
+
+    /// Damages the module
+    ///
+    /// # Arguments
+    /// * `damage` - Amount of damage dealt to the module
+    ///
+    /// # Returns
+    /// * `u32` - Amount of damage left after the module was destroyed
+    pub fn take_damage(&mut self, damage: u32) -> u32 {
+        let taken = damage.min(self.hit_points);
+        self.hit_points -= taken;
+        damage - taken
+    }
+}
+
+impl Displayable for InstalledModule {
+    fn get_name(&self) -> String {
+        self.module.get_name()
+    }
+}
\ No newline at end of file
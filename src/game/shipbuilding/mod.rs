@@ -1,4 +1,13 @@
 pub mod ship_module;
-mod sublight_engine;
+pub mod sublight_engine;
 mod module_trait;
-pub mod ship_module_manager;
\ No newline at end of file
+pub mod ship_module_manager;
+pub mod weapon;
+pub mod armor;
+pub mod shield;
+pub mod point_defense;
+pub mod installed_module;
+pub mod reactor;
+pub mod sensor;
+pub mod survey_sensor;
+pub mod troop_bay;
\ No newline at end of file
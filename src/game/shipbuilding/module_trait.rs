@@ -1,6 +1,15 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ModuleTrait {
     name: String,
+    /// Contribution of the trait to the module's main statistic
+    #[serde(default)]
+    value: f32,
+}
+
+impl ModuleTrait {
+    pub fn get_value(&self) -> f32 {
+        self.value
+    }
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `PointDefense` shoots down incoming missiles aimed at any ship of its side
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct PointDefense {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    /// Amount of missiles the module can engage every combat round
+    shots: u32,
+    /// Chance to shoot down an engaged missile, between 0 and 1
+    interception: f32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for PointDefense {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for PointDefense {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
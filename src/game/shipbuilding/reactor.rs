@@ -0,0 +1,47 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `Reactor` generates the power the weapons and shields of a ship need
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct Reactor {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    power_output: u32,
+    /// Chance of a meltdown when the reactor is hit while fully damaged, a lightly damaged
+    /// reactor melts down less often
+    damage_meltdown_chance: f32,
+    /// Damage a meltdown deals to the ship it happens on
+    meltdown_damage: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for Reactor {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for Reactor {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `SensorMode` tells how a sensor looks for other objects
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SensorMode {
+    /// Listens for the emissions of other objects, does not give the ship away
+    Passive,
+    /// Sends out pulses and listens for the echoes, reaching further but making the ship
+    /// itself much easier to spot
+    Active,
+}
+
+/// `Sensor` lets a ship detect other fleets and celestial bodies
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct Sensor {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    mode: SensorMode,
+    /// Detection range against an object with a signature of 1, in AU
+    strength: f32,
+    /// Power needed to run the module at full capacity
+    #[serde(default)]
+    power_draw: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for Sensor {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for Sensor {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
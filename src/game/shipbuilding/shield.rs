@@ -0,0 +1,48 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `Shield` absorbs incoming damage until depleted and recharges between combat rounds
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct Shield {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    /// Amount of damage the shield can absorb when fully charged
+    capacity: u32,
+    /// Amount of capacity restored every combat round
+    recharge: u32,
+    /// Power needed to run the module at full capacity
+    #[serde(default)]
+    power_draw: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for Shield {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for Shield {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
@@ -1,23 +1,50 @@
 use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
 use serde::de::DeserializeOwned;
 
 use crate::game::celestial_bodies::Displayable;
+use crate::game::mods::asset_layers::{AssetError, AssetLayers};
 use crate::game::shipbuilding::module_trait::ModuleTrait;
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ShipModuleType {
     SublightThruster,
+    Weapon,
+    Armor,
+    Shield,
+    PointDefense,
+    Reactor,
+    Sensor,
+    SurveySensor,
+    TroopBay,
 }
 
 impl Displayable for ShipModuleType {
     fn get_name(&self) -> String {
         match self {
             ShipModuleType::SublightThruster => { "Sublight Thruster" }
+            ShipModuleType::Weapon => { "Weapon" }
+            ShipModuleType::Armor => { "Armor" }
+            ShipModuleType::Shield => { "Shield" }
+            ShipModuleType::PointDefense => { "Point Defense" }
+            ShipModuleType::Reactor => { "Reactor" }
+            ShipModuleType::Sensor => { "Sensor" }
+            ShipModuleType::SurveySensor => { "Survey Sensor" }
+            ShipModuleType::TroopBay => { "Troop Bay" }
         }.to_string()
     }
 
     fn get_menu_color(&self) -> Color {
         match self {
             ShipModuleType::SublightThruster => { Color::Indexed(75) }
+            ShipModuleType::Weapon => { Color::LightRed }
+            ShipModuleType::Armor => { Color::Gray }
+            ShipModuleType::Shield => { Color::LightCyan }
+            ShipModuleType::PointDefense => { Color::LightYellow }
+            ShipModuleType::Reactor => { Color::LightGreen }
+            ShipModuleType::Sensor => { Color::LightMagenta }
+            ShipModuleType::SurveySensor => { Color::Magenta }
+            ShipModuleType::TroopBay => { Color::Green }
         }
     }
 }
@@ -26,20 +53,43 @@ impl From<String> for ShipModuleType {
     fn from(value: String) -> Self {
         match value.as_str() {
             "Sublight Thruster" => { Self::SublightThruster }
+            "Weapon" => { Self::Weapon }
+            "Armor" => { Self::Armor }
+            "Shield" => { Self::Shield }
+            "Point Defense" => { Self::PointDefense }
+            "Reactor" => { Self::Reactor }
+            "Sensor" => { Self::Sensor }
+            "Survey Sensor" => { Self::SurveySensor }
+            "Troop Bay" => { Self::TroopBay }
             _ => panic!()
         }
     }
 }
 
+/// Amount of hit points a module has if its definition does not say otherwise
+pub fn default_hit_points() -> u32 {
+    10
+}
+
 pub trait ShipModule {
     fn get_traits(&self) -> Vec<ModuleTrait>;
 
-    fn load_from_file<T>(filepath: &str) -> Vec<T>
+    /// Returns the amount of damage the module can take before it is destroyed
+    fn get_hit_points(&self) -> u32;
+
+    /// Loads the modules of the base game and the enabled mods
+    ///
+    /// # Returns
+    /// * `Result<Vec<T>, AssetError>` - The modules, or why the file could not be loaded
+    fn load_from_assets<T>(assets: &AssetLayers, asset: &str) -> Result<Vec<T>, AssetError>
     where
         T: ShipModule + DeserializeOwned,
     {
-        let file_contents = std::fs::read_to_string(filepath).unwrap();
+        let file_contents = assets.read_records(asset)?;
 
-        serde_json::from_str(&file_contents).unwrap()
+        serde_json::from_str(&file_contents).map_err(|e| AssetError::Parse {
+            path: asset.to_string(),
+            message: e.to_string(),
+        })
     }
 }
@@ -1,24 +1,93 @@
+use ratatui::prelude::Color;
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::fleets::ship::Ship;
+use crate::game::fleets::ship_design::ShipDesign;
+use crate::game::mods::asset_layers::{AssetError, AssetLayers};
+use crate::game::research::tech_modifiers::TechModifiers;
+use crate::game::shipbuilding::armor::Armor;
+use crate::game::shipbuilding::installed_module::ModuleKind;
+use crate::game::shipbuilding::point_defense::PointDefense;
+use crate::game::shipbuilding::reactor::Reactor;
+use crate::game::shipbuilding::sensor::{Sensor, SensorMode};
 use crate::game::shipbuilding::ship_module::{ShipModule, ShipModuleType};
+use crate::game::shipbuilding::shield::Shield;
 use crate::game::shipbuilding::sublight_engine::SublightEngine;
+use crate::game::shipbuilding::survey_sensor::SurveySensor;
+use crate::game::shipbuilding::troop_bay::TroopBay;
+use crate::game::shipbuilding::weapon::Weapon;
 
 pub struct ShipModuleManager {
     sublight_engines: Vec<SublightEngine>,
+    weapons: Vec<Weapon>,
+    armor: Vec<Armor>,
+    shields: Vec<Shield>,
+    point_defenses: Vec<PointDefense>,
+    reactors: Vec<Reactor>,
+    sensors: Vec<Sensor>,
+    survey_sensors: Vec<SurveySensor>,
+    troop_bays: Vec<TroopBay>,
 }
 
-const SHIP_MODULES_PATH: &str = "./assets/ship_modules/";
+const SHIP_MODULES_PATH: &str = "ship_modules/";
 
 impl ShipModuleManager {
-    pub fn new() -> Self {
-        Self {
-            sublight_engines: SublightEngine::load_from_file(
+    /// Loads the modules of the base game and the enabled mods
+    ///
+    /// # Returns
+    /// * `Result<Self, AssetError>` - The modules, or the first file that failed to load
+    pub fn new(assets: &AssetLayers) -> Result<Self, AssetError> {
+        Ok(Self {
+            sublight_engines: SublightEngine::load_from_assets(
+                assets,
                 (SHIP_MODULES_PATH.to_string() + "sublight_engines.json5").as_str()
-            ),
-        }
+            )?,
+            weapons: Weapon::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "weapons.json5").as_str()
+            )?,
+            armor: Armor::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "armor.json5").as_str()
+            )?,
+            shields: Shield::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "shields.json5").as_str()
+            )?,
+            point_defenses: PointDefense::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "point_defense.json5").as_str()
+            )?,
+            reactors: Reactor::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "reactors.json5").as_str()
+            )?,
+            sensors: Sensor::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "sensors.json5").as_str()
+            )?,
+            survey_sensors: SurveySensor::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "survey_sensors.json5").as_str()
+            )?,
+            troop_bays: TroopBay::load_from_assets(
+                assets,
+                (SHIP_MODULES_PATH.to_string() + "troop_bays.json5").as_str()
+            )?,
+        })
     }
 
     pub fn get_ship_module_types(&self) -> Vec<ShipModuleType> {
         vec![
             ShipModuleType::SublightThruster,
+            ShipModuleType::Weapon,
+            ShipModuleType::Armor,
+            ShipModuleType::Shield,
+            ShipModuleType::PointDefense,
+            ShipModuleType::Reactor,
+            ShipModuleType::Sensor,
+            ShipModuleType::SurveySensor,
+            ShipModuleType::TroopBay,
         ]
     }
 
@@ -26,11 +95,154 @@ impl ShipModuleManager {
         ShipModuleType::from(name)
     }
 
-    // pub fn get_ship_modules_by_type<T: ShipModule>(&self, module_type: ShipModuleType) -> Vec<T> {
-    //     let modules = match module_type {
-    //         ShipModuleType::SublightThruster => { self.sublight_engines.clone() }
-    //     };
-    // 
-    //     modules
-    // }
+    /// Lists the modules of a type along with their main statistic
+    ///
+    /// # Arguments
+    /// * `module_type` - The type of the modules
+    /// * `modifiers` - Bonuses of the researched technologies
+    pub fn get_ship_modules_by_type(
+        &self,
+        module_type: ShipModuleType,
+        modifiers: &TechModifiers,
+    ) -> Vec<(String, Color)> {
+        match module_type {
+            ShipModuleType::SublightThruster => {
+                self.sublight_engines.iter().map(|e| {
+                    (
+                        format!("{} ({:.1} thrust)", e.get_name(), e.get_thrust(modifiers)),
+                        e.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::Weapon => {
+                self.weapons.iter().map(|w| {
+                    (
+                        format!(
+                            "{} ({} damage, range {}, {:.0}% accuracy)",
+                            w.get_name(),
+                            w.damage(),
+                            w.range(),
+                            w.accuracy() * 100.0,
+                        ),
+                        w.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::Armor => {
+                self.armor.iter().map(|a| {
+                    (
+                        format!("{} ({} reduction, {} HP)", a.get_name(), a.reduction(), a.get_hit_points()),
+                        a.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::Shield => {
+                self.shields.iter().map(|s| {
+                    (
+                        format!("{} ({} capacity, {} recharge)", s.get_name(), s.capacity(), s.recharge()),
+                        s.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::PointDefense => {
+                self.point_defenses.iter().map(|pd| {
+                    (
+                        format!(
+                            "{} ({} shots, {:.0}% interception)",
+                            pd.get_name(),
+                            pd.shots(),
+                            pd.interception() * 100.0,
+                        ),
+                        pd.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::Reactor => {
+                self.reactors.iter().map(|r| {
+                    (
+                        format!(
+                            "{} ({} power, {:.0}% meltdown chance)",
+                            r.get_name(),
+                            r.power_output(),
+                            r.damage_meltdown_chance() * 100.0,
+                        ),
+                        r.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::Sensor => {
+                self.sensors.iter().map(|s| {
+                    (
+                        format!(
+                            "{} ({}, {:.1} AU strength)",
+                            s.get_name(),
+                            match s.mode() {
+                                SensorMode::Passive => "passive",
+                                SensorMode::Active => "active",
+                            },
+                            s.strength(),
+                        ),
+                        s.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::SurveySensor => {
+                self.survey_sensors.iter().map(|s| {
+                    (
+                        format!(
+                            "{} ({}, {} points per day)",
+                            s.get_name(),
+                            String::from(*s.kind()).to_lowercase(),
+                            s.points(),
+                        ),
+                        s.get_menu_color(),
+                    )
+                }).collect()
+            }
+            ShipModuleType::TroopBay => {
+                self.troop_bays.iter().map(|t| {
+                    (
+                        format!("{} ({} ground units)", t.get_name(), t.capacity()),
+                        t.get_menu_color(),
+                    )
+                }).collect()
+            }
+        }
+    }
+
+    /// Finds a module definition by its type and name
+    pub fn get_module(&self, module_type: ShipModuleType, name: &str) -> Option<ModuleKind> {
+        match module_type {
+            ShipModuleType::SublightThruster => self.sublight_engines.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::SublightEngine),
+            ShipModuleType::Weapon => self.weapons.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::Weapon),
+            ShipModuleType::Armor => self.armor.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::Armor),
+            ShipModuleType::Shield => self.shields.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::Shield),
+            ShipModuleType::PointDefense => self.point_defenses.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::PointDefense),
+            ShipModuleType::Reactor => self.reactors.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::Reactor),
+            ShipModuleType::Sensor => self.sensors.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::Sensor),
+            ShipModuleType::SurveySensor => self.survey_sensors.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::SurveySensor),
+            ShipModuleType::TroopBay => self.troop_bays.iter()
+                .find(|m| m.get_name() == name).cloned().map(ModuleKind::TroopBay),
+        }
+    }
+
+    /// Builds a ship from a design
+    ///
+    /// # Returns
+    /// * `Option<Ship>` - The new ship, `None` if the design uses an unknown module
+    pub fn build_ship(&self, design: &ShipDesign, name: String) -> Option<Ship> {
+        let modules: Option<Vec<ModuleKind>> = design.modules().iter()
+            .map(|m| self.get_module(*m.module_type(), m.name()))
+            .collect();
+
+        modules.map(|modules| Ship::new(name, design, modules))
+    }
 }
\ No newline at end of file
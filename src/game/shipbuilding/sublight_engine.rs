@@ -1,17 +1,54 @@
-use serde::Deserialize;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
 
+use crate::game::celestial_bodies::Displayable;
+use crate::game::research::tech_modifiers::{ModifierType, TechModifiers};
 use crate::game::shipbuilding::module_trait::ModuleTrait;
-use crate::game::shipbuilding::ship_module::ShipModule;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SublightEngine {
     name: String,
     traits: Vec<ModuleTrait>,
     is_unlocked: bool,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl SublightEngine {
+    /// Calculates the thrust of the engine, combining its traits with the researched bonuses
+    ///
+    /// # Arguments
+    /// * `modifiers` - Bonuses of the researched technologies
+    ///
+    /// # Returns
+    /// * `f32` - The thrust of the engine
+    pub fn get_thrust(&self, modifiers: &TechModifiers) -> f32 {
+        let base: f32 = self.traits.iter().map(|t| t.get_value()).sum();
+        base * modifiers.get_multiplier(ModifierType::SublightThrust)
+    }
 }
 
 impl ShipModule for SublightEngine {
     fn get_traits(&self) -> Vec<ModuleTrait> {
         self.traits.clone()
     }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for SublightEngine {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::exploration::body_survey::SurveyKind;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+/// `SurveySensor` surveys the body its ship is orbiting
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct SurveySensor {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    kind: SurveyKind,
+    /// Amount of survey points gathered every day
+    points: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for SurveySensor {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for SurveySensor {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use derive_getters::Getters;
+use ratatui::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::shipbuilding::module_trait::ModuleTrait;
+use crate::game::shipbuilding::ship_module::{default_hit_points, ShipModule};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum WeaponKind {
+    /// Hits instantly, loses accuracy quickly with distance
+    Beam,
+    /// Slugs fired by mass drivers
+    Kinetic,
+    /// Guided missiles, which can be shot down by point defense
+    Missile,
+}
+
+impl WeaponKind {
+    /// Returns the share of the accuracy lost at the maximum range of the weapon
+    pub fn get_accuracy_falloff(&self) -> f32 {
+        match self {
+            WeaponKind::Beam => 0.5,
+            WeaponKind::Kinetic => 0.3,
+            WeaponKind::Missile => 0.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Getters)]
+pub struct Weapon {
+    name: String,
+    traits: Vec<ModuleTrait>,
+    is_unlocked: bool,
+    kind: WeaponKind,
+    /// Damage dealt by a single hit
+    damage: u32,
+    /// Maximum distance the weapon can fire at, in range bands
+    range: u32,
+    /// Chance to hit a target at point blank range, between 0 and 1
+    accuracy: f32,
+    /// Power needed to run the module at full capacity
+    #[serde(default)]
+    power_draw: u32,
+    #[serde(default = "default_hit_points")]
+    hit_points: u32,
+}
+
+impl ShipModule for Weapon {
+    fn get_traits(&self) -> Vec<ModuleTrait> {
+        self.traits.clone()
+    }
+
+    fn get_hit_points(&self) -> u32 {
+        self.hit_points
+    }
+}
+
+impl Displayable for Weapon {
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_menu_color(&self) -> Color {
+        if self.is_unlocked {
+            Color::White
+        } else {
+            Color::DarkGray
+        }
+    }
+}
\ No newline at end of file
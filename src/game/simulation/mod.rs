@@ -0,0 +1,3 @@
+pub mod simulation_runner;
+pub mod simulation_plan;
+pub mod tick_metrics;
\ No newline at end of file
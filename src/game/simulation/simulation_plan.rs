@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use color_eyre::eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::game::game_options::GameOptions;
+
+/// `PlanStep` is an order of the simulated player, given as soon as the day of the step comes
+/// and the order can be carried out
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PlanStep {
+    /// Starts a research, waits for its requirements to be researched first
+    Research { day: u64, research: String },
+    /// Queues buildings in a colony, the capital if no colony is named. Waits for the colony to
+    /// be founded first
+    Build {
+        day: u64,
+        building: String,
+        #[serde(default)]
+        colony: Option<String>,
+        #[serde(default = "default_amount")]
+        amount: u32,
+    },
+}
+
+fn default_amount() -> u32 {
+    1
+}
+
+impl PlanStep {
+    pub fn get_day(&self) -> u64 {
+        match self {
+            PlanStep::Research { day, .. } => *day,
+            PlanStep::Build { day, .. } => *day,
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            PlanStep::Research { day, research } => {
+                format!("day {day}: research `{research}`")
+            }
+            PlanStep::Build { day, building, colony, amount } => {
+                format!(
+                    "day {day}: build {amount} x `{building}` in {}",
+                    colony.as_ref().map_or(String::from("the capital"), |c| format!("`{c}`")),
+                )
+            }
+        }
+    }
+}
+
+/// `SimulationPlan` is a scripted game, that is the rules it is played with and the orders of
+/// the player
+///
+/// **Fields**
+/// - options (`GameOptions`) - the rules of the game, the same as in the configuration file
+/// - steps (`Vec<PlanStep>`) - the orders of the player
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SimulationPlan {
+    #[serde(default)]
+    pub options: GameOptions,
+    #[serde(default)]
+    pub steps: Vec<PlanStep>,
+}
+
+impl SimulationPlan {
+    pub fn load_from_file(filepath: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(filepath)
+            .wrap_err_with(|| format!("{}: unable to read file", filepath.display()))?;
+        serde_json::from_str(&contents)
+            .wrap_err_with(|| format!("{}: unable to parse file", filepath.display()))
+    }
+}
\ No newline at end of file
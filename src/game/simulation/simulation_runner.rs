@@ -0,0 +1,194 @@
+use color_eyre::eyre::{eyre, Result};
+
+use crate::game::colony::building::BuildingType;
+use crate::game::empires::strategy::Order;
+use crate::game::game_state::GameState;
+use crate::game::scripting::script_manager::ScriptManager;
+use crate::game::simulation::simulation_plan::{PlanStep, SimulationPlan};
+use crate::game::simulation::tick_metrics::TickMetrics;
+
+/// `Simulation` plays a game without the interface, following a plan instead of the player.
+/// Events asking for a choice are answered with their first affordable option
+///
+/// **Fields**
+/// - state (`GameState`) - the simulated game
+/// - pending (`Vec<PlanStep>`) - the steps of the plan which were not carried out yet
+/// - scripts (`ScriptManager`) - scripts of the player, if the options enable them
+pub struct Simulation {
+    state: GameState,
+    pending: Vec<PlanStep>,
+    scripts: ScriptManager,
+}
+
+impl Simulation {
+    /// Starts a game and checks that the plan refers to existing researches and buildings
+    ///
+    /// # Arguments
+    /// * `plan` - The rules of the game and the orders of the player
+    /// * `seed` - Seed of the game, replaces the one of the plan
+    pub fn new(plan: SimulationPlan, seed: Option<u64>) -> Result<Self> {
+        let mut options = plan.options;
+        options.seed = seed.or(options.seed).or(Some(0));
+        let state = GameState::new(options.clone())?;
+        let scripts = ScriptManager::for_options(&options, state.get_assets())?;
+
+        let research_manager = state.get_player().get_research_manager();
+        for (n, step) in plan.steps.iter().enumerate() {
+            match step {
+                PlanStep::Research { research, .. } if !research_manager.has_research(research) => {
+                    return Err(eyre!("step {}: unknown research `{research}`", n + 1))
+                }
+                PlanStep::Build { building, .. } if BuildingType::from_name(building).is_none() => {
+                    return Err(eyre!("step {}: unknown building `{building}`", n + 1))
+                }
+                _ => {}
+            }
+        }
+
+        let mut pending = plan.steps;
+        pending.sort_by_key(|s| s.get_day());
+        Ok(Self { state, pending, scripts })
+    }
+
+    /// Plays the game
+    ///
+    /// # Arguments
+    /// * `ticks` - Amount of in-game days to play
+    /// * `interval` - Amount of days between two snapshots of the metrics
+    ///
+    /// # Returns
+    /// * `Vec<TickMetrics>` - The snapshots, the first one is taken before the first day and the
+    ///   last one after the last day
+    pub fn run(&mut self, ticks: u64, interval: u64) -> Vec<TickMetrics> {
+        let interval = interval.max(1);
+        let mut metrics = vec![TickMetrics::collect(&self.state)];
+        for n in 1..=ticks {
+            self.carry_out_steps();
+            let entries = self.state.tick();
+            self.scripts.run(&mut self.state, &entries);
+            self.answer_events();
+            if n % interval == 0 || n == ticks {
+                metrics.push(TickMetrics::collect(&self.state));
+            }
+        }
+        metrics
+    }
+
+    /// Returns the descriptions of the steps which could not be carried out
+    pub fn get_pending_steps(&self) -> Vec<String> {
+        self.pending.iter().map(|s| s.get_description()).collect()
+    }
+
+    fn carry_out_steps(&mut self) {
+        let date = self.state.get_date();
+        let steps: Vec<PlanStep> = self.pending.drain(..).collect();
+        for step in steps {
+            let is_done = step.get_day() <= date && self.carry_out(&step);
+            if !is_done {
+                self.pending.push(step);
+            }
+        }
+    }
+
+    /// Gives the order of a step
+    ///
+    /// # Returns
+    /// * `bool` - `true` if the order was given or is not needed anymore
+    fn carry_out(&mut self, step: &PlanStep) -> bool {
+        match step {
+            PlanStep::Research { research, .. } => {
+                let research_manager = self.state.get_player().get_research_manager();
+                if research_manager.get_researched().contains(research)
+                    || research_manager.get_researches_in_progress().contains(research) {
+                    return true
+                }
+                if !research_manager.get_available_researches().contains(research) {
+                    return false
+                }
+                self.state.start_research(research.clone());
+                true
+            }
+            PlanStep::Build { building, colony, amount, .. } => {
+                let Some(building) = BuildingType::from_name(building) else {
+                    return false
+                };
+                // The colony changes with every queued building, so it is looked up every time
+                let find_colony = |state: &GameState| match colony {
+                    Some(name) => state.get_colony_by_name(name.clone()),
+                    None => state.get_colonies().first().cloned(),
+                };
+                if find_colony(&self.state).is_none() {
+                    return false
+                }
+                for _ in 0..*amount {
+                    if let Some(colony) = find_colony(&self.state) {
+                        self.state.start_construction(colony, building.clone());
+                    }
+                }
+                true
+            }
+        }
+    }
+
+    fn answer_events(&mut self) {
+        let get_prompt = |state: &GameState| {
+            state.get_event_manager().get_prompt(state.get_player())
+        };
+        while let Some(prompt) = get_prompt(&self.state) {
+            let answered = prompt.options.iter().position(|(_, is_affordable)| *is_affordable)
+                .is_some_and(|choice| self.state.give_order(Order::AnswerEvent { choice }));
+            if !answered {
+                break
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use crate::game::simulation::simulation_runner::Simulation;
+    use crate::game::simulation::simulation_plan::SimulationPlan;
+
+    fn get_plan() -> SimulationPlan {
+        serde_json::from_value(serde_json::json!({
+            "options": { "computer_empires": 1 },
+            "steps": [
+                { "day": 0, "type": "Build", "building": "Mine", "amount": 2 },
+                { "day": 3, "type": "Research", "research": "ion-drive" },
+            ],
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_simulation_depends_only_on_the_seed() {
+        let first = Simulation::new(get_plan(), Some(7)).unwrap().run(60, 10);
+        let second = Simulation::new(get_plan(), Some(7)).unwrap().run(60, 10);
+
+        assert_eq!(first.len(), 7);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_plan_steps_are_carried_out() {
+        let mut simulation = Simulation::new(get_plan(), Some(7)).unwrap();
+        let metrics = simulation.run(30, 1);
+
+        let mines = |day: usize| metrics[day].buildings["Mine"];
+        assert_eq!(mines(30), mines(0) + 2);
+        assert!(metrics[2].researching.is_empty());
+        assert!(metrics[30].researching.contains(&String::from("ion-drive"))
+            || metrics[30].researched > metrics[0].researched);
+        assert!(simulation.get_pending_steps().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_buildings_are_rejected() {
+        let plan = serde_json::from_value(serde_json::json!({
+            "steps": [{ "day": 0, "type": "Build", "building": "Moon base" }],
+        })).unwrap();
+
+        assert!(Simulation::new(plan, None).is_err());
+    }
+}
\ No newline at end of file
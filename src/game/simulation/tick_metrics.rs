@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::celestial_bodies::Displayable;
+use crate::game::colony::building::BuildingType;
+use crate::game::event_log::format_date;
+use crate::game::game_state::GameState;
+use crate::game::resource::resource::ResourceType;
+
+/// `TickMetrics` is a snapshot of the player's empire, taken after an in-game day
+///
+/// **Fields**
+/// - day (`u64`) - amount of days passed since the start of the game
+/// - date (`String`) - the same day, as shown in the game
+/// - colonies (`usize`) - amount of the player's colonies
+/// - population (`i64`) - colonists living in all the colonies
+/// - researched (`usize`) - amount of researches with at least a level researched
+/// - researching (`Vec<String>`) - ids of the researches in progress
+/// - construction (`usize`) - amount of the buildings waiting in the construction queues
+/// - resources (`BTreeMap<String, u32>`) - stored resources of every type, summed across the
+///   colonies
+/// - buildings (`BTreeMap<String, u32>`) - buildings of every type, summed across the colonies
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TickMetrics {
+    pub day: u64,
+    pub date: String,
+    pub colonies: usize,
+    pub population: i64,
+    pub researched: usize,
+    pub researching: Vec<String>,
+    pub construction: usize,
+    pub resources: BTreeMap<String, u32>,
+    pub buildings: BTreeMap<String, u32>,
+}
+
+impl TickMetrics {
+    pub fn collect(state: &GameState) -> Self {
+        let colonies = state.get_colonies();
+        let research_manager = state.get_player().get_research_manager();
+
+        let mut resources: BTreeMap<String, u32> = ResourceType::get_variants().into_iter()
+            .map(|rt| (rt.get_name(), 0))
+            .collect();
+        let mut buildings: BTreeMap<String, u32> = BuildingType::get_variants().into_iter()
+            .map(|(bt, _)| (bt.get_name(), 0))
+            .collect();
+        for colony in colonies.iter() {
+            colony.get_resources().into_iter().for_each(|(rt, amount)| {
+                *resources.entry(rt.get_name()).or_insert(0) += amount;
+            });
+            colony.get_buildings().into_iter().for_each(|(name, amount, _)| {
+                *buildings.entry(name).or_insert(0) += amount;
+            });
+        }
+
+        Self {
+            day: state.get_date(),
+            date: format_date(state.get_date()),
+            colonies: colonies.len(),
+            population: colonies.iter().map(|c| c.get_population() as i64).sum(),
+            researched: research_manager.get_researched().len(),
+            researching: research_manager.get_researches_in_progress(),
+            construction: colonies.iter().map(|c| c.get_construction().len()).sum(),
+            resources,
+            buildings,
+        }
+    }
+}
+
+fn escape_csv(field: String) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+/// Formats the metrics as CSV, a row for every snapshot. The resources and the buildings get a
+/// column for every type
+pub fn to_csv(metrics: &[TickMetrics]) -> String {
+    let mut header: Vec<String> = [
+        "day", "date", "colonies", "population", "researched", "researching", "construction",
+    ].iter().map(|s| s.to_string()).collect();
+    header.extend(ResourceType::get_variants().into_iter().map(|rt| rt.get_name()));
+    header.extend(BuildingType::get_variants().into_iter().map(|(bt, _)| bt.get_name()));
+
+    let mut lines = vec![header];
+    for m in metrics.iter() {
+        let mut row = vec![
+            m.day.to_string(),
+            m.date.clone(),
+            m.colonies.to_string(),
+            m.population.to_string(),
+            m.researched.to_string(),
+            m.researching.join(";"),
+            m.construction.to_string(),
+        ];
+        row.extend(ResourceType::get_variants().into_iter().map(|rt| {
+            m.resources.get(&rt.get_name()).unwrap_or(&0).to_string()
+        }));
+        row.extend(BuildingType::get_variants().into_iter().map(|(bt, _)| {
+            m.buildings.get(&bt.get_name()).unwrap_or(&0).to_string()
+        }));
+        lines.push(row);
+    }
+
+    lines.into_iter()
+        .map(|row| row.into_iter().map(escape_csv).collect::<Vec<String>>().join(",") + "\n")
+        .collect()
+}
\ No newline at end of file
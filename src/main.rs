@@ -2,13 +2,25 @@
 #![allow(unused_imports)]
 #![allow(unused_variables)]
 
+use std::path::Path;
+
 use clap::Parser;
 use color_eyre::eyre::Result;
 
-use cli::Cli;
+use cli::{Cli, Command, MetricsFormat, ReplayArgs, SimulateArgs};
 
 use crate::{
   app::App,
+  config::Config,
+  game::mods::{asset_layers::AssetLayers, game_mod::get_enabled_mods},
+  game::fleets::ship_design::{SHIP_DESIGNS_PATH, ShipDesign},
+  game::research::research_manager::ResearchManager,
+  game::shipbuilding::ship_module_manager::ShipModuleManager,
+  game::scripting::script_manager::ScriptManager,
+  game::simulation::{simulation_runner::Simulation, simulation_plan::SimulationPlan, tick_metrics},
+  game::simulation::tick_metrics::TickMetrics,
+  replay::Replay,
+  saves::{get_latest_save, get_saves_dir, Save},
   utils::{initialize_logging, initialize_panic_handler},
 };
 
@@ -17,7 +29,10 @@ pub mod app;
 pub mod cli;
 pub mod components;
 pub mod config;
+pub mod control;
 pub mod mode;
+pub mod replay;
+pub mod saves;
 pub mod tui;
 pub mod utils;
 mod game;
@@ -29,12 +44,96 @@ async fn tokio_main() -> Result<()> {
   initialize_panic_handler()?;
 
   let args = Cli::parse();
-  let mut app = App::new(args.tick_rate, args.frame_rate)?;
+  match args.command {
+    Some(Command::ValidateAssets) => return validate_assets(),
+    Some(Command::Simulate(simulate_args)) => return simulate(simulate_args),
+    Some(Command::Replay(replay_args)) => return replay(replay_args),
+    None => {}
+  }
+
+  let save = match (&args.load, args.continue_game) {
+    (Some(path), _) => Some(Save::load_from_file(path)?),
+    (None, true) => match get_latest_save(&get_saves_dir())? {
+      Some(path) => Some(Save::load_from_file(&path)?),
+      None => return Err(color_eyre::eyre::eyre!("There is no saved game to continue")),
+    },
+    (None, false) => None,
+  };
+
+  let mut app = App::new(args.tick_rate, args.frame_rate, args.control, args.record, save)?;
   app.run().await?;
 
   Ok(())
 }
 
+fn validate_assets() -> Result<()> {
+  let assets = AssetLayers::load(&get_enabled_mods(&Config::new()?.game)?)?;
+  let mut errors: Vec<String> = ResearchManager::validate_assets(&assets).iter()
+    .map(|e| e.to_string())
+    .collect();
+  if let Err(e) = ShipModuleManager::new(&assets) {
+    errors.push(e.to_string());
+  }
+  if let Err(e) = ShipDesign::load_from_assets(&assets, SHIP_DESIGNS_PATH) {
+    errors.push(e.to_string());
+  }
+  if let Err(e) = ScriptManager::new(&assets) {
+    errors.push(e.to_string());
+  }
+  if errors.is_empty() {
+    println!("All assets are valid");
+    return Ok(());
+  }
+
+  for error in errors.iter() {
+    println!("{error}");
+  }
+  Err(color_eyre::eyre::eyre!("Found {} problem(s) in the game assets", errors.len()))
+}
+
+fn simulate(args: SimulateArgs) -> Result<()> {
+  let plan = match &args.plan {
+    Some(path) => SimulationPlan::load_from_file(path)?,
+    None => SimulationPlan::default(),
+  };
+  let mut simulation = Simulation::new(plan, args.seed)?;
+  let metrics = simulation.run(args.ticks, args.interval);
+  for step in simulation.get_pending_steps() {
+    eprintln!("Step was never carried out: {step}");
+  }
+
+  write_metrics(&metrics, args.format, args.output.as_deref())
+}
+
+fn replay(args: ReplayArgs) -> Result<()> {
+  let replay = Replay::load_from_file(&args.replay)?;
+  if replay.version != env!("CARGO_PKG_VERSION") {
+    eprintln!("The replay was recorded with version {}, it may play differently", replay.version);
+  }
+  let metrics = replay.run(args.interval)?;
+  write_metrics(&metrics, args.format, args.output.as_deref())?;
+  match metrics.last() {
+    Some(last) => replay.check_result(last),
+    None => Ok(()),
+  }
+}
+
+fn write_metrics(
+  metrics: &[TickMetrics],
+  format: MetricsFormat,
+  output: Option<&Path>,
+) -> Result<()> {
+  let text = match format {
+    MetricsFormat::Csv => tick_metrics::to_csv(metrics),
+    MetricsFormat::Json => serde_json::to_string_pretty(metrics)? + "\n",
+  };
+  match output {
+    Some(path) => std::fs::write(path, text)?,
+    None => print!("{text}"),
+  }
+  Ok(())
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
   if let Err(e) = tokio_main().await {
@@ -7,9 +7,21 @@ pub enum Mode {
   SelectingBodyInSystemTree,
   SelectingResearchField,
   SelectingResearch,
+  ResearchTreeNavigation,
   SelectingColony,
   SelectingBuilding,
   SystemMapNavigation,
   SelectingShipModuleType,
   SelectingShipModule,
+  SelectingFleet,
+  SelectingEmpire,
+  SelectingDiplomaticOption,
+  SelectingPolicy,
+  SelectingOfficer,
+  SelectingAssignment,
+  AnsweringEvent,
+  SelectingLogCategory,
+  ShowingHelp,
+  SelectingMod,
+  UsingCommandPalette,
 }
@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::game::colony::building::BuildingType;
+use crate::game::empires::strategy::Order;
+use crate::game::game_options::GameOptions;
+use crate::game::game_state::GameState;
+use crate::game::scripting::script_manager::ScriptManager;
+use crate::game::simulation::tick_metrics::TickMetrics;
+
+/// `RecordedOrder` is an order of the player and the day it was given on
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedOrder {
+  pub day: u64,
+  pub action: Action,
+}
+
+/// `Replay` is a recorded game, which plays again the same way from its options and the orders
+/// of the player
+///
+/// **Fields**
+/// - version (`String`) - version of the game it was recorded with
+/// - options (`GameOptions`) - options of the game, with its seed and its mods
+/// - days (`u64`) - amount of days the game lasted
+/// - orders (`Vec<RecordedOrder>`) - every order of the player, in the order they were given
+/// - result (`Option<TickMetrics>`) - the player's empire at the end of the game, a replay
+///   ending differently is reported. It is `None` if the game did not end cleanly
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+  pub version: String,
+  pub options: GameOptions,
+  pub days: u64,
+  pub orders: Vec<RecordedOrder>,
+  pub result: Option<TickMetrics>,
+}
+
+impl Replay {
+  pub fn new(state: &GameState) -> Self {
+    Self {
+      version: String::from(env!("CARGO_PKG_VERSION")),
+      options: state.get_options(),
+      days: state.get_date(),
+      orders: Vec::new(),
+      result: None,
+    }
+  }
+
+  pub fn load_from_file(filepath: &Path) -> Result<Self> {
+    let contents = std::fs::read_to_string(filepath)
+      .wrap_err_with(|| format!("{}: unable to read file", filepath.display()))?;
+    serde_json::from_str(&contents)
+      .wrap_err_with(|| format!("{}: unable to parse file", filepath.display()))
+  }
+
+  pub fn save_to_file(&self, filepath: &Path) -> Result<()> {
+    std::fs::write(filepath, serde_json::to_string_pretty(self)?)
+      .wrap_err_with(|| format!("{}: unable to write file", filepath.display()))
+  }
+
+  /// Plays the game again without the interface. The scripts of the player run after every
+  /// day, as they did in the recorded game
+  ///
+  /// # Arguments
+  /// * `interval` - Amount of days between two snapshots of the metrics
+  ///
+  /// # Returns
+  /// * `Result<Vec<TickMetrics>>` - The snapshots, the first one is taken before the first day
+  ///   and the last one after the last order
+  pub fn run(&self, interval: u64) -> Result<Vec<TickMetrics>> {
+    let interval = interval.max(1);
+    let mut metrics = Vec::new();
+    let (state, _) = self.play(|state| {
+      if state.get_date() % interval == 0 {
+        metrics.push(TickMetrics::collect(state));
+      }
+    })?;
+    metrics.push(TickMetrics::collect(&state));
+    Ok(metrics)
+  }
+
+  /// Plays the game again up to its last day, to go on with it
+  ///
+  /// # Returns
+  /// * `Result<(GameState, ScriptManager)>` - The game and the scripts of the player
+  pub fn restore(&self) -> Result<(GameState, ScriptManager)> {
+    self.play(|_| {})
+  }
+
+  /// Plays the game again up to its last day
+  ///
+  /// # Arguments
+  /// * `on_day` - Called before every day but the last one, once the orders of the day were
+  ///   given
+  fn play(&self, mut on_day: impl FnMut(&GameState)) -> Result<(GameState, ScriptManager)> {
+    let mut state = GameState::new(self.options.clone())?;
+    let mut scripts = ScriptManager::for_options(&self.options, state.get_assets())?;
+
+    let mut orders = self.orders.iter().peekable();
+    loop {
+      while let Some(order) = orders.next_if(|o| o.day <= state.get_date()) {
+        apply_order(&mut state, &order.action);
+      }
+      if state.get_date() >= self.days {
+        break
+      }
+      on_day(&state);
+      let entries = state.tick();
+      scripts.run(&mut state, &entries);
+    }
+    Ok((state, scripts))
+  }
+
+  /// Checks that a replay ended like the recorded game
+  ///
+  /// # Arguments
+  /// * `last` - The last snapshot of the replay
+  pub fn check_result(&self, last: &TickMetrics) -> Result<()> {
+    match &self.result {
+      Some(result) if result != last => Err(eyre!(
+        "The replay does not end like the recorded game, the game or its assets changed since \
+        it was recorded"
+      )),
+      _ => Ok(()),
+    }
+  }
+}
+
+/// Carries out an order of the player, the same way the interface does. Orders which can not be
+/// carried out anymore, e.g. a construction on a lost colony, are skipped
+pub fn apply_order(state: &mut GameState, action: &Action) {
+  match action.clone() {
+    Action::StartResearch(research) => state.start_research(research),
+    Action::StartConstruction((colony, building)) => {
+      if let (Some(colony), Some(building)) = (
+        state.get_colony_by_name(colony),
+        BuildingType::from_name(&building),
+      ) {
+        state.start_construction(colony, building);
+      }
+    }
+    Action::DeclareWar(empire) => {
+      state.give_order(Order::DeclareWar(empire));
+    }
+    Action::ProposeTreaty((empire, treaty)) => {
+      state.give_order(Order::ProposeTreaty { empire, treaty });
+    }
+    Action::AcceptTreaty((empire, treaty)) => {
+      state.give_order(Order::AcceptTreaty { empire, treaty });
+    }
+    Action::CancelTreaty((empire, treaty)) => {
+      state.give_order(Order::CancelTreaty { empire, treaty });
+    }
+    Action::SetPolicy((policy, is_enacted)) => {
+      state.give_order(Order::SetPolicy { policy, is_enacted });
+    }
+    Action::AssignOfficer((officer, assignment)) => {
+      state.give_order(Order::AssignOfficer { officer, assignment });
+    }
+    Action::ChooseEventOption(choice) => {
+      state.give_order(Order::AnswerEvent { choice });
+    }
+    _ => {}
+  }
+}
+
+/// `ReplayRecorder` records the orders of the player, which saves are made of. If a
+/// replay file is set, it is written after every order, so a crashed game can still be replayed
+/// up to its last order
+///
+/// **Fields**
+/// - path (`Option<PathBuf>`) - the replay file, if the game is recorded
+/// - replay (`Replay`) - the game recorded so far
+pub struct ReplayRecorder {
+  path: Option<PathBuf>,
+  replay: Replay,
+}
+
+impl ReplayRecorder {
+  /// # Arguments
+  /// * `path` - The replay file, if the game is recorded
+  /// * `replay` - The game to record, a loaded game goes on with its orders
+  pub fn new(path: Option<PathBuf>, replay: Replay) -> Result<Self> {
+    // The empire at the end of a loaded game is not the one at the end of this one
+    let recorder = Self { path, replay: Replay { result: None, ..replay } };
+    recorder.save()?;
+    Ok(recorder)
+  }
+
+  /// Records an order, right before it is carried out
+  pub fn record(&mut self, state: &GameState, action: &Action) -> Result<()> {
+    self.replay.days = state.get_date();
+    self.replay.orders.push(RecordedOrder { day: state.get_date(), action: action.clone() });
+    self.save()
+  }
+
+  /// Records the end of the game, along with the player's empire to check replays against
+  pub fn finish(&mut self, state: &GameState) -> Result<()> {
+    self.replay = self.get_replay(state);
+    self.save()
+  }
+
+  /// Returns the game recorded up to now, which plays again up to the current day
+  pub fn get_replay(&self, state: &GameState) -> Replay {
+    Replay {
+      days: state.get_date(),
+      result: Some(TickMetrics::collect(state)),
+      ..self.replay.clone()
+    }
+  }
+
+  fn save(&self) -> Result<()> {
+    match &self.path {
+      Some(path) => self.replay.save_to_file(path),
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use pretty_assertions::assert_eq;
+
+  use crate::action::Action;
+  use crate::game::celestial_bodies::Displayable;
+  use crate::game::game_options::GameOptions;
+  use crate::game::game_state::GameState;
+  use crate::replay::{apply_order, RecordedOrder, Replay};
+  use crate::game::simulation::tick_metrics::TickMetrics;
+
+  #[test]
+  fn test_replay_ends_like_the_recorded_game() {
+    let mut state = GameState::new(GameOptions {
+      computer_empires: 1,
+      ..GameOptions::default()
+    }).unwrap();
+    let mut replay = Replay::new(&state);
+    let capital = state.get_colonies()[0].get_name();
+
+    for day in 0..40 {
+      if day == 3 || day == 25 {
+        let action = Action::StartConstruction((capital.clone(), String::from("Mine")));
+        replay.orders.push(RecordedOrder { day: state.get_date(), action: action.clone() });
+        apply_order(&mut state, &action);
+      }
+      state.tick();
+    }
+    replay.days = state.get_date();
+    replay.result = Some(TickMetrics::collect(&state));
+
+    let replay: Replay = serde_json::from_str(&serde_json::to_string(&replay).unwrap()).unwrap();
+    let metrics = replay.run(10).unwrap();
+    assert_eq!(metrics.iter().map(|m| m.day).collect::<Vec<_>>(), vec![0, 10, 20, 30, 40]);
+    assert!(replay.check_result(metrics.last().unwrap()).is_ok());
+    assert!(replay.check_result(&metrics[0]).is_err());
+  }
+}
\ No newline at end of file
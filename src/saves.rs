@@ -0,0 +1,339 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::game::game_state::GameState;
+use crate::game::mods::asset_layers::AssetLayers;
+use crate::game::research::research_progress::ResearchState;
+use crate::game::scripting::script_manager::ScriptManager;
+use crate::game::simulation::tick_metrics::TickMetrics;
+use crate::replay::{RecordedOrder, Replay};
+
+/// Prefix of the autosave files, followed by the number of the slot
+const AUTOSAVE_FILE_PREFIX: &str = "autosave-";
+/// File the game is saved to when it crashes
+const EMERGENCY_SAVE_FILE: &str = "emergency.json";
+/// File keeping track of the saves, see `SaveIndex`
+const SAVE_INDEX_FILE: &str = "index.json";
+
+/// The current game, kept up to date with its days and its orders for the panic handler to save
+/// it
+static CURRENT_GAME: Lazy<Mutex<Option<Save>>> = Lazy::new(|| Mutex::new(None));
+
+/// `Save` is a saved game. It is the replay of the game up to the day it was saved on, which is
+/// played again when the game is loaded, along with the progress the game is restored to
+///
+/// **Fields**
+/// - replay (`Replay`) - the options and the orders of the game, a save is also a replay file
+/// - mods (`Vec<String>`) - the id and the version of every mod of the game, the game is only
+///   loaded with the same mods installed
+/// - research (`BTreeMap<String, ResearchState>`) - the research progress of every empire, by
+///   the id of the empire. The emergency save has none, the progress is then the replayed one
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Save {
+  #[serde(flatten)]
+  pub replay: Replay,
+  pub mods: Vec<String>,
+  #[serde(default)]
+  pub research: BTreeMap<String, ResearchState>,
+}
+
+impl Save {
+  /// # Arguments
+  /// * `replay` - The game recorded up to now
+  /// * `state` - The current game, its progress is saved along with the replay
+  pub fn new(replay: Replay, state: &GameState) -> Self {
+    Self { replay, mods: state.get_mods(), research: state.get_research_states() }
+  }
+
+  pub fn load_from_file(filepath: &Path) -> Result<Self> {
+    let contents = std::fs::read_to_string(filepath)
+      .wrap_err_with(|| format!("{}: unable to read file", filepath.display()))?;
+    serde_json::from_str(&contents)
+      .wrap_err_with(|| format!("{}: unable to parse file", filepath.display()))
+  }
+
+  pub fn save_to_file(&self, filepath: &Path) -> Result<()> {
+    std::fs::write(filepath, serde_json::to_string_pretty(self)?)
+      .wrap_err_with(|| format!("{}: unable to write file", filepath.display()))
+  }
+
+  /// Plays the game again up to the day it was saved on and restores its progress. A game saved
+  /// with other versions of its mods, or which does not play again the way it was saved, is not
+  /// loaded, as its progress would not fit it
+  ///
+  /// # Returns
+  /// * `Result<(GameState, ScriptManager)>` - The game and the scripts of the player
+  pub fn restore(&self) -> Result<(GameState, ScriptManager)> {
+    let mods = AssetLayers::load(&self.replay.options.mods)?.get_labels();
+    if mods != self.mods {
+      return Err(eyre!(
+        "The game was saved with the mods [{}], but the installed ones are [{}]",
+        self.mods.join(", "),
+        mods.join(", "),
+      ));
+    }
+    let (mut state, scripts) = self.replay.restore()?;
+    self.replay.check_result(&TickMetrics::collect(&state))?;
+    state.set_research_states(self.research.clone())?;
+    Ok((state, scripts))
+  }
+}
+
+/// `AutosaveOptions` sets how often the game is saved. They are read from the `autosave` section
+/// of the configuration file
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AutosaveOptions {
+  /// Amount of in-game days between two autosaves, the game is not saved if it is 0
+  #[serde(default = "default_interval")]
+  pub interval: u64,
+  /// Amount of autosaves kept, a new one replaces the oldest
+  #[serde(default = "default_slots")]
+  pub slots: u32,
+}
+
+fn default_interval() -> u64 {
+  30
+}
+
+fn default_slots() -> u32 {
+  3
+}
+
+impl Default for AutosaveOptions {
+  fn default() -> Self {
+    Self { interval: default_interval(), slots: default_slots() }
+  }
+}
+
+/// Returns the directory the games are saved to
+pub fn get_saves_dir() -> PathBuf {
+  crate::utils::get_data_dir().join("saves")
+}
+
+/// `SaveIndex` keeps track of the saves of a directory, as the modification times of the files
+/// are not always precise enough to tell which one is the newest
+///
+/// **Fields**
+/// - next_slot (`u32`) - autosave slot the next autosave goes to
+/// - latest (`Option<String>`) - file name of the newest save, an autosave or the emergency save
+#[derive(Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+struct SaveIndex {
+  next_slot: u32,
+  latest: Option<String>,
+}
+
+impl SaveIndex {
+  /// Reads the index of a directory, which is empty if nothing was saved there yet
+  fn load(dir: &Path) -> Result<Self> {
+    let path = dir.join(SAVE_INDEX_FILE);
+    if !path.exists() {
+      return Ok(Self::default())
+    }
+    let contents = std::fs::read_to_string(&path)
+      .wrap_err_with(|| format!("{}: unable to read file", path.display()))?;
+    serde_json::from_str(&contents)
+      .wrap_err_with(|| format!("{}: unable to parse file", path.display()))
+  }
+
+  fn save(&self, dir: &Path) -> Result<()> {
+    let path = dir.join(SAVE_INDEX_FILE);
+    std::fs::write(&path, serde_json::to_string_pretty(self)?)
+      .wrap_err_with(|| format!("{}: unable to write file", path.display()))
+  }
+}
+
+/// Saves a game to the next autosave slot, the slots are taken in turn so a new autosave replaces
+/// the oldest one
+///
+/// # Arguments
+/// * `dir` - Directory of the saves
+/// * `slots` - Amount of autosave slots
+/// * `game` - The game to save
+///
+/// # Returns
+/// * `Result<PathBuf>` - The file the game was saved to
+pub fn write_autosave(dir: &Path, slots: u32, game: &Save) -> Result<PathBuf> {
+  std::fs::create_dir_all(dir)
+    .wrap_err_with(|| format!("{}: unable to create directory", dir.display()))?;
+  // A broken index is replaced, rather than stopping the game from being saved
+  let mut index = SaveIndex::load(dir).unwrap_or_default();
+  let slots = slots.max(1);
+  let file = format!("{AUTOSAVE_FILE_PREFIX}{}.json", index.next_slot % slots);
+  let path = dir.join(&file);
+  game.save_to_file(&path)?;
+
+  index.next_slot = (index.next_slot + 1) % slots;
+  index.latest = Some(file);
+  index.save(dir)?;
+  Ok(path)
+}
+
+/// Finds the last saved game, an autosave or the one saved when the game crashed
+///
+/// # Arguments
+/// * `dir` - Directory of the saves
+///
+/// # Returns
+/// * `Result<Option<PathBuf>>` - The newest save, if there is one
+pub fn get_latest_save(dir: &Path) -> Result<Option<PathBuf>> {
+  let index = SaveIndex::load(dir)?;
+  Ok(index.latest.map(|file| dir.join(file)).filter(|path| path.exists()))
+}
+
+/// Keeps the current game for the emergency save, it is then kept up to date with
+/// `set_current_day` and `record_current_order` instead of being copied again
+pub fn set_current_game(game: Save) {
+  if let Ok(mut current) = CURRENT_GAME.lock() {
+    *current = Some(game);
+  }
+}
+
+/// Moves the current game on to a day, the emergency save then plays the game up to it
+pub fn set_current_day(day: u64) {
+  if let Ok(mut current) = CURRENT_GAME.lock() {
+    if let Some(game) = current.as_mut() {
+      game.replay.days = day;
+    }
+  }
+}
+
+/// Adds an order of the player to the current game
+pub fn record_current_order(order: RecordedOrder) {
+  if let Ok(mut current) = CURRENT_GAME.lock() {
+    if let Some(game) = current.as_mut() {
+      game.replay.days = order.day;
+      game.replay.orders.push(order);
+    }
+  }
+}
+
+/// Saves the current game, called when the game crashes. It does not wait for the game and does
+/// not fail, as it runs in the panic handler
+///
+/// # Returns
+/// * `Option<PathBuf>` - The file the game was saved to, `None` if there was no game to save or
+///   it could not be saved
+pub fn write_emergency_save() -> Option<PathBuf> {
+  let current = CURRENT_GAME.try_lock().ok()?;
+  let game = current.as_ref()?;
+  let dir = get_saves_dir();
+  std::fs::create_dir_all(&dir).ok()?;
+  let path = dir.join(EMERGENCY_SAVE_FILE);
+  game.save_to_file(&path).ok()?;
+  let mut index = SaveIndex::load(&dir).unwrap_or_default();
+  index.latest = Some(String::from(EMERGENCY_SAVE_FILE));
+  index.save(&dir).ok()?;
+  Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::{SystemTime, UNIX_EPOCH};
+
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Map, Value};
+
+  use crate::action::Action;
+  use crate::game::game_options::GameOptions;
+  use crate::game::game_state::GameState;
+  use crate::game::research::research_progress::ResearchState;
+  use crate::game::simulation::tick_metrics::TickMetrics;
+  use crate::replay::{RecordedOrder, Replay};
+  use crate::saves::{
+    get_latest_save, record_current_order, set_current_day, set_current_game, write_autosave,
+    Save, CURRENT_GAME,
+  };
+
+  /// Starts a seeded game without events waiting for the player's answer
+  fn game(computer_empires: u32, seed: u64) -> GameState {
+    GameState::new(GameOptions {
+      random_events: false,
+      computer_empires,
+      seed: Some(seed),
+      ..GameOptions::default()
+    }).unwrap()
+  }
+
+  /// Builds the research progress of an empire from the id, the points and whether it is being
+  /// researched of every started research
+  fn research_state(progress: &[(&str, u32, bool)]) -> ResearchState {
+    let researches: Map<String, Value> = progress.iter()
+      .map(|(id, progress, is_going)| {
+        (id.to_string(), json!({ "progress": progress, "is_going": is_going }))
+      })
+      .collect();
+    serde_json::from_value(Value::Object(researches)).unwrap()
+  }
+
+  #[test]
+  fn test_autosaves_rotate_through_the_slots() {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let dir = std::env::temp_dir().join(format!("astray-saves-{}-{nanos}", std::process::id()));
+    assert_eq!(get_latest_save(&dir).unwrap(), None);
+
+    let state = game(1, 4);
+    let mut game = Save::new(Replay::new(&state), &state);
+    let mut paths = Vec::new();
+    for days in 0..3 {
+      game.replay.days = days;
+      paths.push(write_autosave(&dir, 2, &game).unwrap());
+    }
+
+    assert_eq!(paths[0], paths[2]);
+    assert_ne!(paths[0], paths[1]);
+    assert_eq!(get_latest_save(&dir).unwrap(), Some(paths[2].clone()));
+    assert_eq!(Save::load_from_file(&paths[2]).unwrap().replay.days, 2);
+    // A save is also a replay
+    assert_eq!(Replay::load_from_file(&paths[2]).unwrap().days, 2);
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  #[test]
+  fn test_saves_restore_the_research_progress() {
+    let mut state = game(1, 4);
+    let mut replay = Replay::new(&state);
+    for _ in 0..20 {
+      state.tick();
+    }
+    replay.days = state.get_date();
+    replay.result = Some(TickMetrics::collect(&state));
+
+    let mut save = Save::new(replay, &state);
+    let player = state.get_research_states().into_keys().next().unwrap();
+    save.research.insert(player.clone(), research_state(&[("optics", 5, true)]));
+    let (restored, _) = save.restore().unwrap();
+    assert_eq!(restored.get_research_states(), save.research);
+
+    save.research.insert(String::from("unknown"), research_state(&[]));
+    assert!(save.restore().is_err());
+    save.research.remove("unknown");
+
+    save.mods.push(String::from("unknown 1.0.0"));
+    assert!(save.restore().is_err());
+    save.mods.pop();
+
+    // The saved progress does not fit a game which plays again differently
+    save.replay.days += 1;
+    assert!(save.restore().is_err());
+  }
+
+  #[test]
+  fn test_current_game_follows_the_days_and_the_orders() {
+    let state = game(1, 4);
+    set_current_game(Save::new(Replay::new(&state), &state));
+    set_current_day(5);
+    record_current_order(RecordedOrder { day: 7, action: Action::ChooseEventOption(0) });
+    set_current_day(9);
+
+    let current = CURRENT_GAME.lock().unwrap().clone().unwrap();
+    assert_eq!(current.replay.days, 9);
+    assert_eq!(current.replay.orders, vec![
+      RecordedOrder { day: 7, action: Action::ChooseEventOption(0) },
+    ]);
+  }
+}
\ No newline at end of file
@@ -4,8 +4,15 @@ use serde::{Deserialize, Serialize};
 pub enum Tabs {
     SystemView,
     Research,
+    ResearchTree,
     Colonies,
-    ShipModules
+    ShipModules,
+    Fleets,
+    Diplomacy,
+    Policies,
+    Personnel,
+    Log,
+    Mods,
 }
 
 impl From<Tabs> for String {
@@ -13,8 +20,15 @@ impl From<Tabs> for String {
         match value {
             Tabs::SystemView => String::from("System View"),
             Tabs::Research => String::from("Research"),
+            Tabs::ResearchTree => String::from("Tech tree"),
             Tabs::Colonies => String::from("Colonies"),
             Tabs::ShipModules => String::from("Ship modules"),
+            Tabs::Fleets => String::from("Fleets"),
+            Tabs::Diplomacy => String::from("Diplomacy"),
+            Tabs::Policies => String::from("Policies"),
+            Tabs::Personnel => String::from("Personnel"),
+            Tabs::Log => String::from("Log"),
+            Tabs::Mods => String::from("Mods"),
         }
     }
 }
\ No newline at end of file
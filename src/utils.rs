@@ -64,6 +64,10 @@ pub fn initialize_panic_handler() -> Result<()> {
         .create_panic_handler()(panic_info);
     }
 
+    if let Some(path) = crate::saves::write_emergency_save() {
+      eprintln!("The game was saved to {}, continue it with --continue", path.display());
+    }
+
     std::process::exit(libc::EXIT_FAILURE);
   }));
   Ok(())